@@ -0,0 +1,155 @@
+//! String with a maximum length in bytes, for string fields of accounts
+//! whose data length is fixed when they are created.
+
+use {
+    crate::error::LandError,
+    borsh::{BorshDeserialize, BorshSerialize},
+    std::{
+        convert::TryFrom,
+        io::{Error, ErrorKind, Result, Write},
+    },
+};
+
+/// UTF-8 string of at most N bytes. Serialized exactly as a Borsh
+/// String, i.e. a u32 little-endian length followed by the bytes, so
+/// occupies at most BoundedString::<N>::MAX_DATA_LEN bytes of account
+/// data.
+///
+/// Strings longer than N bytes are rejected with FieldTooLong when
+/// constructed, serialized and deserialized. On serialize and
+/// deserialize the LandError is wrapped in an io::Error of kind
+/// InvalidData, from which it can be recovered with
+/// `err.get_ref().and_then(|e| e.downcast_ref::<LandError>())`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BoundedString<const N: usize>(String);
+
+impl<const N: usize> BoundedString<N> {
+    /// Maximum length of the string in bytes.
+    pub const MAX_LEN: usize = N;
+
+    /// Maximum length of the serialized string in bytes.
+    pub const MAX_DATA_LEN: usize = 4 + N;
+
+    pub fn new(s: String) -> std::result::Result<Self, LandError> {
+        if s.len() > N {
+            return Err(LandError::FieldTooLong);
+        }
+        Ok(BoundedString(s))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl<const N: usize> TryFrom<String> for BoundedString<N> {
+    type Error = LandError;
+
+    fn try_from(s: String) -> std::result::Result<Self, LandError> {
+        BoundedString::new(s)
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for BoundedString<N> {
+    type Error = LandError;
+
+    fn try_from(s: &str) -> std::result::Result<Self, LandError> {
+        BoundedString::new(s.to_string())
+    }
+}
+
+fn field_too_long() -> Error {
+    Error::new(ErrorKind::InvalidData, LandError::FieldTooLong)
+}
+
+impl<const N: usize> BorshSerialize for BoundedString<N> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        // the field is public to this module only, but confirm
+        // anyway so that an over-long string is never written
+        if self.0.len() > N {
+            return Err(field_too_long());
+        }
+        self.0.serialize(writer)
+    }
+}
+
+impl<const N: usize> BorshDeserialize for BoundedString<N> {
+    fn deserialize(buf: &mut &[u8]) -> Result<Self> {
+        // check the length prefix before reading the bytes so that
+        // an over-long string is rejected without being read
+        let len = u32::deserialize(buf)? as usize;
+        if len > N {
+            return Err(field_too_long());
+        }
+        if buf.len() < len {
+            return Err(Error::new(ErrorKind::InvalidInput, "Unexpected length of input"));
+        }
+        let (bytes, rest) = buf.split_at(len);
+        let s = String::from_utf8(bytes.to_vec())
+            .map_err(|err| Error::new(ErrorKind::InvalidData, err))?;
+        *buf = rest;
+        Ok(BoundedString(s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn land_error(err: Error) -> Option<LandError> {
+        err.get_ref()?.downcast_ref::<LandError>().cloned()
+    }
+
+    #[test]
+    fn test_bounded_string_max_len() {
+        // exactly N bytes
+        let s = BoundedString::<8>::try_from("abcdefgh").unwrap();
+        let data = s.try_to_vec().unwrap();
+        assert_eq!(BoundedString::<8>::MAX_DATA_LEN, data.len());
+        assert_eq!(s, BoundedString::<8>::try_from_slice(&data).unwrap());
+
+        // N + 1 bytes
+        assert_eq!(
+            Err(LandError::FieldTooLong),
+            BoundedString::<8>::try_from("abcdefghi"),
+        );
+
+        // a string written at N + 1 bytes cannot be read back
+        let data = "abcdefghi".to_string().try_to_vec().unwrap();
+        assert_eq!(
+            Some(LandError::FieldTooLong),
+            land_error(BoundedString::<8>::try_from_slice(&data).unwrap_err()),
+        );
+    }
+
+    #[test]
+    fn test_bounded_string_serialize_too_long() {
+        // only reachable from within this module
+        let s = BoundedString::<2>("abc".to_string());
+        assert_eq!(Some(LandError::FieldTooLong), land_error(s.try_to_vec().unwrap_err()));
+    }
+
+    #[test]
+    fn test_bounded_string_multi_byte_utf8() {
+        // length is counted in bytes rather than characters,
+        // 'é' is 2 bytes and '€' is 3 bytes
+        assert!(BoundedString::<4>::try_from("éé").is_ok());
+        assert!(BoundedString::<4>::try_from("a€").is_ok());
+        assert_eq!(Err(LandError::FieldTooLong), BoundedString::<4>::try_from("ééa"));
+        assert_eq!(Err(LandError::FieldTooLong), BoundedString::<4>::try_from("aa€"));
+
+        let s = BoundedString::<4>::try_from("a€").unwrap();
+        assert_eq!(s, BoundedString::<4>::try_from_slice(&s.try_to_vec().unwrap()).unwrap());
+
+        // bytes cut part way through a character are not valid UTF-8
+        let mut data = 2u32.try_to_vec().unwrap();
+        data.extend_from_slice(&"€".as_bytes()[..2]);
+        let err = BoundedString::<4>::try_from_slice(&data).unwrap_err();
+        assert_eq!(ErrorKind::InvalidData, err.kind());
+        assert_eq!(None, land_error(err));
+    }
+}
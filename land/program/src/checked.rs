@@ -0,0 +1,39 @@
+//! Checked arithmetic that fails with a LandError, for use wherever
+//! the processor computes with values taken from instruction data or
+//! account state.
+
+use crate::error::LandError;
+
+/// Checked_add_err returns a + b, or ArithmeticOverflow if the sum
+/// does not fit in a u64.
+pub fn checked_add_err(a: u64, b: u64) -> Result<u64, LandError> {
+    a.checked_add(b).ok_or(LandError::ArithmeticOverflow)
+}
+
+/// Checked_mul_err returns a * b, or ArithmeticOverflow if the product
+/// does not fit in a u64.
+pub fn checked_mul_err(a: u64, b: u64) -> Result<u64, LandError> {
+    a.checked_mul(b).ok_or(LandError::ArithmeticOverflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_add_err() {
+        assert_eq!(Ok(5), checked_add_err(2, 3));
+        assert_eq!(Ok(u64::MAX), checked_add_err(u64::MAX - 1, 1));
+        assert_eq!(Err(LandError::ArithmeticOverflow), checked_add_err(u64::MAX, 1));
+        assert_eq!(Err(LandError::ArithmeticOverflow), checked_add_err(u64::MAX / 2 + 1, u64::MAX / 2 + 1));
+    }
+
+    #[test]
+    fn test_checked_mul_err() {
+        assert_eq!(Ok(6), checked_mul_err(2, 3));
+        assert_eq!(Ok(0), checked_mul_err(u64::MAX, 0));
+        assert_eq!(Ok(u64::MAX - 1), checked_mul_err(u64::MAX / 2, 2));
+        assert_eq!(Err(LandError::ArithmeticOverflow), checked_mul_err(u64::MAX / 2 + 1, 2));
+        assert_eq!(Err(LandError::ArithmeticOverflow), checked_mul_err(1 << 32, 1 << 32));
+    }
+}
@@ -0,0 +1,1216 @@
+//! Helpers for clients of the land program, e.g. frontends that
+//! submit mint transactions and report the result.
+
+use {
+    crate::{
+        error::LandError,
+        instruction::mint_next_land_asset,
+        processor::{
+            LAND_PLANE_STATE_HASH_EVENT_TAG,
+            LAND_PLANE_STATE_HASH_LOG_PREFIX,
+            MINTED_LAND_ASSET_EVENT_TAG,
+            MINTED_LAND_ASSET_LOG_PREFIX,
+            PARCEL_RANGE_LOG_PREFIX,
+        },
+        state::{
+            Coordinate,
+            LandAsset,
+            LandAssetVersion,
+            LandPlane,
+            LandPlaneConfig,
+            MintPattern,
+            LAND_ASSET_ACC_DATA_LEN,
+            LAND_PLANE_STATS_ACC_PREFIX,
+            PROGRAM_CONFIG_ACC_PREFIX,
+        },
+    },
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::{
+        instruction::{Instruction, InstructionError},
+        keccak,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+    solana_sdk::transaction::TransactionError,
+};
+
+/// Prefix given by the runtime to lines logged by a program.
+const PROGRAM_LOG_PREFIX: &str = "Program log: ";
+
+/// Outcome of a MintNextLandAsset transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MintOutcome {
+    /// Land at the given co-ordinate was minted
+    Minted(Coordinate),
+
+    /// Every piece of land on the land plane has already been minted
+    PlaneComplete,
+}
+
+/// Errors that may be returned when decoding a MintOutcome.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MintOutcomeError {
+    /// Transaction failed for a reason other than the land plane
+    /// being complete
+    Transaction(TransactionError),
+
+    /// Transaction succeeded but did not log the minted co-ordinate
+    MissingMintLog,
+}
+
+/// Mint_outcome decodes the outcome of a MintNextLandAsset transaction
+/// from its result and log messages, e.g. as given by the status and
+/// meta of a confirmed transaction.
+pub fn mint_outcome(
+    result: &Result<(), TransactionError>,
+    logs: &[String],
+) -> Result<MintOutcome, MintOutcomeError> {
+    match result {
+        Ok(()) => logs
+            .iter()
+            .find_map(|log| parse_minted_log(log))
+            .map(MintOutcome::Minted)
+            .ok_or(MintOutcomeError::MissingMintLog),
+        Err(TransactionError::InstructionError(_, InstructionError::Custom(code)))
+            if *code == LandError::LandComplete as u32 =>
+        {
+            Ok(MintOutcome::PlaneComplete)
+        }
+        Err(err) => Err(MintOutcomeError::Transaction(err.clone())),
+    }
+}
+
+/// Parse_event_log returns the four values following the tag of an
+/// event logged with sol_log_64 under the given tag, which the runtime
+/// writes as five comma separated hex values, or None for any other line.
+fn parse_event_log(log: &str, tag: u64) -> Option<[u64; 4]> {
+    let mut values = [0; 5];
+    let mut fields = log.strip_prefix(PROGRAM_LOG_PREFIX)?.split(", ");
+    for value in values.iter_mut() {
+        *value = u64::from_str_radix(fields.next()?.strip_prefix("0x")?, 16).ok()?;
+    }
+    if fields.next().is_some() || values[0] != tag {
+        return None;
+    }
+    Some([values[1], values[2], values[3], values[4]])
+}
+
+/// Parse_minted_log returns the co-ordinate from the log line written
+/// on a successful mint, whether as text or as a compact event, or None
+/// for any other line.
+fn parse_minted_log(log: &str) -> Option<Coordinate> {
+    if let Some([x, z, _, _]) = parse_event_log(log, MINTED_LAND_ASSET_EVENT_TAG) {
+        return Some(Coordinate { x, z });
+    }
+    let fields = log
+        .strip_prefix(PROGRAM_LOG_PREFIX)?
+        .strip_prefix(MINTED_LAND_ASSET_LOG_PREFIX)?;
+    let (x, z) = fields.split_once(", ")?;
+    Some(Coordinate {
+        x: x.strip_prefix("x=")?.parse().ok()?,
+        z: z.strip_prefix("z=")?.parse().ok()?,
+    })
+}
+
+/// Verify_plane_state confirms that the given land plane state is the
+/// one committed to by the state hash logged by a MintNextLandAsset
+/// transaction, i.e. the state of the land plane following the mint.
+/// False is returned if the logs hold no state hash.
+pub fn verify_plane_state(land_plane: &LandPlane, logs: &[String]) -> bool {
+    logs.iter().find_map(|log| parse_state_hash_log(log)) == Some(land_plane.state_hash())
+}
+
+/// Parcel_range decodes the land logged by an EmitParcelRange
+/// transaction, e.g. as given by the logs of a simulated transaction,
+/// returning the index in mint order of the first piece of land and
+/// the state of each land asset account in turn, with None for land
+/// whose account has not been initialised. None is returned if the
+/// logs hold no parcel range.
+pub fn parcel_range(logs: &[String]) -> Option<(u64, Vec<Option<LandAsset>>)> {
+    logs.iter().find_map(|log| parse_parcel_range_log(log))
+}
+
+/// Parse_parcel_range_log returns the start index and land asset states
+/// from the log line written by EmitParcelRange, or None for any other
+/// line.
+fn parse_parcel_range_log(log: &str) -> Option<(u64, Vec<Option<LandAsset>>)> {
+    let fields = log
+        .strip_prefix(PROGRAM_LOG_PREFIX)?
+        .strip_prefix(PARCEL_RANGE_LOG_PREFIX)?;
+    let (start_index, data) = fields.split_once(", ")?;
+    let start_index = start_index.strip_prefix("start_index=")?.parse().ok()?;
+    let hex = data.strip_prefix("data=")?.as_bytes();
+    if hex.len() % (2 * LAND_ASSET_ACC_DATA_LEN) != 0 {
+        return None;
+    }
+    let data = hex
+        .chunks(2)
+        .map(|pair| u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok())
+        .collect::<Option<Vec<u8>>>()?;
+    let assets = data
+        .chunks(LAND_ASSET_ACC_DATA_LEN)
+        .map(|packed| {
+            let land_asset = LandAsset::unpack_versioned(packed).ok()?;
+            if land_asset.version == LandAssetVersion::Uninitialised {
+                return Some(None);
+            }
+            Some(Some(land_asset))
+        })
+        .collect::<Option<Vec<Option<LandAsset>>>>()?;
+    Some((start_index, assets))
+}
+
+/// Validate_mint_target confirms that the given co-ordinate is the next
+/// piece of land in the minting pattern of the land plane, so that a
+/// client may fail fast rather than submit a MintNextLandAsset that the
+/// land plane has moved on from. As on-chain, land that the land plane
+/// has already passed is reported as claimed by another mint.
+pub fn validate_mint_target(plane: &LandPlane, expected: Coordinate) -> Result<(), LandError> {
+    if expected == (Coordinate { x: plane.next_x, z: plane.next_z }) {
+        return Ok(());
+    }
+    if plane.mint_pattern == MintPattern::Shell && plane.has_passed(expected) {
+        return Err(LandError::ParcelAlreadyClaimed);
+    }
+    Err(LandError::InvalidLandAssetAccKey)
+}
+
+/// SPL Associated Token Account program
+pub mod associated_token_program {
+    solana_program::declare_id!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+}
+
+/// Associated_token_acc_pubkey returns the address of the associated
+/// token account holding the given mint for the given owner.
+/// i.e. PDA of ([owner_pubkey, token_program_id, mint_pubkey], associated_token_program_id)
+pub fn associated_token_acc_pubkey(owner_pubkey: &Pubkey, mint_pubkey: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            owner_pubkey.as_ref(),
+            spl_token::id().as_ref(),
+            mint_pubkey.as_ref(),
+        ],
+        &associated_token_program::id(),
+    ).0
+}
+
+/// Mint_next_auto creates a MintNextLandAsset instruction minting the
+/// current target of the given land plane, whose state is given, to the
+/// holder of the given NFT, who pays for the mint. Every account is
+/// derived: the land asset account of the target, the NFT holder's
+/// associated token account, the land plane stats and program config
+/// accounts, and the land plane's price oracle and fee recipient, if any.
+/// Only the protocol treasury, held by the program config, must be given.
+/// 
+/// The target of a land plane filled at random depends on which of its
+/// candidate co-ordinates hold land already minted, which its state does
+/// not show, so InvalidArgument is returned for such land planes.
+pub fn mint_next_auto(
+    program_id: &Pubkey,
+    plane: &Pubkey,
+    plane_state: &LandPlane,
+    owner: &Pubkey,
+    nft_mint: &Pubkey,
+    protocol_treasury: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    if plane_state.mint_pattern != MintPattern::Shell {
+        return Err(ProgramError::InvalidArgument);
+    }
+    let target = Coordinate { x: plane_state.next_x, z: plane_state.next_z };
+    let (land_asset_acc_pubkey, _) = plane_state.land_asset_address(program_id, plane, target);
+    let (land_plane_stats_acc_pubkey, _) = Pubkey::find_program_address(
+        &[LAND_PLANE_STATS_ACC_PREFIX.as_bytes(), plane.as_ref()],
+        program_id,
+    );
+    let (program_config_acc_pubkey, _) = Pubkey::find_program_address(
+        &[PROGRAM_CONFIG_ACC_PREFIX.as_bytes()],
+        program_id,
+    );
+    // a price oracle may set a fee even where mint_fee is zero
+    let fee_recipient = if plane_state.mint_fee > 0 || plane_state.price_oracle.is_some() {
+        plane_state.fee_recipient()
+    } else {
+        None
+    };
+    mint_next_land_asset(
+        program_id,
+        owner,
+        owner,
+        &land_asset_acc_pubkey,
+        plane,
+        &associated_token_acc_pubkey(owner, nft_mint),
+        nft_mint,
+        &land_plane_stats_acc_pubkey,
+        &program_config_acc_pubkey,
+        protocol_treasury,
+        plane_state.price_oracle.as_ref(),
+        fee_recipient.as_ref(),
+    )
+}
+
+/// Parse_state_hash_log returns the land plane state hash from the log
+/// line written on a successful mint, whether as text or as a compact
+/// event, or None for any other line.
+fn parse_state_hash_log(log: &str) -> Option<[u8; 32]> {
+    if let Some(words) = parse_event_log(log, LAND_PLANE_STATE_HASH_EVENT_TAG) {
+        let mut hash = [0; 32];
+        for (chunk, word) in hash.chunks_mut(8).zip(words.iter()) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        return Some(hash);
+    }
+    let hash: keccak::Hash = log
+        .strip_prefix(PROGRAM_LOG_PREFIX)?
+        .strip_prefix(LAND_PLANE_STATE_HASH_LOG_PREFIX)?
+        .parse()
+        .ok()?;
+    Some(hash.to_bytes())
+}
+
+/// Rough compute units used by a MintNextLandAsset instruction for any
+/// land plane, mostly in deriving program addresses, creating the land
+/// asset by mint account and logging the state hash.
+const BASE_MINT_COMPUTE: u32 = 60_000;
+
+/// Rough compute units added to a mint by each validation that a land
+/// plane config may enable.
+const FROZEN_MINT_COMPUTE: u32 = 2_000;
+const MINT_FEE_COMPUTE: u32 = 5_000;
+const ONE_PARCEL_PER_NFT_COMPUTE: u32 = 2_000;
+const RANDOM_FILL_COMPUTE: u32 = 5_000;
+const REQUIRED_COLLECTION_COMPUTE: u32 = 15_000;
+const MAX_SUPPLY_COMPUTE: u32 = 500;
+const MINT_COOLDOWN_COMPUTE: u32 = 15_000;
+const PRICE_ORACLE_COMPUTE: u32 = 2_000;
+
+/// Estimate_mint_compute returns a rough estimate of the compute units
+/// used by a MintNextLandAsset instruction on a land plane with the
+/// given config, for setting the compute budget of a mint transaction.
+/// The cost of deriving program addresses varies with their bumps, so
+/// the estimate errs high.
+pub fn estimate_mint_compute(config: &LandPlaneConfig) -> u32 {
+    let mut units = BASE_MINT_COMPUTE;
+    if config.require_frozen_mint {
+        units += FROZEN_MINT_COMPUTE;
+    }
+    // a price oracle may set a fee even where mint_fee is zero
+    if config.mint_fee > 0 || config.price_oracle.is_some() {
+        units += MINT_FEE_COMPUTE;
+    }
+    if config.one_parcel_per_nft {
+        units += ONE_PARCEL_PER_NFT_COMPUTE;
+    }
+    if config.mint_pattern == MintPattern::RandomFill {
+        units += RANDOM_FILL_COMPUTE;
+    }
+    if config.required_collection.is_some() {
+        units += REQUIRED_COLLECTION_COMPUTE;
+    }
+    if config.max_supply.is_some() {
+        units += MAX_SUPPLY_COMPUTE;
+    }
+    if config.mint_cooldown_slots > 0 {
+        units += MINT_COOLDOWN_COMPUTE;
+    }
+    if config.price_oracle.is_some() {
+        units += PRICE_ORACLE_COMPUTE;
+    }
+    units
+}
+
+/// Diff_planes lists the fields that differ between two snapshots of a
+/// land plane, e.g. taken before and after a migration, as the field
+/// name with its old and new values in Debug form. minted_count, derived
+/// from the cursor, is listed too if it changed.
+pub fn diff_planes(before: &LandPlane, after: &LandPlane) -> Vec<(&'static str, String, String)> {
+    // destructured so that a field added to LandPlane
+    // fails to compile until it is diffed here
+    let LandPlane {
+        version,
+        next_x,
+        next_z,
+        depth,
+        coordinate_ceiling,
+        require_frozen_mint,
+        mint_fee,
+        owner,
+        mint_pattern,
+        one_parcel_per_nft,
+        royalty_bps,
+        addr_width,
+        required_collection,
+        migrated_count,
+        max_supply,
+        mint_cooldown_slots,
+        price_oracle,
+        reserve_claimant,
+        closes_at_slot,
+        mint_start_slot,
+        mint_end_slot,
+        random_fill_seed,
+        plane_treasury,
+    } = before;
+
+    let mut diff = Vec::new();
+    macro_rules! diff_field {
+        ($field:ident) => {
+            if $field != &after.$field {
+                diff.push((
+                    stringify!($field),
+                    format!("{:?}", $field),
+                    format!("{:?}", after.$field),
+                ));
+            }
+        };
+    }
+    diff_field!(version);
+    diff_field!(next_x);
+    diff_field!(next_z);
+    diff_field!(depth);
+    diff_field!(coordinate_ceiling);
+    diff_field!(require_frozen_mint);
+    diff_field!(mint_fee);
+    diff_field!(owner);
+    diff_field!(mint_pattern);
+    diff_field!(one_parcel_per_nft);
+    diff_field!(royalty_bps);
+    diff_field!(addr_width);
+    diff_field!(required_collection);
+    diff_field!(migrated_count);
+    diff_field!(max_supply);
+    diff_field!(mint_cooldown_slots);
+    diff_field!(price_oracle);
+    diff_field!(reserve_claimant);
+    diff_field!(closes_at_slot);
+    diff_field!(mint_start_slot);
+    diff_field!(mint_end_slot);
+    diff_field!(random_fill_seed);
+    diff_field!(plane_treasury);
+
+    if before.minted_count() != after.minted_count() {
+        diff.push((
+            "minted_count",
+            before.minted_count().to_string(),
+            after.minted_count().to_string(),
+        ));
+    }
+    diff
+}
+
+/// Version of the serialized layout of a MapSnapshot, which is its
+/// leading byte. Bump it whenever the layout changes.
+pub const MAP_SNAPSHOT_VERSION: u8 = 1;
+
+/// Every piece of land minted from a land plane, e.g. exported by an
+/// indexer for caching or delivery over a CDN.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MapSnapshot {
+    /// MAP_SNAPSHOT_VERSION at which the snapshot was serialized
+    pub version: u8,
+    /// State_hash of the land plane the snapshot was taken from, which a
+    /// client may compare with that logged by the latest mint
+    pub land_plane_state_hash: [u8; 32],
+    /// Pieces of land minted from the land plane
+    pub minted_count: u64,
+    /// Minted land, ordered by co-ordinate
+    pub parcels: Vec<MapParcel>,
+}
+
+/// A single piece of land in a MapSnapshot.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MapParcel {
+    pub coordinate: Coordinate,
+    /// Mint of the NFT to which the land is bound
+    pub mint_pubkey: Pubkey,
+    pub attributes: u32,
+}
+
+/// Errors that may be returned when decoding a MapSnapshot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MapSnapshotError {
+    /// Snapshot was serialized at a version this client does not know
+    UnsupportedVersion(u8),
+
+    /// Snapshot is empty, truncated or otherwise not a valid snapshot
+    Malformed,
+}
+
+impl MapSnapshot {
+    /// To_bytes serializes the snapshot, which decode reverses.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.try_to_vec().unwrap()
+    }
+
+    /// Decode deserializes a snapshot serialized by to_bytes. The version
+    /// is checked before anything else, so that a snapshot at a later
+    /// layout is reported as such rather than as malformed.
+    pub fn decode(data: &[u8]) -> Result<MapSnapshot, MapSnapshotError> {
+        match data.first() {
+            Some(&MAP_SNAPSHOT_VERSION) => {
+                MapSnapshot::try_from_slice(data).map_err(|_| MapSnapshotError::Malformed)
+            }
+            Some(version) => Err(MapSnapshotError::UnsupportedVersion(*version)),
+            None => Err(MapSnapshotError::Malformed),
+        }
+    }
+}
+
+/// Build_map_snapshot builds a snapshot of the given land plane from
+/// its land asset accounts, each given with the co-ordinate of its land,
+/// e.g. as fetched by getProgramAccounts. Land not yet minted is left
+/// out, and the rest is ordered by co-ordinate so that the same land
+/// always gives the same serialized snapshot.
+pub fn build_map_snapshot(plane: &LandPlane, assets: &[(Coordinate, LandAsset)]) -> MapSnapshot {
+    let mut parcels: Vec<MapParcel> = assets
+        .iter()
+        .filter(|(_, asset)| asset.is_minted())
+        .map(|(coordinate, asset)| MapParcel {
+            coordinate: *coordinate,
+            mint_pubkey: asset.mint_pubkey,
+            attributes: asset.attributes,
+        })
+        .collect();
+    parcels.sort_by_key(|parcel| (parcel.coordinate.x, parcel.coordinate.z));
+    MapSnapshot {
+        version: MAP_SNAPSHOT_VERSION,
+        land_plane_state_hash: plane.state_hash(),
+        minted_count: plane.minted_count(),
+        parcels,
+    }
+}
+
+/// An account expected by a land program instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AccountSpec {
+    pub name: &'static str,
+    pub is_signer: bool,
+    pub is_writable: bool,
+    /// Account is given once for each of a number of accounts set by the
+    /// arguments of the instruction, e.g. count for MigrateAssetsBatch
+    pub is_repeated: bool,
+}
+
+/// An argument of a land program instruction, with its Rust type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ArgSpec {
+    pub name: &'static str,
+    pub ty: &'static str,
+}
+
+/// Name, accounts and arguments of a land program instruction, e.g. for
+/// generating typed clients.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstructionSpec {
+    /// Name of the LandInstruction variant
+    pub name: &'static str,
+    /// Accounts expected by the instruction, in order
+    pub accounts: Vec<AccountSpec>,
+    /// Arguments of the instruction, in serialized order
+    pub args: Vec<ArgSpec>,
+}
+
+fn account(name: &'static str, is_signer: bool, is_writable: bool) -> AccountSpec {
+    AccountSpec { name, is_signer, is_writable, is_repeated: false }
+}
+
+fn arg(name: &'static str, ty: &'static str) -> ArgSpec {
+    ArgSpec { name, ty }
+}
+
+/// Accounts shared by every instruction that mints the next piece of land.
+fn mint_next_accounts() -> Vec<AccountSpec> {
+    vec![
+        account("payer_acc", true, true),
+        account("nft_assoc_token_acc_owner_acc", false, false),
+        account("land_asset_acc", false, true),
+        account("land_plane_acc", false, true),
+        account("nft_assoc_token_acc", false, false),
+        account("nft_mint_acc", false, false),
+        account("land_plane_stats_acc", false, true),
+        account("system_program_acc", false, false),
+        account("program_config_acc", false, true),
+        account("protocol_treasury_acc", false, true),
+        account("land_asset_by_mint_acc", false, true),
+        account("rent_sysvar_acc", false, false),
+        account("slot_hashes_sysvar_acc", false, false),
+        account("nft_metadata_acc", false, false),
+        account("clock_sysvar_acc", false, false),
+        account("wallet_mint_record_acc", false, true),
+        account("price_oracle_acc", false, false),
+        account("plane_treasury_acc", false, true),
+    ]
+}
+
+/// Arguments shared by every instruction that initialises a land plane.
+fn land_plane_config_args() -> Vec<ArgSpec> {
+    vec![
+        arg("coordinate_ceiling", "Option<u64>"),
+        arg("require_frozen_mint", "bool"),
+        arg("mint_fee", "u64"),
+        arg("owner", "Option<Pubkey>"),
+        arg("mint_pattern", "MintPattern"),
+        arg("one_parcel_per_nft", "bool"),
+        arg("royalty_bps", "u16"),
+        arg("compact_addresses", "bool"),
+        arg("required_collection", "Option<Pubkey>"),
+        arg("max_supply", "Option<u64>"),
+        arg("mint_cooldown_slots", "u64"),
+        arg("price_oracle", "Option<Pubkey>"),
+        arg("reserve_claimant", "Option<Pubkey>"),
+        arg("closes_at_slot", "Option<u64>"),
+    ]
+}
+
+/// Instruction_specs describes every LandInstruction variant, in the
+/// order of their serialized tags, with the accounts passed by the
+/// instruction builder of each. ProcessBatch lists no accounts, since
+/// it is given those of its sub-instructions.
+pub fn instruction_specs() -> Vec<InstructionSpec> {
+    vec![
+        InstructionSpec {
+            name: "InitialiseLandPlane",
+            accounts: vec![
+                account("land_plane_acc", false, true),
+                account("rent_sysvar_acc", false, false),
+                account("program_config_acc", false, true),
+            ],
+            args: land_plane_config_args(),
+        },
+        InstructionSpec {
+            name: "InitialiseNextLandAsset",
+            accounts: vec![
+                account("rent_payer_acc", true, true),
+                account("land_asset_acc", false, true),
+                account("land_plane_acc", false, true),
+                account("rent_sysvar_acc", false, false),
+                account("system_program_acc", false, false),
+                account("authority_acc", true, false),
+            ],
+            args: vec![],
+        },
+        InstructionSpec {
+            name: "MintNextLandAsset",
+            accounts: mint_next_accounts(),
+            args: vec![],
+        },
+        InstructionSpec {
+            name: "InitialiseLandPlaneStats",
+            accounts: vec![
+                account("rent_payer_acc", true, true),
+                account("land_plane_stats_acc", false, true),
+                account("land_plane_acc", false, false),
+                account("rent_sysvar_acc", false, false),
+                account("system_program_acc", false, false),
+            ],
+            args: vec![],
+        },
+        InstructionSpec {
+            name: "InitialiseLandPlaneIdempotent",
+            accounts: vec![
+                account("land_plane_acc", false, true),
+                account("rent_sysvar_acc", false, false),
+                account("program_config_acc", false, true),
+            ],
+            args: land_plane_config_args(),
+        },
+        InstructionSpec {
+            name: "StampCoordinateUri",
+            accounts: vec![
+                account("update_authority_acc", true, false),
+                account("land_asset_acc", false, false),
+                account("land_plane_acc", false, false),
+                account("nft_metadata_acc", false, true),
+                account("token_metadata_program_acc", false, false),
+            ],
+            args: vec![arg("x", "u64"), arg("z", "u64")],
+        },
+        InstructionSpec {
+            name: "InitialiseProgramConfig",
+            accounts: vec![
+                account("upgrade_authority_acc", true, true),
+                account("program_config_acc", false, true),
+                account("program_data_acc", false, false),
+                account("rent_sysvar_acc", false, false),
+                account("system_program_acc", false, false),
+            ],
+            args: vec![arg("protocol_fee", "u64"), arg("protocol_treasury", "Pubkey")],
+        },
+        InstructionSpec {
+            name: "TransferLand",
+            accounts: vec![
+                account("payer_acc", true, true),
+                account("nft_assoc_token_acc_owner_acc", true, false),
+                account("land_asset_acc", false, true),
+                account("land_plane_acc", false, true),
+                account("nft_assoc_token_acc", false, false),
+                account("land_asset_by_mint_acc", false, true),
+                account("new_nft_mint_acc", false, false),
+                account("new_land_asset_by_mint_acc", false, true),
+                account("rent_sysvar_acc", false, false),
+                account("system_program_acc", false, false),
+                account("plane_treasury_acc", false, true),
+            ],
+            args: vec![arg("x", "u64"), arg("z", "u64"), arg("sale_price", "u64")],
+        },
+        InstructionSpec {
+            name: "RepairParcelCoordinate",
+            accounts: vec![
+                account("land_plane_owner_acc", true, false),
+                account("land_asset_acc", false, true),
+                account("land_plane_acc", false, false),
+            ],
+            args: vec![],
+        },
+        InstructionSpec {
+            name: "ProcessBatch",
+            accounts: vec![],
+            args: vec![arg("instructions", "Vec<BatchedInstruction>")],
+        },
+        InstructionSpec {
+            name: "SetupPlane",
+            accounts: vec![
+                account("payer_acc", true, true),
+                account("land_plane_acc", true, true),
+                account("land_plane_stats_acc", false, true),
+                account("rent_sysvar_acc", false, false),
+                account("system_program_acc", false, false),
+                account("program_config_acc", false, true),
+            ],
+            args: land_plane_config_args(),
+        },
+        InstructionSpec {
+            name: "MigrateAssetsBatch",
+            accounts: vec![
+                account("land_plane_owner_acc", true, false),
+                account("land_plane_acc", false, true),
+                AccountSpec { is_repeated: true, ..account("land_asset_acc", false, true) },
+            ],
+            args: vec![arg("start_index", "u64"), arg("count", "u8")],
+        },
+        InstructionSpec {
+            name: "ForceSetCursor",
+            accounts: vec![
+                account("upgrade_authority_acc", true, false),
+                account("land_plane_acc", false, true),
+                account("program_data_acc", false, false),
+            ],
+            args: vec![arg("next_x", "u64"), arg("next_z", "u64"), arg("depth", "u64")],
+        },
+        InstructionSpec {
+            name: "SetParcelAttributes",
+            accounts: vec![
+                account("land_plane_owner_acc", true, false),
+                account("land_asset_acc", false, true),
+                account("land_plane_acc", false, false),
+            ],
+            args: vec![arg("mask", "u32"), arg("value", "u32")],
+        },
+        InstructionSpec {
+            name: "MergeParcels",
+            accounts: vec![
+                account("nft_assoc_token_acc_owner_acc", true, false),
+                account("primary_land_asset_acc", false, true),
+                account("land_asset_acc", false, true),
+                account("land_asset_acc", false, true),
+                account("land_asset_acc", false, true),
+                account("land_plane_acc", false, false),
+                account("nft_assoc_token_acc", false, false),
+                account("nft_assoc_token_acc", false, false),
+                account("nft_assoc_token_acc", false, false),
+                account("nft_assoc_token_acc", false, false),
+            ],
+            args: vec![],
+        },
+        InstructionSpec {
+            name: "SetPlaneMetadata",
+            accounts: vec![
+                account("land_plane_owner_acc", true, true),
+                account("plane_metadata_acc", false, true),
+                account("land_plane_acc", false, false),
+                account("rent_sysvar_acc", false, false),
+                account("system_program_acc", false, false),
+            ],
+            args: vec![
+                arg("name", "BoundedString<MAX_PLANE_NAME_LEN>"),
+                arg("symbol", "BoundedString<MAX_PLANE_SYMBOL_LEN>"),
+                arg("base_uri", "BoundedString<MAX_PLANE_BASE_URI_LEN>"),
+            ],
+        },
+        InstructionSpec {
+            name: "VerifyParcelOwnership",
+            accounts: vec![
+                account("nft_assoc_token_acc_owner_acc", true, false),
+                account("land_asset_acc", false, false),
+                account("land_plane_acc", false, false),
+                account("nft_assoc_token_acc", false, false),
+            ],
+            args: vec![],
+        },
+        InstructionSpec {
+            name: "AdminRelinkParcel",
+            accounts: vec![
+                account("land_plane_owner_acc", true, true),
+                account("land_asset_acc", false, true),
+                account("land_plane_acc", false, false),
+                account("land_asset_by_mint_acc", false, true),
+                account("new_nft_mint_acc", false, false),
+                account("new_land_asset_by_mint_acc", false, true),
+                account("rent_sysvar_acc", false, false),
+                account("system_program_acc", false, false),
+            ],
+            args: vec![arg("new_mint", "Pubkey")],
+        },
+        InstructionSpec {
+            name: "MintNextWithAttributes",
+            accounts: mint_next_accounts(),
+            args: vec![arg("attributes", "u32")],
+        },
+        InstructionSpec {
+            name: "LockParcel",
+            accounts: vec![
+                account("nft_assoc_token_acc_owner_acc", true, false),
+                account("land_asset_acc", false, true),
+                account("land_plane_acc", false, false),
+                account("nft_assoc_token_acc", false, false),
+            ],
+            args: vec![arg("locker", "Pubkey")],
+        },
+        InstructionSpec {
+            name: "UnlockParcel",
+            accounts: vec![
+                account("locker_acc", true, false),
+                account("land_asset_acc", false, true),
+                account("land_plane_acc", false, false),
+            ],
+            args: vec![],
+        },
+        InstructionSpec {
+            name: "MintNextWithDeadline",
+            accounts: mint_next_accounts(),
+            args: vec![arg("valid_until_slot", "u64")],
+        },
+        InstructionSpec {
+            name: "ClaimReservedParcel",
+            accounts: vec![
+                account("reserve_claimant_acc", true, true),
+                account("land_asset_acc", false, true),
+                account("land_plane_acc", false, true),
+                account("nft_mint_acc", false, false),
+                account("land_plane_stats_acc", false, true),
+                account("land_asset_by_mint_acc", false, true),
+                account("clock_sysvar_acc", false, false),
+                account("rent_sysvar_acc", false, false),
+                account("system_program_acc", false, false),
+            ],
+            args: vec![arg("x", "u64"), arg("z", "u64")],
+        },
+        InstructionSpec {
+            name: "CheckCoordinate",
+            accounts: vec![
+                account("land_asset_acc", false, false),
+                account("land_plane_acc", false, false),
+            ],
+            args: vec![arg("x", "u64"), arg("z", "u64")],
+        },
+        InstructionSpec {
+            name: "EmitParcelRange",
+            accounts: vec![
+                account("land_plane_acc", false, false),
+                AccountSpec { is_repeated: true, ..account("land_asset_acc", false, false) },
+            ],
+            args: vec![arg("start_index", "u64"), arg("count", "u8")],
+        },
+        InstructionSpec {
+            name: "SetMintWindow",
+            accounts: vec![
+                account("land_plane_owner_acc", true, false),
+                account("land_plane_acc", false, true),
+            ],
+            args: vec![arg("mint_start_slot", "Option<u64>"), arg("mint_end_slot", "Option<u64>")],
+        },
+        InstructionSpec {
+            name: "SetPlaneTreasury",
+            accounts: vec![
+                account("land_plane_owner_acc", true, false),
+                account("land_plane_acc", false, true),
+            ],
+            args: vec![arg("plane_treasury", "Option<Pubkey>")],
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instruction::LandInstruction;
+    use solana_program::pubkey::Pubkey;
+    use std::convert::TryInto;
+
+    fn logs(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    #[test]
+    fn test_mint_outcome_minted() {
+        assert_eq!(
+            Ok(MintOutcome::Minted(Coordinate { x: 3, z: 1 })),
+            mint_outcome(
+                &Ok(()),
+                &logs(&[
+                    "Program 73SDVkNXf4UBhttg1N6sQa3EVyge9hN7ESSwU7pzDb5T invoke [1]",
+                    "Program log: Instruction: Mint Next Land Asset",
+                    "Program log: Minted land asset: x=3, z=1",
+                    "Program 73SDVkNXf4UBhttg1N6sQa3EVyge9hN7ESSwU7pzDb5T success",
+                ]),
+            ),
+        );
+
+        // a successful transaction without the mint log is not a mint
+        assert_eq!(
+            Err(MintOutcomeError::MissingMintLog),
+            mint_outcome(
+                &Ok(()),
+                &logs(&["Program log: Instruction: Mint Next Land Asset"]),
+            ),
+        );
+    }
+
+    #[test]
+    fn test_mint_outcome_plane_complete() {
+        assert_eq!(
+            Ok(MintOutcome::PlaneComplete),
+            mint_outcome(
+                &Err(TransactionError::InstructionError(
+                    0,
+                    InstructionError::Custom(LandError::LandComplete as u32),
+                )),
+                &logs(&[
+                    "Program log: Instruction: Mint Next Land Asset",
+                    "Program log: Land Complete",
+                ]),
+            ),
+        );
+
+        // any other failure is passed back to the caller
+        let err = TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(LandError::NftNotHeld as u32),
+        );
+        assert_eq!(
+            Err(MintOutcomeError::Transaction(err.clone())),
+            mint_outcome(&Err(err), &[]),
+        );
+    }
+
+    #[test]
+    fn test_verify_plane_state() {
+        let land_plane = crate::test_utils::initialized_plane(1, 0, 1);
+        let state_hash = keccak::Hash::new_from_array(land_plane.state_hash());
+        let mint_logs = logs(&[
+            "Program log: Minted land asset: x=0, z=0",
+            &format!("Program log: Land plane state hash: {}", state_hash),
+        ]);
+
+        // the state following the mint is verified
+        assert!(verify_plane_state(&land_plane, &mint_logs));
+
+        // any other state is not
+        assert!(!verify_plane_state(&crate::test_utils::initialized_plane(0, 0, 0), &mint_logs));
+
+        // nor is any state if no hash was logged
+        assert!(!verify_plane_state(&land_plane, &mint_logs[..1]));
+    }
+
+    #[test]
+    fn test_parcel_range() {
+        // run of five parcels from index 3, the last not yet initialised
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let minted: Vec<LandAsset> = (3..7)
+            .map(|index| LandAsset{
+                land_plane: land_plane_acc_pubkey,
+                coordinate: crate::state::coordinate_at_index(index),
+                ..crate::test_utils::initialized_asset(&Pubkey::new_unique())
+            })
+            .collect();
+        let mut data = vec![0; 5 * LAND_ASSET_ACC_DATA_LEN];
+        for (land_asset, packed) in minted.iter().zip(data.chunks_mut(LAND_ASSET_ACC_DATA_LEN)) {
+            packed.copy_from_slice(&land_asset.try_to_vec().unwrap());
+        }
+        let hex: String = data.iter().map(|b| format!("{:02x}", b)).collect();
+        let range_logs = logs(&[
+            "Program log: Instruction: Emit Parcel Range",
+            &format!("Program log: Parcel range: start_index=3, data={}", hex),
+        ]);
+
+        let (start_index, assets) = parcel_range(&range_logs).unwrap();
+        assert_eq!(3, start_index);
+        assert_eq!(5, assets.len());
+        for (land_asset, asset) in minted.iter().zip(assets.iter()) {
+            assert_eq!(Some(land_asset), asset.as_ref());
+        }
+        assert_eq!(None, assets[4]);
+
+        // logs without a parcel range, or with a truncated one, hold none
+        assert_eq!(None, parcel_range(&range_logs[..1]));
+        let truncated = logs(&[&range_logs[1][..range_logs[1].len() - 2]]);
+        assert_eq!(None, parcel_range(&truncated));
+    }
+
+    #[test]
+    fn test_validate_mint_target() {
+        let land_plane = crate::test_utils::initialized_plane(2, 1, 2);
+
+        // the current co-ordinate is the target
+        assert_eq!(Ok(()), validate_mint_target(&land_plane, Coordinate { x: 2, z: 1 }));
+
+        // a stale co-ordinate has been claimed by another mint
+        assert_eq!(
+            Err(LandError::ParcelAlreadyClaimed),
+            validate_mint_target(&land_plane, Coordinate { x: 1, z: 1 }),
+        );
+
+        // and one not yet reached is not the target
+        assert_eq!(
+            Err(LandError::InvalidLandAssetAccKey),
+            validate_mint_target(&land_plane, Coordinate { x: 2, z: 2 }),
+        );
+    }
+
+    #[test]
+    fn test_mint_next_auto() {
+        let program_id = crate::id();
+        let plane = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let nft_mint = Pubkey::new_unique();
+        let protocol_treasury = Pubkey::new_unique();
+        let plane_state = LandPlane{
+            price_oracle: Some(Pubkey::new_unique()),
+            ..crate::test_utils::initialized_plane(2, 1, 2)
+        };
+
+        // the same instruction as one built by hand from derived accounts
+        let (land_asset_acc_pubkey, _) =
+            crate::state::find_land_asset_address(&program_id, &plane, Coordinate { x: 2, z: 1 }, plane_state.addr_width);
+        let (land_plane_stats_acc_pubkey, _) =
+            Pubkey::find_program_address(&[b"solsspace-stats", plane.as_ref()], &program_id);
+        let (program_config_acc_pubkey, _) =
+            Pubkey::find_program_address(&[b"solsspace-config"], &program_id);
+        let (nft_assoc_token_acc_pubkey, _) = Pubkey::find_program_address(
+            &[owner.as_ref(), spl_token::id().as_ref(), nft_mint.as_ref()],
+            &"ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL".parse().unwrap(),
+        );
+        let manual = mint_next_land_asset(
+            &program_id,
+            &owner,
+            &owner,
+            &land_asset_acc_pubkey,
+            &plane,
+            &nft_assoc_token_acc_pubkey,
+            &nft_mint,
+            &land_plane_stats_acc_pubkey,
+            &program_config_acc_pubkey,
+            &protocol_treasury,
+            plane_state.price_oracle.as_ref(),
+            None,
+        ).unwrap();
+        assert_eq!(
+            Ok(manual),
+            mint_next_auto(&program_id, &plane, &plane_state, &owner, &nft_mint, &protocol_treasury),
+        );
+
+        // the target of land filled at random is not known from its state alone
+        let random_fill = LandPlane{
+            mint_pattern: MintPattern::RandomFill,
+            coordinate_ceiling: Some(10),
+            ..plane_state
+        };
+        assert_eq!(
+            Err(ProgramError::InvalidArgument),
+            mint_next_auto(&program_id, &plane, &random_fill, &owner, &nft_mint, &protocol_treasury),
+        );
+    }
+
+    #[test]
+    fn test_compact_mint_logs() {
+        // events formatted as the runtime formats sol_log_64
+        let event = |values: [u64; 5]| format!(
+            "Program log: {:#x}, {:#x}, {:#x}, {:#x}, {:#x}",
+            values[0], values[1], values[2], values[3], values[4],
+        );
+        let land_plane = crate::test_utils::initialized_plane(1, 0, 1);
+        let state_hash = land_plane.state_hash();
+        let word = |i: usize| u64::from_le_bytes(state_hash[i * 8..(i + 1) * 8].try_into().unwrap());
+        let mint_logs = vec![
+            "Program log: Instruction: Mint Next Land Asset".to_string(),
+            event([MINTED_LAND_ASSET_EVENT_TAG, 40, 71, 0, 0]),
+            event([LAND_PLANE_STATE_HASH_EVENT_TAG, word(0), word(1), word(2), word(3)]),
+        ];
+
+        // decode to the minted co-ordinate and the state hash
+        assert_eq!(
+            Ok(MintOutcome::Minted(Coordinate { x: 40, z: 71 })),
+            mint_outcome(&Ok(()), &mint_logs),
+        );
+        assert_eq!(Some(state_hash), parse_state_hash_log(&mint_logs[2]));
+        assert!(verify_plane_state(&land_plane, &mint_logs));
+        assert!(!verify_plane_state(&crate::test_utils::initialized_plane(0, 0, 0), &mint_logs));
+
+        // as documented for the minted land asset event
+        assert_eq!(
+            "Program log: 0x4d494e54, 0x28, 0x47, 0x0, 0x0",
+            event([MINTED_LAND_ASSET_EVENT_TAG, 40, 71, 0, 0]),
+        );
+
+        // events under another tag, or malformed, are not decoded
+        assert_eq!(None, parse_minted_log(&event([0, 40, 71, 0, 0])));
+        assert_eq!(None, parse_minted_log("Program log: 0x4d494e54, 0x28, 0x47, 0x0"));
+        assert_eq!(None, parse_minted_log("Program log: 0x4d494e54, 0x28, 0x47, 0x0, 0x0, 0x0"));
+        assert_eq!(None, parse_minted_log("Program log: 0x4d494e54, 40, 0x47, 0x0, 0x0"));
+    }
+
+    #[test]
+    fn test_diff_planes() {
+        let before = crate::test_utils::initialized_plane(2, 1, 2);
+
+        // identical planes do not differ
+        assert_eq!(Vec::<(&str, String, String)>::new(), diff_planes(&before, &before.clone()));
+
+        // cursor moved on to the next shell
+        let after = LandPlane{ next_x: 3, next_z: 0, depth: 3, ..before.clone() };
+        assert_eq!(
+            vec![
+                ("next_x", "2".to_string(), "3".to_string()),
+                ("next_z", "1".to_string(), "0".to_string()),
+                ("depth", "2".to_string(), "3".to_string()),
+                ("minted_count", "5".to_string(), "9".to_string()),
+            ],
+            diff_planes(&before, &after),
+        );
+
+        // optional fields are shown in Debug form
+        let after = LandPlane{ max_supply: Some(10), ..before.clone() };
+        assert_eq!(
+            vec![("max_supply", "None".to_string(), "Some(10)".to_string())],
+            diff_planes(&before, &after),
+        );
+    }
+
+    #[test]
+    fn test_estimate_mint_compute() {
+        let base = crate::test_utils::initialized_plane(0, 0, 0).config();
+        let estimate = estimate_mint_compute(&base);
+        assert!(estimate > 0);
+
+        // each validation enabled adds to the estimate
+        let mut config = base;
+        let mut previous = estimate;
+        let steps: Vec<fn(&mut LandPlaneConfig)> = vec![
+            |c| c.require_frozen_mint = true,
+            |c| c.mint_fee = 5_000,
+            |c| c.one_parcel_per_nft = true,
+            |c| c.mint_pattern = MintPattern::RandomFill,
+            |c| c.required_collection = Some(Pubkey::new_unique()),
+            |c| c.max_supply = Some(10_000),
+            |c| c.mint_cooldown_slots = 150,
+            |c| c.price_oracle = Some(Pubkey::new_unique()),
+        ];
+        for step in steps {
+            let mut single = base;
+            step(&mut single);
+            assert!(estimate_mint_compute(&single) > estimate, "{:?}", single);
+
+            step(&mut config);
+            assert!(estimate_mint_compute(&config) > previous, "{:?}", config);
+            previous = estimate_mint_compute(&config);
+        }
+
+        // while settings that add no validation do not
+        assert_eq!(estimate, estimate_mint_compute(&LandPlaneConfig{ royalty_bps: 250, ..base }));
+    }
+
+    #[test]
+    fn test_map_snapshot_round_trip() {
+        let plane = crate::test_utils::initialized_plane(2, 1, 2);
+        let asset = |x, z, attributes| {
+            let coordinate = Coordinate { x, z };
+            (coordinate, LandAsset{
+                coordinate,
+                attributes,
+                ..crate::test_utils::initialized_asset(&Pubkey::new_unique())
+            })
+        };
+        let assets = vec![
+            asset(2, 0, 0),
+            asset(0, 0, 0b101),
+            asset(1, 1, 0),
+            // initialised but not yet minted
+            (Coordinate { x: 2, z: 1 }, crate::test_utils::initialized_asset(&Pubkey::default())),
+            asset(0, 1, u32::MAX),
+        ];
+
+        // minted land is kept, in co-ordinate order
+        let snapshot = build_map_snapshot(&plane, &assets);
+        assert_eq!(MAP_SNAPSHOT_VERSION, snapshot.version);
+        assert_eq!(plane.state_hash(), snapshot.land_plane_state_hash);
+        assert_eq!(plane.minted_count(), snapshot.minted_count);
+        assert_eq!(
+            vec![
+                MapParcel { coordinate: Coordinate { x: 0, z: 0 }, mint_pubkey: assets[1].1.mint_pubkey, attributes: 0b101 },
+                MapParcel { coordinate: Coordinate { x: 0, z: 1 }, mint_pubkey: assets[4].1.mint_pubkey, attributes: u32::MAX },
+                MapParcel { coordinate: Coordinate { x: 1, z: 1 }, mint_pubkey: assets[2].1.mint_pubkey, attributes: 0 },
+                MapParcel { coordinate: Coordinate { x: 2, z: 0 }, mint_pubkey: assets[0].1.mint_pubkey, attributes: 0 },
+            ],
+            snapshot.parcels,
+        );
+
+        // and the order in which assets are given does not matter
+        let reversed: Vec<_> = assets.iter().rev().cloned().collect();
+        let bytes = snapshot.to_bytes();
+        assert_eq!(bytes, build_map_snapshot(&plane, &reversed).to_bytes());
+
+        // serialized snapshot decodes to the same snapshot
+        assert_eq!(Ok(snapshot.clone()), MapSnapshot::decode(&bytes));
+
+        // but not once truncated, extended or at an unknown version
+        assert_eq!(Err(MapSnapshotError::Malformed), MapSnapshot::decode(&bytes[..bytes.len() - 1]));
+        assert_eq!(Err(MapSnapshotError::Malformed), MapSnapshot::decode(&[bytes.clone(), vec![0]].concat()));
+        assert_eq!(Err(MapSnapshotError::Malformed), MapSnapshot::decode(&[]));
+        let mut later = bytes;
+        later[0] = MAP_SNAPSHOT_VERSION + 1;
+        assert_eq!(Err(MapSnapshotError::UnsupportedVersion(MAP_SNAPSHOT_VERSION + 1)), MapSnapshot::decode(&later));
+    }
+
+    #[test]
+    fn test_instruction_specs() {
+        let specs = instruction_specs();
+
+        // there is a spec for every variant, in tag order, and no more
+        for (tag, spec) in specs.iter().enumerate() {
+            if spec.args.is_empty() {
+                assert!(LandInstruction::try_from_slice(&[tag as u8]).is_ok(), "{}", spec.name);
+            }
+        }
+        assert!(LandInstruction::try_from_slice(&[specs.len() as u8]).is_err());
+
+        // spec of MintNextLandAsset matches the metas of its constructor
+        let mint_next = specs.iter().find(|spec| spec.name == "MintNextLandAsset").unwrap();
+        let instruction = crate::instruction::mint_next_land_asset(
+            &crate::id(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            Some(&Pubkey::new_unique()),
+            Some(&Pubkey::new_unique()),
+        ).unwrap();
+        assert_eq!(mint_next.accounts.len(), instruction.accounts.len());
+        for (spec, meta) in mint_next.accounts.iter().zip(instruction.accounts.iter()) {
+            assert_eq!((spec.is_signer, spec.is_writable), (meta.is_signer, meta.is_writable), "{}", spec.name);
+        }
+    }
+}
@@ -10,10 +10,13 @@ use {
     thiserror::Error,
 };
 
+#[cfg(feature = "client")]
+use num_traits::FromPrimitive;
+
 /// Errors that may be returned by the Token program.
 #[derive(Clone, Debug, Eq, Error, FromPrimitive, PartialEq)]
 pub enum LandError {
-    /// Signature error
+    /// Signature error, i.e. signed but not by the expected key
     #[error("Signature error")]
     SignatureError,    
 
@@ -48,6 +51,265 @@ pub enum LandError {
     /// Land asset account is uninitialised
     #[error("Land plane account uninitialsed")]
     LandAssetAccUninitialised,
+
+    /// MintAuthorityNotDisabled
+    #[error("NFT mint authority not disabled")]
+    MintAuthorityNotDisabled,
+
+    /// TokenAccountFrozen
+    #[error("NFT token account frozen")]
+    TokenAccountFrozen,
+
+    /// InvalidLandPlaneStatsAccKey
+    #[error("Invalid land plane stats acc key")]
+    InvalidLandPlaneStatsAccKey,
+
+    /// Land plane stats account is already initialised
+    #[error("Land plane stats account already initialised")]
+    LandPlaneStatsAccAlreadyInitialised,
+
+    /// Land plane stats account is uninitialised
+    #[error("Land plane stats account uninitialised")]
+    LandPlaneStatsAccUninitialised,
+
+    /// NftTokenAccOwnerMismatch
+    #[error("NFT token account not owned by recipient")]
+    NftTokenAccOwnerMismatch,
+
+    /// NftTokenAccMintMismatch
+    #[error("NFT token account does not hold given NFT mint")]
+    NftTokenAccMintMismatch,
+
+    /// NftNotHeld
+    #[error("NFT token account does not hold the NFT")]
+    NftNotHeld,
+
+    /// LandPlaneConfigMismatch
+    #[error("Land plane account already initialised with a different config")]
+    LandPlaneConfigMismatch,
+
+    /// InconsistentPlaneState
+    #[error("Land plane next co-ordinate is not on the minting pattern")]
+    InconsistentPlaneState,
+
+    /// LandAssetNotMinted
+    #[error("Land asset not yet minted")]
+    LandAssetNotMinted,
+
+    /// InvalidNftMetadataAccKey
+    #[error("Invalid NFT metadata acc key")]
+    InvalidNftMetadataAccKey,
+
+    /// UpdateAuthorityMismatch
+    #[error("Signer is not the NFT metadata update authority")]
+    UpdateAuthorityMismatch,
+
+    /// UriTooLong
+    #[error("NFT metadata URI too long")]
+    UriTooLong,
+
+    /// MissingSignature, i.e. a required signature is absent
+    #[error("Missing required signature")]
+    MissingSignature,
+
+    /// InsufficientFeeFunds
+    #[error("Payer has insufficient lamports to pay the mint fee")]
+    InsufficientFeeFunds,
+
+    /// InvalidProgramConfigAccKey
+    #[error("Invalid program config acc key")]
+    InvalidProgramConfigAccKey,
+
+    /// Program config account is already initialised
+    #[error("Program config account already initialised")]
+    ProgramConfigAccAlreadyInitialised,
+
+    /// Program config account is uninitialised
+    #[error("Program config account uninitialised")]
+    ProgramConfigAccUninitialised,
+
+    /// InvalidProgramDataAcc
+    #[error("Invalid program data acc")]
+    InvalidProgramDataAcc,
+
+    /// InvalidProtocolTreasuryAcc
+    #[error("Protocol treasury acc does not match program config")]
+    InvalidProtocolTreasuryAcc,
+
+    /// InvalidLandAssetByMintAccKey
+    #[error("Invalid land asset by mint acc key")]
+    InvalidLandAssetByMintAccKey,
+
+    /// FieldTooLong
+    #[error("String field exceeds its maximum length")]
+    FieldTooLong,
+
+    /// LandPlaneOwnerMismatch
+    #[error("Signer is not the owner of the land plane")]
+    LandPlaneOwnerMismatch,
+
+    /// NestedProcessBatch
+    #[error("Process batch instructions may not be nested")]
+    NestedProcessBatch,
+
+    /// InvalidSystemProgram
+    #[error("Invalid system program")]
+    InvalidSystemProgram,
+
+    /// RandomFillRequiresCeiling
+    #[error("Random fill requires a coordinate ceiling")]
+    RandomFillRequiresCeiling,
+
+    /// LandAssetAlreadyMinted
+    #[error("Land asset already minted")]
+    LandAssetAlreadyMinted,
+
+    /// InvalidSlotHashesSysvar
+    #[error("Invalid slot hashes sysvar")]
+    InvalidSlotHashesSysvar,
+
+    /// NftAlreadyOwnsLand
+    #[error("NFT already owns land")]
+    NftAlreadyOwnsLand,
+
+    /// Payer of a TransferLand cannot cover the royalty of the land plane
+    #[error("Insufficient funds for royalty")]
+    InsufficientRoyaltyFunds,
+
+    /// CompactAddressesRequireCeiling
+    #[error("Compact addresses require a coordinate ceiling")]
+    CompactAddressesRequireCeiling,
+
+    /// BatchTooLarge
+    #[error("Process batch holds more than the maximum number of instructions")]
+    BatchTooLarge,
+
+    /// WrongCollection
+    #[error("NFT is not in the verified collection required by the land plane")]
+    WrongCollection,
+
+    /// MigrationOutOfOrder
+    #[error("Migrate assets batch does not start where the last one ended")]
+    MigrationOutOfOrder,
+
+    /// InsufficientRentFunds
+    #[error("Payer has insufficient lamports to fund the new account")]
+    InsufficientRentFunds,
+
+    /// MaxSupplyReached
+    #[error("Max supply of the land plane has been reached")]
+    MaxSupplyReached,
+
+    /// CoordinateOutOfBounds
+    #[error("Co-ordinate lies beyond the bounds of the land plane")]
+    CoordinateOutOfBounds,
+
+    /// VersionMismatch
+    #[error("Land plane and program config versions are not compatible")]
+    VersionMismatch,
+
+    /// ArithmeticOverflow
+    #[error("Arithmetic overflow")]
+    ArithmeticOverflow,
+
+    /// InvalidAccountProgramId
+    #[error("Program ID given as a writable account")]
+    InvalidAccountProgramId,
+
+    /// MintCooldownActive
+    #[error("Wallet must wait for the mint cooldown of the land plane")]
+    MintCooldownActive,
+
+    /// InvalidWalletMintRecordAccKey
+    #[error("Invalid wallet mint record acc key")]
+    InvalidWalletMintRecordAccKey,
+
+    /// ParcelsNotContiguous
+    #[error("Parcels do not form a contiguous block")]
+    ParcelsNotContiguous,
+
+    /// ParcelAlreadyMerged
+    #[error("Parcel is already part of an estate")]
+    ParcelAlreadyMerged,
+
+    /// ParcelAlreadyClaimed
+    #[error("Parcel already claimed by another mint")]
+    ParcelAlreadyClaimed,
+
+    /// InvalidPlaneMetadataAccKey
+    #[error("Invalid plane metadata acc key")]
+    InvalidPlaneMetadataAccKey,
+
+    /// MissingMetadataAccount
+    #[error("Missing metadata account")]
+    MissingMetadataAccount,
+
+    /// NotAnNft
+    #[error("Mint is not that of an NFT")]
+    NotAnNft,
+
+    /// InvalidPriceOracleAccKey
+    #[error("Invalid price oracle account key")]
+    InvalidPriceOracleAccKey,
+
+    /// Price feed account is uninitialised
+    #[error("Price feed account uninitialised")]
+    PriceFeedAccUninitialised,
+
+    /// Land asset was minted from a land plane other than the one given
+    #[error("Land asset belongs to another land plane")]
+    PlaneMismatch,
+
+    /// Land asset is locked, e.g. for staking
+    #[error("Land asset is locked")]
+    ParcelLocked,
+
+    /// Signer is not the account by which the land asset was locked
+    #[error("Signer did not lock the land asset")]
+    LockerMismatch,
+
+    /// Slot until which the transaction was valid has passed
+    #[error("Transaction expired")]
+    TransactionExpired,
+
+    /// Account data length is not that of the current layout, e.g. as
+    /// the account has not yet been migrated
+    #[error("Stale account layout")]
+    StaleAccountLayout,
+
+    /// Signer is not the reserve claimant of the land plane
+    #[error("Signer is not the reserve claimant of the land plane")]
+    InvalidReserveClaimant,
+
+    /// Sale window of the land plane has not yet closed
+    #[error("Sale window has not closed")]
+    SaleWindowOpen,
+
+    /// EmitParcelRange given more than the maximum number of parcels
+    #[error("Parcel range holds more than the maximum number of parcels")]
+    ParcelRangeTooLarge,
+
+    /// Mint window of the land plane has not yet opened
+    #[error("Minting has not started")]
+    MintNotStarted,
+
+    /// Mint window of the land plane has closed
+    #[error("Minting has ended")]
+    MintEnded,
+
+    /// Mint window would end before it starts
+    #[error("Mint window ends before it starts")]
+    InvalidMintWindow,
+
+    /// Land at a co-ordinate probed past by a mint from a land plane
+    /// filled at random has not been minted
+    #[error("Probed land has not been minted")]
+    ProbedLandNotMinted,
+
+    /// Land plane charges a fee but has neither a plane treasury nor an
+    /// owner to pay it to
+    #[error("Land plane has no fee recipient")]
+    FeeRecipientUnset,
 }
 
 impl PrintProgramError for LandError {
@@ -66,4 +328,48 @@ impl<T> DecodeError<T> for LandError {
     fn type_of() -> &'static str {
         "Land Error"
     }
+}
+
+/// Converts the code of a custom program error returned by the land
+/// program into a JSON object of the form:
+/// 
+/// ```text
+/// { "code": N, "name": "...", "message": "..." }
+/// ```
+/// 
+/// Returns None if the given code does not correspond to a LandError.
+#[cfg(feature = "client")]
+pub fn land_error_json(code: u32) -> Option<serde_json::Value> {
+    let error = LandError::from_u32(code)?;
+    Some(serde_json::json!({
+        "code": code,
+        "name": format!("{:?}", error),
+        "message": error.to_string(),
+    }))
+}
+
+#[cfg(all(test, feature = "client"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_land_error_json() {
+        assert_eq!(
+            Some(serde_json::json!({
+                "code": 0,
+                "name": "SignatureError",
+                "message": "Signature error",
+            })),
+            land_error_json(LandError::SignatureError as u32),
+        );
+        assert_eq!(
+            Some(serde_json::json!({
+                "code": 5,
+                "name": "LandComplete",
+                "message": "Land Complete",
+            })),
+            land_error_json(LandError::LandComplete as u32),
+        );
+        assert_eq!(None, land_error_json(u32::MAX));
+    }
 }
\ No newline at end of file
@@ -1,16 +1,39 @@
-use crate::{check_program_account};
+use crate::{
+    bounded_string::BoundedString,
+    check_program_account,
+    metadata::{metadata_acc_pubkey, token_metadata_program},
+    state::{
+        land_asset_by_mint_acc_pubkey,
+        plane_metadata_acc_pubkey,
+        program_config_acc_pubkey,
+        wallet_mint_record_acc_pubkey,
+        MintPattern,
+        MAX_PLANE_BASE_URI_LEN,
+        MAX_PLANE_NAME_LEN,
+        MAX_PLANE_SYMBOL_LEN,
+    },
+};
 use {
     borsh::{BorshDeserialize, BorshSerialize},
+    std::{
+        convert::TryFrom,
+        io::{self, Write},
+    },
     solana_program::{
         program_error::{ProgramError},
         pubkey::Pubkey,
         instruction::{AccountMeta, Instruction},
         sysvar,
-        system_program
+        system_program,
+        bpf_loader_upgradeable,
     },
 };
 
 /// Instructions supported by the Metadata program.
+///
+/// The borsh discriminant of each variant is its leading byte on the wire,
+/// so new variants MUST be appended at the end of the enum; inserting or
+/// reordering variants changes the meaning of already-signed transactions.
 #[derive(BorshSerialize, BorshDeserialize, Clone)]
 pub enum LandInstruction {
     /// Initialise Land Plane Account
@@ -25,7 +48,40 @@ pub enum LandInstruction {
     /// 0. `[writable] land_place_acc`
     ///     Land plane account to initialise.
     /// 1. `[] rent_sysvar_acc`
-    InitialiseLandPlane,
+    /// 2. `[writable] program_config_acc`
+    ///     The program config account, updated to count the new land plane.
+    ///     i.e. PDA of (['solsspace-config'], land_program_acc_pubkey)
+    InitialiseLandPlane {
+        /// Optional hard ceiling on the x and z co-ordinates of minted land.
+        coordinate_ceiling: Option<u64>,
+        /// Only allow land to be bound to NFTs whose mint authority is disabled.
+        require_frozen_mint: bool,
+        /// Lamports charged for each piece of land minted.
+        mint_fee: u64,
+        /// Optional owner of the land plane.
+        owner: Option<Pubkey>,
+        /// Order in which land is assigned to each mint.
+        mint_pattern: MintPattern,
+        /// Only allow land to be bound to NFTs not already bound to land.
+        one_parcel_per_nft: bool,
+        /// Royalty, in basis points of the sale price, paid on each transfer.
+        royalty_bps: u16,
+        /// Encode co-ordinates in land asset addresses in as few bytes as
+        /// the coordinate ceiling allows.
+        compact_addresses: bool,
+        /// Only allow land to be bound to NFTs in this verified collection.
+        required_collection: Option<Pubkey>,
+        /// Maximum number of pieces of land that may be minted.
+        max_supply: Option<u64>,
+        /// Minimum number of slots between mints by the same wallet, zero for none.
+        mint_cooldown_slots: u64,
+        /// Price feed from which to read the mint fee, if any.
+        price_oracle: Option<Pubkey>,
+        /// Account that may claim land left unminted once the sale window has closed, if any.
+        reserve_claimant: Option<Pubkey>,
+        /// Slot after which the sale window is closed, if any.
+        closes_at_slot: Option<u64>,
+    },
 
 
     /// Initialise Land Asset Account
@@ -33,21 +89,29 @@ pub enum LandInstruction {
     /// Initialise land asset account before using it in a mint next
     /// instruction.
     /// 
+    /// Fails if the land plane has reached its max supply, since the
+    /// land could then never be minted.
+    /// 
     /// Accounts expected by this instruction:
     /// 
-    /// 1. `[signer] rent_payer_acc`
+    /// 1. `[signer, writable] rent_payer_acc`
     ///     Key of account responsible for paying required rent for the new
     ///     land_asset_acc.
     /// 2. `[writable] land_asset_acc`
     ///     Key of new land asset account.
     ///     This key should be a PDA of:
     ///     (['solsspace-land', land_plane_acc_pubkey, x, y], land_program_acc_pubkey)
-    ///     Typically this would correspond to the next piece of land that will be minted.
+    ///     This must correspond to the next piece of land that will be minted.
     /// 3. `[] land_plane_acc`
     ///     Public key of the land plane account from which the next piece of land will be minted.
     ///     Used to determine that the correct land_asset_acc is provided.
     /// 4. `[] rent_sysvar_acc`
     /// 5. `[] system_program_acc`
+    /// 6. `[signer] authority_acc`
+    ///     Account authorising the initialisation where it is not the rent
+    ///     payer, e.g. a user whose backend funds the rent. Only given, and
+    ///     then required to sign, in that case. For a land plane with an
+    ///     owner, it must be the owner.
     InitialiseNextLandAsset,
 
     /// Mint Land Pience
@@ -56,34 +120,910 @@ pub enum LandInstruction {
     /// linking it to the given SPL NFT. This renders the owner of
     /// the NFT the owner of the new piece of land.
     /// 
+    /// The payer need not be the owner of the NFT, allowing a relayer to
+    /// mint land on behalf of the NFT owner.
+    /// 
+    /// The owner of the NFT at the time of the mint is recorded on the
+    /// land asset as its minter.
+    /// 
+    /// If the land plane allows only one parcel per NFT, the NFT must not
+    /// already be bound to land, as shown by its land asset by mint account.
+    /// 
+    /// If the land plane requires a collection, the NFT's metadata must show
+    /// it in that collection, verified.
+    /// 
+    /// If the land plane has a max supply, fewer than that many pieces of
+    /// land must have been minted from it.
+    /// 
+    /// If the land plane has a mint cooldown, at least that many slots must
+    /// have passed since land was last minted from it to the NFT owner, as
+    /// shown by their wallet mint record account.
+    /// 
+    /// If the land plane has a price oracle, the mint fee charged is the
+    /// price held by that price feed account rather than the mint_fee.
+    /// 
     /// Accounts expected by this instruction:
     ///
-    /// 0. `[signer] nft_assoc_token_acc_owner_acc`
+    /// 0. `[signer, writable] payer_acc`
+    ///     Account responsible for signing and paying for the mint, including
+    ///     the land plane's mint fee and the protocol fee.
+    /// 1. `[] nft_assoc_token_acc_owner_acc`
     ///     A normal system account that is the owner of the SPL NFT holding associate token
-    ///     account. A signature is required for this account to confirm that the given owner
-    ///     would like to associate the new piece of land with their NFT.
-    /// 1. `[writable] land_asset_acc`
+    ///     account, i.e. the recipient of the new piece of land.
+    /// 2. `[writable] land_asset_acc`
     ///     This account should already exist and have been initialised through invocation
     ///     of the InitialiseNextLandAsset method on the land program.
     ///     This account should be a PDA corresponding to the next piece of land.
     ///     i.e. PDA of (['solsspace-land', land_plane_acc_pubkey, x, y], land_program_acc_pubkey)
-    /// 2. `[writable] land_plane_acc`
+    ///     If the land plane is filled at random then this is instead the PDA of the
+    ///     co-ordinate given by LandPlane::random_fill_coordinate, for the most recent
+    ///     slot hash, at the first probe whose land has not been minted, and is created
+    ///     if it does not yet exist with rent paid by the payer.
+    /// 3. `[writable] land_plane_acc`
     ///     Public key of the land plane account from which the next piece of land will be minted.
-    /// 3. `[] nft_assoc_token_acc`
+    /// 4. `[] nft_assoc_token_acc`
     ///     Public key of an SPL NFT holding account. Should be owned by given
-    ///     `nft_assoc_token_acc_owner` and should hold a balance of 1.
-    /// 4. `[] nft_mint_acc`
-    ///     The SPL NFT Mint account.
+    ///     `nft_assoc_token_acc_owner`, should hold a balance of 1 and should
+    ///     not be frozen.
+    /// 5. `[] nft_mint_acc`
+    ///     The SPL NFT Mint account. If the land plane requires a frozen mint
+    ///     then the mint authority of this account must be disabled.
+    /// 6. `[writable] land_plane_stats_acc`
+    ///     The land plane stats account, updated to record the mint.
+    ///     i.e. PDA of (['solsspace-stats', land_plane_acc_pubkey], land_program_acc_pubkey)
+    /// 7. `[] system_program_acc`
+    ///     Used to transfer the land plane's mint fee from the payer to its fee recipient.
+    /// 8. `[writable] program_config_acc`
+    ///     The program config account, giving the protocol fee charged to the payer,
+    ///     updated to count the mint.
+    ///     i.e. PDA of (['solsspace-config'], land_program_acc_pubkey)
+    /// 9. `[writable] protocol_treasury_acc`
+    ///     Account into which the protocol fee is paid. Must match the program config.
+    /// 10. `[writable] land_asset_by_mint_acc`
+    ///     Index from the SPL NFT Mint to the new piece of land, created if it does not
+    ///     yet exist with rent paid by the payer.
+    ///     i.e. PDA of (['solsspace-bymint', nft_mint_acc_pubkey], land_program_acc_pubkey)
+    /// 11. `[] rent_sysvar_acc`
+    /// 12. `[] slot_hashes_sysvar_acc`
+    ///     Only read if the land plane is filled at random, in which case the most
+    ///     recent slot hash rolls on the seed of the co-ordinate of the next mint.
+    /// 13. `[] nft_metadata_acc`
+    ///     Metaplex metadata account of the SPL NFT Mint. Only read if the land
+    ///     plane requires a collection.
+    ///     i.e. PDA of (['metadata', token_metadata_program_id, nft_mint_acc_pubkey], token_metadata_program_id)
+    /// 14. `[] clock_sysvar_acc`
+    ///     Only read if the land plane has a mint cooldown or a mint window.
+    /// 15. `[writable] wallet_mint_record_acc`
+    ///     Slot at which land was last minted to the NFT owner. Only read if the land
+    ///     plane has a mint cooldown, and created if it does not yet exist with rent
+    ///     paid by the payer.
+    ///     i.e. PDA of (['solsspace-wallet', land_plane_acc_pubkey, nft_assoc_token_acc_owner_pubkey], land_program_acc_pubkey)
+    /// 16. `[] price_oracle_acc`
+    ///     Price feed holding the mint fee. Only read, and then required, if the
+    ///     land plane has a price oracle, whose key it must be.
+    /// 17. `[writable] plane_treasury_acc`
+    ///     Account into which the mint fee is paid, i.e. LandPlane::fee_recipient.
+    ///     Only required if a mint fee is charged. Found by key.
+    /// 18. .. 18 + probes `[] probed_land_asset_acc`
+    ///     If the land plane is filled at random, the land asset account of each
+    ///     co-ordinate probed past before that of land_asset_acc, showing its land
+    ///     to have been minted. Found by key, in any order.
     MintNextLandAsset,
+
+    /// Initialise Land Plane Stats Account
+    /// 
+    /// Create and initialise the account holding cumulative totals for
+    /// a land plane. This must be done before land can be minted from the
+    /// land plane.
+    /// 
+    /// Accounts expected by this instruction:
+    /// 
+    /// 0. `[signer] rent_payer_acc`
+    ///     Key of account responsible for paying required rent for the new
+    ///     land_plane_stats_acc.
+    /// 1. `[writable] land_plane_stats_acc`
+    ///     Key of new land plane stats account.
+    ///     This key should be a PDA of:
+    ///     (['solsspace-stats', land_plane_acc_pubkey], land_program_acc_pubkey)
+    /// 2. `[] land_plane_acc`
+    ///     Public key of the land plane account to which the stats belong.
+    /// 3. `[] rent_sysvar_acc`
+    /// 4. `[] system_program_acc`
+    InitialiseLandPlaneStats,
+
+    /// Initialise Land Plane Account Idempotently
+    /// 
+    /// As `InitialiseLandPlane`, except that if the land plane account is
+    /// already initialised with the given configuration then nothing is
+    /// done and the instruction succeeds. This allows clients to safely
+    /// retry initialisation. If the account is already initialised with a
+    /// different configuration then the instruction fails.
+    /// 
+    /// Accounts expected by this instruction:
+    /// 
+    /// 0. `[writable] land_place_acc`
+    ///     Land plane account to initialise.
+    /// 1. `[] rent_sysvar_acc`
+    /// 2. `[writable] program_config_acc`
+    ///     The program config account, updated to count the new land plane.
+    ///     i.e. PDA of (['solsspace-config'], land_program_acc_pubkey)
+    InitialiseLandPlaneIdempotent {
+        /// Optional hard ceiling on the x and z co-ordinates of minted land.
+        coordinate_ceiling: Option<u64>,
+        /// Only allow land to be bound to NFTs whose mint authority is disabled.
+        require_frozen_mint: bool,
+        /// Lamports charged for each piece of land minted.
+        mint_fee: u64,
+        /// Optional owner of the land plane.
+        owner: Option<Pubkey>,
+        /// Order in which land is assigned to each mint.
+        mint_pattern: MintPattern,
+        /// Only allow land to be bound to NFTs not already bound to land.
+        one_parcel_per_nft: bool,
+        /// Royalty, in basis points of the sale price, paid on each transfer.
+        royalty_bps: u16,
+        /// Encode co-ordinates in land asset addresses in as few bytes as
+        /// the coordinate ceiling allows.
+        compact_addresses: bool,
+        /// Only allow land to be bound to NFTs in this verified collection.
+        required_collection: Option<Pubkey>,
+        /// Maximum number of pieces of land that may be minted.
+        max_supply: Option<u64>,
+        /// Minimum number of slots between mints by the same wallet, zero for none.
+        mint_cooldown_slots: u64,
+        /// Price feed from which to read the mint fee, if any.
+        price_oracle: Option<Pubkey>,
+        /// Account that may claim land left unminted once the sale window has closed, if any.
+        reserve_claimant: Option<Pubkey>,
+        /// Slot after which the sale window is closed, if any.
+        closes_at_slot: Option<u64>,
+    },
+
+    /// Stamp Co-ordinate URI
+    /// 
+    /// Append the co-ordinate of a minted piece of land to the URI of the
+    /// metadata of the NFT to which it is bound, through the Metaplex Token
+    /// Metadata program. Only the update authority of the NFT's metadata
+    /// may do this.
+    /// 
+    /// Accounts expected by this instruction:
+    /// 
+    /// 0. `[signer] update_authority_acc`
+    ///     Update authority of the NFT's metadata account.
+    /// 1. `[] land_asset_acc`
+    ///     The minted piece of land.
+    ///     i.e. PDA of (['solsspace-land', land_plane_acc_pubkey, x, y], land_program_acc_pubkey)
+    /// 2. `[] land_plane_acc`
+    ///     Public key of the land plane account from which the land was minted.
+    /// 3. `[writable] nft_metadata_acc`
+    ///     Metaplex metadata account of the NFT to which the land is bound.
+    ///     i.e. PDA of (['metadata', token_metadata_program_id, nft_mint_pubkey], token_metadata_program_id)
+    /// 4. `[] token_metadata_program_acc`
+    StampCoordinateUri {
+        /// x co-ordinate of the piece of land.
+        x: u64,
+        /// z co-ordinate of the piece of land.
+        z: u64,
+    },
+
+    /// Initialise Program Config Account
+    /// 
+    /// Create and initialise the program wide config account. This must be
+    /// done once, before any land can be minted, and may only be done by the
+    /// upgrade authority of the land program.
+    /// 
+    /// Accounts expected by this instruction:
+    /// 
+    /// 0. `[signer, writable] upgrade_authority_acc`
+    ///     Upgrade authority of the land program, responsible for paying required
+    ///     rent for the new program_config_acc.
+    /// 1. `[writable] program_config_acc`
+    ///     Key of new program config account.
+    ///     This key should be a PDA of:
+    ///     (['solsspace-config'], land_program_acc_pubkey)
+    /// 2. `[] program_data_acc`
+    ///     The land program's program data account, held by the upgradeable BPF loader.
+    ///     i.e. PDA of ([land_program_acc_pubkey], bpf_loader_upgradeable_program_id)
+    /// 3. `[] rent_sysvar_acc`
+    /// 4. `[] system_program_acc`
+    InitialiseProgramConfig {
+        /// Lamports charged for each piece of land minted from any land plane.
+        protocol_fee: u64,
+        /// Account into which protocol fees are paid.
+        protocol_treasury: Pubkey,
+    },
+
+    /// Transfer Land
+    /// 
+    /// Rebind a minted piece of land from the NFT to which it is bound to
+    /// another NFT, which then becomes the owner of the land. Only the
+    /// holder of the NFT to which the land is currently bound may do this.
+    /// 
+    /// The land asset by mint account of the new NFT is pointed at the
+    /// land, and that of the current NFT is closed if it pointed at the
+    /// land, with its rent returned to the payer.
+    /// 
+    /// If the land plane allows only one parcel per NFT, the new NFT must
+    /// not already be bound to other land, as shown by its land asset by
+    /// mint account.
+    /// 
+    /// If the land plane charges a royalty, royalty_bps basis points of
+    /// the given sale price are paid by the payer into the land plane
+    /// account.
+    /// 
+    /// The transfer is counted on the land asset, if its account was
+    /// allocated at V7 or later.
+    /// 
+    /// Land that has been locked by LockParcel cannot be transferred until
+    /// it is unlocked, nor can land merged into an estate by MergeParcels.
+    /// 
+    /// Accounts expected by this instruction:
+    /// 
+    /// 0. `[signer, writable] payer_acc`
+    ///     Account responsible for paying the royalty of the land plane, and
+    ///     required rent for the new_land_asset_by_mint_acc, if it does not
+    ///     yet exist.
+    /// 1. `[signer] nft_assoc_token_acc_owner_acc`
+    ///     Holder of the NFT to which the land is currently bound.
+    /// 2. `[writable] land_asset_acc`
+    ///     The minted piece of land.
+    ///     i.e. PDA of (['solsspace-land', land_plane_acc_pubkey, x, y], land_program_acc_pubkey)
+    /// 3. `[writable] land_plane_acc`
+    ///     Public key of the land plane account from which the land was minted.
+    /// 4. `[] nft_assoc_token_acc`
+    ///     SPL NFT holding account of the current NFT. Should be owned by the given
+    ///     `nft_assoc_token_acc_owner` and should hold a balance of 1.
+    /// 5. `[writable] land_asset_by_mint_acc`
+    ///     Index of the current NFT.
+    ///     i.e. PDA of (['solsspace-bymint', nft_mint_acc_pubkey], land_program_acc_pubkey)
+    /// 6. `[] new_nft_mint_acc`
+    ///     The SPL NFT Mint account of the NFT to which the land will be bound.
+    /// 7. `[writable] new_land_asset_by_mint_acc`
+    ///     Index of the new NFT, created if it does not yet exist.
+    ///     i.e. PDA of (['solsspace-bymint', new_nft_mint_acc_pubkey], land_program_acc_pubkey)
+    /// 8. `[] rent_sysvar_acc`
+    /// 9. `[] system_program_acc`
+    /// 10. `[writable] plane_treasury_acc`
+    ///     Account into which the royalty is paid, i.e. LandPlane::fee_recipient.
+    ///     Only required if a royalty is charged. Found by key.
+    TransferLand {
+        /// x co-ordinate of the piece of land.
+        x: u64,
+        /// z co-ordinate of the piece of land.
+        z: u64,
+        /// Lamports for which the land was sold, on which the royalty
+        /// of the land plane is charged.
+        sale_price: u64,
+    },
+
+    /// Repair Parcel Co-ordinate
+    /// 
+    /// Recompute the co-ordinate of a minted piece of land from the address
+    /// of its land asset account and write it, along with the land plane, to
+    /// the land asset. This repairs land minted before the co-ordinate was
+    /// recorded on the land asset, for which it reads as (0, 0). Only the
+    /// owner of the land plane may do this.
+    /// 
+    /// The co-ordinate is found by deriving the land asset account address
+    /// of each piece of land minted from the land plane in turn, so this is
+    /// only affordable for land planes with few pieces of land minted. Land
+    /// asset accounts written at a version older than V3 are too short to
+    /// hold the co-ordinate and cannot be repaired.
+    /// 
+    /// Accounts expected by this instruction:
+    /// 
+    /// 0. `[signer] land_plane_owner_acc`
+    ///     Owner of the land plane.
+    /// 1. `[writable] land_asset_acc`
+    ///     The minted piece of land.
+    ///     i.e. PDA of (['solsspace-land', land_plane_acc_pubkey, x, y], land_program_acc_pubkey)
+    /// 2. `[] land_plane_acc`
+    ///     Public key of the land plane account from which the land was minted.
+    RepairParcelCoordinate,
+
+    /// Process Batch
+    /// 
+    /// Process a sequence of land program instructions, in order, within
+    /// a single instruction. This allows for example a land asset account
+    /// to be initialised and then minted in one program invocation.
+    /// 
+    /// Each sub-instruction is given the accounts of this instruction at
+    /// its account indices, in order, and so expects them exactly as it
+    /// would as a top level instruction. If any sub-instruction fails then
+    /// this instruction fails, and so the changes made by every earlier
+    /// sub-instruction are rolled back along with it.
+    /// 
+    /// Sub-instructions may not themselves be ProcessBatch instructions,
+    /// and at most MAX_BATCH_COUNT may be given.
+    /// 
+    /// Accounts expected by this instruction:
+    /// 
+    /// The accounts of every sub-instruction, each given once, as built by
+    /// the `process_batch` function.
+    ProcessBatch {
+        /// Sub-instructions to process, in order.
+        instructions: Vec<BatchedInstruction>,
+    },
+
+    /// Setup Plane
+    /// 
+    /// Create and initialise a new land plane account along with its land
+    /// plane stats account in a single instruction, so that land may be
+    /// minted from the land plane once it completes. This does the work of
+    /// InitialiseLandPlane and InitialiseLandPlaneStats together, with the
+    /// land plane account created here rather than beforehand.
+    /// 
+    /// Accounts expected by this instruction:
+    /// 
+    /// 0. `[signer, writable] payer_acc`
+    ///     Account responsible for paying required rent for the new
+    ///     land_plane_acc and land_plane_stats_acc.
+    /// 1. `[signer, writable] land_plane_acc`
+    ///     New land plane account, which must not yet exist.
+    /// 2. `[writable] land_plane_stats_acc`
+    ///     Key of new land plane stats account.
+    ///     This key should be a PDA of:
+    ///     (['solsspace-stats', land_plane_acc_pubkey], land_program_acc_pubkey)
+    /// 3. `[] rent_sysvar_acc`
+    /// 4. `[] system_program_acc`
+    /// 5. `[writable] program_config_acc`
+    ///     The program config account, updated to count the new land plane.
+    ///     i.e. PDA of (['solsspace-config'], land_program_acc_pubkey)
+    SetupPlane {
+        /// Optional hard ceiling on the x and z co-ordinates of minted land.
+        coordinate_ceiling: Option<u64>,
+        /// Only allow land to be bound to NFTs whose mint authority is disabled.
+        require_frozen_mint: bool,
+        /// Lamports charged for each piece of land minted.
+        mint_fee: u64,
+        /// Optional owner of the land plane.
+        owner: Option<Pubkey>,
+        /// Order in which land is assigned to each mint.
+        mint_pattern: MintPattern,
+        /// Only allow land to be bound to NFTs not already bound to land.
+        one_parcel_per_nft: bool,
+        /// Royalty, in basis points of the sale price, paid on each transfer.
+        royalty_bps: u16,
+        /// Encode co-ordinates in land asset addresses in as few bytes as
+        /// the coordinate ceiling allows.
+        compact_addresses: bool,
+        /// Only allow land to be bound to NFTs in this verified collection.
+        required_collection: Option<Pubkey>,
+        /// Maximum number of pieces of land that may be minted.
+        max_supply: Option<u64>,
+        /// Minimum number of slots between mints by the same wallet, zero for none.
+        mint_cooldown_slots: u64,
+        /// Price feed from which to read the mint fee, if any.
+        price_oracle: Option<Pubkey>,
+        /// Account that may claim land left unminted once the sale window has closed, if any.
+        reserve_claimant: Option<Pubkey>,
+        /// Slot after which the sale window is closed, if any.
+        closes_at_slot: Option<u64>,
+    },
+
+    /// Migrate Assets Batch
+    /// 
+    /// Bring a contiguous run of land asset accounts, in mint order, up to
+    /// the current version, recording on each the land plane and its
+    /// co-ordinate as RepairParcelCoordinate does. Progress is recorded on
+    /// the land plane so that migrating every land asset account of the
+    /// land plane may be spread over many transactions. Each batch must
+    /// start where the last one ended, from index 0. Only the owner of the
+    /// land plane may do this.
+    /// 
+    /// Land not yet minted, and land asset accounts already at the current
+    /// version, are passed over, as are land asset accounts allocated at a
+    /// version older than V3 that are too short to hold the co-ordinate.
+    /// Each is still counted towards the progress of the migration.
+    /// 
+    /// Accounts expected by this instruction:
+    /// 
+    /// 0. `[signer] land_plane_owner_acc`
+    ///     Owner of the land plane.
+    /// 1. `[writable] land_plane_acc`
+    ///     Public key of the land plane account from which the land was minted.
+    /// 2. .. 2 + count `[writable] land_asset_acc`
+    ///     Land asset account of the piece of land minted at each index from
+    ///     start_index, in order, see asset_address_at_index.
+    MigrateAssetsBatch {
+        /// Index in mint order of the first land asset account to migrate,
+        /// which must be the land plane's migrated_count.
+        start_index: u64,
+        /// Number of land asset accounts to migrate.
+        count: u8,
+    },
+
+    /// Force Set Cursor
+    /// 
+    /// Set the next co-ordinate of a land plane, and so its minted_count,
+    /// to a known good state. This is a break-glass tool for recovering
+    /// from a bug, and only the upgrade authority of the land program may
+    /// do it. The co-ordinate must satisfy LandPlane::validate_invariants
+    /// and lie within the coordinate ceiling, if any, unless it is the
+    /// first co-ordinate of the shell beyond the ceiling, at which the
+    /// land plane is left complete once all of its land is minted.
+    /// 
+    /// Accounts expected by this instruction:
+    /// 
+    /// 0. `[signer] upgrade_authority_acc`
+    ///     Upgrade authority of the land program.
+    /// 1. `[writable] land_plane_acc`
+    ///     Public key of the land plane account.
+    /// 2. `[] program_data_acc`
+    ///     Program data account of the land program, recording its upgrade authority.
+    ///     i.e. PDA of ([land_program_acc_pubkey], bpf_loader_upgradeable_program_id)
+    ForceSetCursor {
+        next_x: u64,
+        next_z: u64,
+        depth: u64,
+    },
+
+    /// Set Parcel Attributes
+    /// 
+    /// Set the bits of the attributes of a piece of land selected by mask
+    /// to those of value, leaving the others unchanged. Only the owner of
+    /// the land plane may do this. The land need not have been minted, but
+    /// its land asset account must have been allocated at V4 or later,
+    /// since older accounts are too short to hold the attributes.
+    /// 
+    /// Accounts expected by this instruction:
+    /// 
+    /// 0. `[signer] land_plane_owner_acc`
+    ///     Owner of the land plane.
+    /// 1. `[writable] land_asset_acc`
+    ///     Land asset account of the piece of land.
+    /// 2. `[] land_plane_acc`
+    ///     Public key of the land plane account of the land.
+    SetParcelAttributes {
+        /// Bits of the attributes to set.
+        mask: u32,
+        /// Values of the bits selected by mask.
+        value: u32,
+    },
+
+    /// Merge Parcels
+    /// 
+    /// Merge a 2x2 block of minted land into a single estate. The pieces
+    /// of land must lie on the same land plane at (x, z), (x + 1, z),
+    /// (x, z + 1) and (x + 1, z + 1), none may already be part of an
+    /// estate, and the signer must hold the NFT to which each is bound.
+    /// The land at (x, z) becomes the primary parcel of the estate, and
+    /// the other three are marked as merged into it. Land asset accounts
+    /// must have been allocated at V5 or later, since older accounts are
+    /// too short to record the estate.
+    /// 
+    /// Accounts expected by this instruction:
+    /// 
+    /// 0. `[signer] nft_assoc_token_acc_owner_acc`
+    ///     Holder of the NFTs to which the pieces of land are bound.
+    /// 1. `[writable] primary_land_asset_acc`
+    ///     Land asset account of the piece of land at (x, z).
+    /// 2. `[writable] land_asset_acc`
+    ///     Land asset account of the piece of land at (x + 1, z).
+    /// 3. `[writable] land_asset_acc`
+    ///     Land asset account of the piece of land at (x, z + 1).
+    /// 4. `[writable] land_asset_acc`
+    ///     Land asset account of the piece of land at (x + 1, z + 1).
+    /// 5. `[] land_plane_acc`
+    ///     Public key of the land plane account of the land.
+    /// 6. `[] nft_assoc_token_acc`
+    ///     SPL NFT holding account of the NFT bound to the land at (x, z).
+    /// 7. `[] nft_assoc_token_acc`
+    ///     SPL NFT holding account of the NFT bound to the land at (x + 1, z).
+    /// 8. `[] nft_assoc_token_acc`
+    ///     SPL NFT holding account of the NFT bound to the land at (x, z + 1).
+    /// 9. `[] nft_assoc_token_acc`
+    ///     SPL NFT holding account of the NFT bound to the land at (x + 1, z + 1).
+    MergeParcels,
+
+    /// Set Plane Metadata
+    /// 
+    /// Set the collection level metadata of a land plane, which frontends
+    /// read to label the land minted from it. The plane metadata account
+    /// is created on the first call, and overwritten on later ones. Only
+    /// the owner of the land plane may do this.
+    /// 
+    /// Accounts expected by this instruction:
+    /// 
+    /// 0. `[signer, writable] land_plane_owner_acc`
+    ///     Owner of the land plane, responsible for paying required rent
+    ///     for the new plane_metadata_acc.
+    /// 1. `[writable] plane_metadata_acc`
+    ///     Key of the plane metadata account.
+    ///     This key should be a PDA of:
+    ///     (['solsspace-metadata', land_plane_acc_pubkey], land_program_acc_pubkey)
+    /// 2. `[] land_plane_acc`
+    ///     Public key of the land plane account to which the metadata belongs.
+    /// 3. `[] rent_sysvar_acc`
+    /// 4. `[] system_program_acc`
+    SetPlaneMetadata {
+        /// Name of the collection.
+        name: BoundedString<MAX_PLANE_NAME_LEN>,
+        /// Symbol of the collection.
+        symbol: BoundedString<MAX_PLANE_SYMBOL_LEN>,
+        /// URI under which the metadata of each piece of land is found.
+        base_uri: BoundedString<MAX_PLANE_BASE_URI_LEN>,
+    },
+
+    /// Verify Parcel Ownership
+    /// 
+    /// Confirm that the signer still holds the NFT to which a minted piece
+    /// of land is bound, without changing anything, e.g. for marketplaces
+    /// and games that periodically re-check ownership. The outcome is
+    /// logged, see PARCEL_OWNERSHIP_LOG_PREFIX, and the instruction fails
+    /// if the signer does not hold the NFT.
+    /// 
+    /// Accounts expected by this instruction:
+    /// 
+    /// 0. `[signer] nft_assoc_token_acc_owner_acc`
+    ///     Holder of the NFT to which the land is bound.
+    /// 1. `[] land_asset_acc`
+    ///     The minted piece of land.
+    ///     i.e. PDA of (['solsspace-land', land_plane_acc_pubkey, x, y], land_program_acc_pubkey)
+    /// 2. `[] land_plane_acc`
+    ///     Public key of the land plane account from which the land was minted.
+    /// 3. `[] nft_assoc_token_acc`
+    ///     SPL NFT holding account of the NFT to which the land is bound.
+    VerifyParcelOwnership,
+
+    /// Admin Relink Parcel
+    /// 
+    /// Rebind a minted piece of land to another NFT without the signature
+    /// of the holder of the NFT to which it is bound, e.g. where that NFT
+    /// has been lost and reissued by its collection operator. This is a
+    /// recovery tool, and only the owner of the land plane may do it. The
+    /// new mint must be that of an NFT, i.e. initialised with a supply of
+    /// one and no decimals.
+    /// 
+    /// The land asset by mint accounts are updated as for TransferLand,
+    /// with any rent returned to, or paid by, the owner of the land plane.
+    /// 
+    /// Land that has been locked by LockParcel cannot be relinked until it
+    /// is unlocked, nor can land merged into an estate by MergeParcels.
+    /// 
+    /// Accounts expected by this instruction:
+    /// 
+    /// 0. `[signer, writable] land_plane_owner_acc`
+    ///     Owner of the land plane, responsible for paying required rent for
+    ///     the new_land_asset_by_mint_acc, if it does not yet exist.
+    /// 1. `[writable] land_asset_acc`
+    ///     The minted piece of land.
+    ///     i.e. PDA of (['solsspace-land', land_plane_acc_pubkey, x, y], land_program_acc_pubkey)
+    /// 2. `[] land_plane_acc`
+    ///     Public key of the land plane account from which the land was minted.
+    /// 3. `[writable] land_asset_by_mint_acc`
+    ///     Index of the NFT to which the land is currently bound.
+    ///     i.e. PDA of (['solsspace-bymint', nft_mint_acc_pubkey], land_program_acc_pubkey)
+    /// 4. `[] new_nft_mint_acc`
+    ///     The SPL NFT Mint account of new_mint.
+    /// 5. `[writable] new_land_asset_by_mint_acc`
+    ///     Index of the new NFT, created if it does not yet exist.
+    ///     i.e. PDA of (['solsspace-bymint', new_mint], land_program_acc_pubkey)
+    /// 6. `[] rent_sysvar_acc`
+    /// 7. `[] system_program_acc`
+    AdminRelinkParcel {
+        /// Mint of the NFT to which the land will be bound.
+        new_mint: Pubkey,
+    },
+
+    /// Mint Next With Attributes
+    /// 
+    /// Mint the next piece of land as for MintNextLandAsset, then set its
+    /// attributes, e.g. to tag special parcels as they are minted. Only
+    /// the owner of the land plane may set nonzero attributes, which it
+    /// does as the payer, so that holders cannot tag their own land.
+    /// 
+    /// Accounts expected by this instruction are those of
+    /// MintNextLandAsset, in the same order.
+    MintNextWithAttributes {
+        /// Attributes of the minted land, zero for none.
+        attributes: u32,
+    },
+
+    /// Lock Parcel
+    /// 
+    /// Lock a minted piece of land on behalf of the holder of the NFT to
+    /// which it is bound, e.g. while it is staked, so that it cannot be
+    /// transferred until the locker unlocks it with UnlockParcel. Land
+    /// that is already locked cannot be locked again, and the land asset
+    /// account must have been allocated at V8 or later.
+    /// 
+    /// Accounts expected by this instruction:
+    /// 
+    /// 0. `[signer] nft_assoc_token_acc_owner_acc`
+    ///     Holder of the NFT to which the land is bound.
+    /// 1. `[writable] land_asset_acc`
+    ///     The minted piece of land.
+    ///     i.e. PDA of (['solsspace-land', land_plane_acc_pubkey, x, y], land_program_acc_pubkey)
+    /// 2. `[] land_plane_acc`
+    ///     Public key of the land plane account from which the land was minted.
+    /// 3. `[] nft_assoc_token_acc`
+    ///     SPL NFT holding account of the NFT to which the land is bound.
+    LockParcel {
+        /// Account, e.g. a staking program, that may unlock the land.
+        locker: Pubkey,
+    },
+
+    /// Unlock Parcel
+    /// 
+    /// Unlock a piece of land locked by LockParcel. Only the locker given
+    /// when the land was locked may do this.
+    /// 
+    /// Accounts expected by this instruction:
+    /// 
+    /// 0. `[signer] locker_acc`
+    ///     Locker by which the land was locked.
+    /// 1. `[writable] land_asset_acc`
+    ///     The locked piece of land.
+    ///     i.e. PDA of (['solsspace-land', land_plane_acc_pubkey, x, y], land_program_acc_pubkey)
+    /// 2. `[] land_plane_acc`
+    ///     Public key of the land plane account from which the land was minted.
+    UnlockParcel,
+
+    /// Mint Next With Deadline
+    /// 
+    /// Mint the next piece of land as for MintNextLandAsset, unless the
+    /// current slot is past the given slot, e.g. so that a transaction
+    /// delayed for long before it is processed does not mint land its
+    /// signer may no longer want.
+    /// 
+    /// Accounts expected by this instruction are those of
+    /// MintNextLandAsset, in the same order, where the clock sysvar is
+    /// always read and so required, even if optional accounts given
+    /// ahead of it are omitted.
+    MintNextWithDeadline {
+        /// Last slot at which the land may be minted.
+        valid_until_slot: u64,
+    },
+
+    /// Claim Reserved Parcel
+    /// 
+    /// Mint land left unminted once the sale window of the land plane has
+    /// closed, i.e. once the current slot is past its closes_at_slot, on
+    /// behalf of its reserve claimant. The land is bound to the given NFT
+    /// without the signature of its holder, and no fees are charged. The
+    /// land plane's other requirements of the NFT, such as a frozen mint
+    /// or a collection, are not checked, the reserve claimant being trusted
+    /// to choose it, but it must be an SPL NFT.
+    /// 
+    /// For land planes minted in shells the land must be the next piece of
+    /// land in the minting pattern, so that the remaining land is claimed
+    /// in order. For land planes filled at random it may be any piece of
+    /// land within the coordinate_ceiling not yet minted.
+    /// 
+    /// Accounts expected by this instruction:
+    /// 
+    /// 0. `[signer, writable] reserve_claimant_acc`
+    ///     Reserve claimant of the land plane, responsible for paying required
+    ///     rent for the land_asset_by_mint_acc and, for land filled at random,
+    ///     the land_asset_acc, if they do not yet exist.
+    /// 1. `[writable] land_asset_acc`
+    ///     The piece of land to claim.
+    ///     i.e. PDA of (['solsspace-land', land_plane_acc_pubkey, x, y], land_program_acc_pubkey)
+    /// 2. `[writable] land_plane_acc`
+    ///     Public key of the land plane account from which the land is claimed.
+    /// 3. `[] nft_mint_acc`
+    ///     The SPL NFT Mint account of the NFT to which the land will be bound.
+    /// 4. `[writable] land_plane_stats_acc`
+    ///     The land plane stats account, updated to record the mint.
+    ///     i.e. PDA of (['solsspace-stats', land_plane_acc_pubkey], land_program_acc_pubkey)
+    /// 5. `[writable] land_asset_by_mint_acc`
+    ///     Index from the SPL NFT Mint to the claimed piece of land, created if it
+    ///     does not yet exist.
+    ///     i.e. PDA of (['solsspace-bymint', nft_mint_acc_pubkey], land_program_acc_pubkey)
+    /// 6. `[] clock_sysvar_acc`
+    /// 7. `[] rent_sysvar_acc`
+    /// 8. `[] system_program_acc`
+    ClaimReservedParcel {
+        /// x co-ordinate of the piece of land.
+        x: u64,
+        /// z co-ordinate of the piece of land.
+        z: u64,
+    },
+
+    /// Check Coordinate
+    /// 
+    /// Report what lies at a co-ordinate of a land plane without changing
+    /// anything: whether its land asset account has been initialised,
+    /// whether the land has been minted and, if so, to which NFT it is
+    /// bound. The outcome is logged, see COORDINATE_OCCUPANCY_LOG_PREFIX.
+    /// The land asset account need not exist, but MUST be that of the
+    /// given co-ordinate.
+    /// 
+    /// Accounts expected by this instruction:
+    /// 
+    /// 0. `[] land_asset_acc`
+    ///     The piece of land at the co-ordinate.
+    ///     i.e. PDA of (['solsspace-land', land_plane_acc_pubkey, x, y], land_program_acc_pubkey)
+    /// 1. `[] land_plane_acc`
+    ///     Public key of the land plane account on which the co-ordinate lies.
+    CheckCoordinate {
+        /// x co-ordinate of the piece of land.
+        x: u64,
+        /// z co-ordinate of the piece of land.
+        z: u64,
+    },
+
+    /// Emit Parcel Range
+    /// 
+    /// Log the state of the land asset accounts of a run of land minted
+    /// consecutively from a land plane, without changing anything, so that
+    /// a single simulated transaction may read many parcels. The states are
+    /// logged packed in one line, see PARCEL_RANGE_LOG_PREFIX. Land asset
+    /// accounts that do not yet exist are logged as uninitialised. As for
+    /// MigrateAssetsBatch, only meaningful for land planes minted in shells,
+    /// and at most MAX_PARCEL_RANGE_COUNT accounts may be given.
+    /// 
+    /// Accounts expected by this instruction:
+    /// 
+    /// 0. `[] land_plane_acc`
+    ///     Public key of the land plane account from which the land was minted.
+    /// 1. .. 1 + count `[] land_asset_acc`
+    ///     Land asset account of the piece of land minted at each index from
+    ///     start_index, in order, see asset_address_at_index.
+    EmitParcelRange {
+        /// Index in mint order of the first land asset account to log.
+        start_index: u64,
+        /// Number of land asset accounts to log.
+        count: u8,
+    },
+
+    /// Set Mint Window
+    /// 
+    /// Set the slots between which land may be minted from a land plane,
+    /// e.g. for a timed drop, replacing any window set earlier. Either end
+    /// may be left open, and both ends are inclusive. Only the owner of the
+    /// land plane may do this.
+    /// 
+    /// Accounts expected by this instruction:
+    /// 
+    /// 0. `[signer] land_plane_owner_acc`
+    ///     Owner of the land plane.
+    /// 1. `[writable] land_plane_acc`
+    ///     Public key of the land plane account.
+    SetMintWindow {
+        /// Slot before which land may not be minted, if any.
+        mint_start_slot: Option<u64>,
+        /// Slot after which land may not be minted, if any.
+        mint_end_slot: Option<u64>,
+    },
+
+    /// Set Plane Treasury
+    /// 
+    /// Set the account into which the mint fees and royalties of a land
+    /// plane are paid, replacing any set earlier, or clear it so that they
+    /// are paid to the owner of the land plane. Only the owner of the land
+    /// plane may do this.
+    /// 
+    /// Accounts expected by this instruction:
+    /// 
+    /// 0. `[signer] land_plane_owner_acc`
+    ///     Owner of the land plane.
+    /// 1. `[writable] land_plane_acc`
+    ///     Public key of the land plane account.
+    SetPlaneTreasury {
+        /// Account into which fees are paid, if not the owner.
+        plane_treasury: Option<Pubkey>,
+    },
+}
+
+/// Maximum number of sub-instructions in a ProcessBatch instruction,
+/// bounding the compute and the accounts created by a single batch.
+pub const MAX_BATCH_COUNT: usize = 16;
+
+/// Maximum number of land asset accounts in an EmitParcelRange
+/// instruction, bounding its log line well within the log size limit
+/// of a transaction.
+pub const MAX_PARCEL_RANGE_COUNT: usize = 16;
+
+/// Sub-instruction of a `ProcessBatch` instruction.
+/// 
+/// Borsh is implemented by hand since the derived implementations bound
+/// each field on its own implementation, which never resolves for the
+/// recursive LandInstruction.
+#[derive(Clone)]
+pub struct BatchedInstruction {
+    /// Indices into the accounts of the `ProcessBatch` instruction of the
+    /// accounts expected by the sub-instruction, in order.
+    pub account_indices: Vec<u8>,
+    /// The sub-instruction.
+    pub instruction: LandInstruction,
+}
+
+impl BorshSerialize for BatchedInstruction {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.account_indices.serialize(writer)?;
+        self.instruction.serialize(writer)
+    }
+}
+
+impl BorshDeserialize for BatchedInstruction {
+    fn deserialize(buf: &mut &[u8]) -> io::Result<Self> {
+        Ok(BatchedInstruction {
+            account_indices: Vec::deserialize(buf)?,
+            instruction: LandInstruction::deserialize(buf)?,
+        })
+    }
 }
 
 /// Creates an `InitialiseLandPlane` instruction.
+/// 
+/// * `land_program_acc_pubkey`
+///     Public key of the land program account - aka. program ID.
+/// * `[writable] land_plane_acc_pubkey`
+///     Public key of the land plane account to initialise.
+/// * `coordinate_ceiling`
+///     Optional hard ceiling on the x and z co-ordinates of minted land.
+/// * `require_frozen_mint`
+///     Only allow land to be bound to NFTs whose mint authority is disabled.
+/// * `mint_fee`
+///     Lamports charged for each piece of land minted.
+/// * `owner`
+///     Optional owner of the land plane.
+/// * `mint_pattern`
+///     Order in which land is assigned to each mint. RandomFill requires
+///     a coordinate_ceiling.
+/// * `one_parcel_per_nft`
+///     Only allow land to be bound to NFTs not already bound to land.
+/// * `royalty_bps`
+///     Royalty, in basis points of the sale price, paid on each transfer.
+/// * `compact_addresses`
+///     Encode co-ordinates in land asset addresses in as few bytes as the
+///     coordinate ceiling allows. Requires a coordinate_ceiling.
+/// * `required_collection`
+///     Only allow land to be bound to NFTs in this verified Metaplex collection.
+/// * `max_supply`
+///     Maximum number of pieces of land that may be minted from the land
+///     plane, regardless of its coordinate_ceiling.
+/// * `mint_cooldown_slots`
+///     Minimum number of slots between mints of land by the same wallet, or
+///     zero for no cooldown.
+/// * `price_oracle`
+///     Price feed account from which MintNextLandAsset reads the mint fee,
+///     in place of mint_fee, or None to charge mint_fee.
+/// * `reserve_claimant`
+///     Account that may claim land left unminted through ClaimReservedParcel
+///     once the sale window has closed, or None for no reserve claimant.
+/// * `closes_at_slot`
+///     Slot after which the sale window is closed, or None if it never closes.
+/// 
+/// The program config account is derived and passed to the instruction.
+#[allow(clippy::too_many_arguments)]
 pub fn initialize_land_plane(
     land_program_acc_pubkey: &Pubkey,
     land_plane_acc_pubkey: &Pubkey,
+    coordinate_ceiling: Option<u64>,
+    require_frozen_mint: bool,
+    mint_fee: u64,
+    owner: Option<Pubkey>,
+    mint_pattern: MintPattern,
+    one_parcel_per_nft: bool,
+    royalty_bps: u16,
+    compact_addresses: bool,
+    required_collection: Option<Pubkey>,
+    max_supply: Option<u64>,
+    mint_cooldown_slots: u64,
+    price_oracle: Option<Pubkey>,
+    reserve_claimant: Option<Pubkey>,
+    closes_at_slot: Option<u64>,
 ) -> Result<Instruction, ProgramError> {
     check_program_account(land_program_acc_pubkey)?;
-    let data = LandInstruction::InitialiseLandPlane.try_to_vec().unwrap();
+    let data = LandInstruction::InitialiseLandPlane {
+        coordinate_ceiling,
+        require_frozen_mint,
+        mint_fee,
+        owner,
+        mint_pattern,
+        one_parcel_per_nft,
+        royalty_bps,
+        compact_addresses,
+        required_collection,
+        max_supply,
+        mint_cooldown_slots,
+        price_oracle,
+        reserve_claimant,
+        closes_at_slot,
+    }.try_to_vec().unwrap();
 
     // prepare list of account to pass to the instruction
     let accounts = vec![
@@ -100,6 +1040,8 @@ pub fn initialize_land_plane(
         AccountMeta::new(*land_plane_acc_pubkey, false),
         // those that require read-only access
         AccountMeta::new_readonly(sysvar::rent::id(), false),
+        // those that require write access
+        AccountMeta::new(program_config_acc_pubkey(land_program_acc_pubkey), false),
     ];
 
     Ok(Instruction {
@@ -121,23 +1063,28 @@ pub fn initialize_land_plane(
 ///     i.e. PDA of (['solsspace-land', land_plane_acc_pubkey, x, y], land_program_acc_pubkey)
 /// * `[] land_plane_acc_pubkey`
 ///     Public key of the land plane account from which the next piece of land will be minted.
+/// * `[signer] authority_acc_pubkey`
+///     Optional public key of the account authorising the initialisation,
+///     where it is not the rent payer. For a land plane with an owner, it
+///     must be the owner.
 pub fn initialize_next_land_asset(
     land_program_acc_pubkey: &Pubkey,
     rent_payer_acc_pubkey: &Pubkey,
     land_asset_acc_pubkey: &Pubkey,
     land_plane_acc_pubkey: &Pubkey,
+    authority_acc_pubkey: Option<&Pubkey>,
 ) -> Result<Instruction, ProgramError> {
     check_program_account(land_program_acc_pubkey)?;
     let data = LandInstruction::InitialiseNextLandAsset.try_to_vec().unwrap();
 
     // prepare list of account to pass to the instruction
-    let accounts = vec![
+    let mut accounts = vec![
         // 1st
         // Addresses requiring signatures are 1st, and in the following order:
         //
         // those that require write access
         // those that require read-only access
-        AccountMeta::new(*rent_payer_acc_pubkey, false),        
+        AccountMeta::new(*rent_payer_acc_pubkey, true),
 
         // 2nd
         // Addresses not requiring signatures are 2nd, and in the following order:
@@ -149,6 +1096,10 @@ pub fn initialize_next_land_asset(
         AccountMeta::new_readonly(sysvar::rent::id(), false),
         AccountMeta::new_readonly(system_program::id(), false),
     ];
+    // the authority is only given where it is not the rent payer
+    if let Some(authority_acc_pubkey) = authority_acc_pubkey {
+        accounts.push(AccountMeta::new_readonly(*authority_acc_pubkey, true));
+    }
 
     Ok(Instruction {
         program_id: *land_program_acc_pubkey,
@@ -161,10 +1112,12 @@ pub fn initialize_next_land_asset(
 /// 
 /// * `land_program_acc_pubkey`
 ///     Public key of the land program account - aka. program ID.
-/// * `[signer] nft_assoc_token_acc_owner_pubkey`
+/// * `[signer, writable] payer_acc_pubkey`
+///     Public key of the account responsible for signing and paying for the mint.
+///     This may be the same as `nft_assoc_token_acc_owner_pubkey`.
+/// * `[] nft_assoc_token_acc_owner_pubkey`
 ///     Public key of the normal system account that is the owner of the given NFT holding SPL
-///     associate token account. A signature is required for this account to confirm
-///     that the given owner would like to associate the new piece of land with their NFT.
+///     associate token account, i.e. the recipient of the new piece of land.
 /// * `[writable] land_asset_acc_pubkey`
 ///     This key should be a PDA corresponding to the next piece of land.
 ///     i.e. PDA of (['solsspace-land', land_plane_acc_pubkey, x, y], land_program_acc_pubkey)
@@ -175,13 +1128,37 @@ pub fn initialize_next_land_asset(
 ///     `nft_assoc_token_acc_owner_pubkey` and should hold a balance of 1.
 /// * `[] nft_mint_acc_pubkey`
 ///     Public key of the SPL NFT Mint account.
+/// * `[writable] land_plane_stats_acc_pubkey`
+///     Public key of the land plane stats account.
+///     i.e. PDA of (['solsspace-stats', land_plane_acc_pubkey], land_program_acc_pubkey)
+/// * `[writable] program_config_acc_pubkey`
+///     Public key of the program config account.
+///     i.e. PDA of (['solsspace-config'], land_program_acc_pubkey)
+/// * `[writable] protocol_treasury_acc_pubkey`
+///     Public key of the protocol treasury given in the program config.
+/// * `[] price_oracle_acc_pubkey`
+///     Public key of the price feed account that is the price oracle of the
+///     land plane, if it has one.
+/// * `[writable] plane_treasury_acc_pubkey`
+///     Public key of the account into which the land plane's mint fee is paid,
+///     i.e. LandPlane::fee_recipient, if the land plane charges one.
+/// 
+/// The land asset by mint account of the given NFT mint is derived and
+/// passed to the instruction, along with the slot hashes sysvar.
+#[allow(clippy::too_many_arguments)]
 pub fn mint_next_land_asset(
     land_program_acc_pubkey: &Pubkey,
+    payer_acc_pubkey: &Pubkey,
     nft_assoc_token_acc_owner_pubkey: &Pubkey,
     land_asset_acc_pubkey: &Pubkey,
     land_plane_acc_pubkey: &Pubkey,
     nft_assoc_token_acc_pubkey: &Pubkey,
     nft_mint_acc_pubkey: &Pubkey,
+    land_plane_stats_acc_pubkey: &Pubkey,
+    program_config_acc_pubkey: &Pubkey,
+    protocol_treasury_acc_pubkey: &Pubkey,
+    price_oracle_acc_pubkey: Option<&Pubkey>,
+    plane_treasury_acc_pubkey: Option<&Pubkey>,
 ) -> Result<Instruction, ProgramError> {
     // confirm given program id is correct
     check_program_account(land_program_acc_pubkey)?;
@@ -190,24 +1167,56 @@ pub fn mint_next_land_asset(
     let data = LandInstruction::MintNextLandAsset.try_to_vec().unwrap();
 
     // prepare list of accounts to pass in instruction
-    let accounts = vec![
+    let mut accounts = vec![
         // 1st
         // Addresses requiring signatures are 1st, and in the following order:
         //
         // those that require write access
+        AccountMeta::new(*payer_acc_pubkey, true),
         // those that require read-only access
-        AccountMeta::new_readonly(*nft_assoc_token_acc_owner_pubkey, true),
         
         // 2nd
         // Addresses not requiring signatures are 2nd, and in the following order:
         //
+        // those that require read-only access
+        AccountMeta::new_readonly(*nft_assoc_token_acc_owner_pubkey, false),
         // those that require write access
         AccountMeta::new(*land_asset_acc_pubkey, false),
         AccountMeta::new(*land_plane_acc_pubkey, false),
         // those that require read-only access
         AccountMeta::new_readonly(*nft_assoc_token_acc_pubkey, false),
         AccountMeta::new_readonly(*nft_mint_acc_pubkey, false),
+        // those that require write access
+        AccountMeta::new(*land_plane_stats_acc_pubkey, false),
+        // those that require read-only access
+        AccountMeta::new_readonly(system_program::id(), false),
+        // those that require write access
+        AccountMeta::new(*program_config_acc_pubkey, false),
+        AccountMeta::new(*protocol_treasury_acc_pubkey, false),
+        AccountMeta::new(land_asset_by_mint_acc_pubkey(land_program_acc_pubkey, nft_mint_acc_pubkey), false),
+        // those that require read-only access
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(sysvar::slot_hashes::id(), false),
+        AccountMeta::new_readonly(metadata_acc_pubkey(nft_mint_acc_pubkey), false),
+        AccountMeta::new_readonly(sysvar::clock::id(), false),
+        // those that require write access
+        AccountMeta::new(
+            wallet_mint_record_acc_pubkey(
+                land_program_acc_pubkey,
+                land_plane_acc_pubkey,
+                nft_assoc_token_acc_owner_pubkey,
+            ),
+            false,
+        ),
     ];
+    // the price oracle is only read for land planes that have one
+    if let Some(price_oracle_acc_pubkey) = price_oracle_acc_pubkey {
+        accounts.push(AccountMeta::new_readonly(*price_oracle_acc_pubkey, false));
+    }
+    // and the fee recipient only paid for land planes that charge a fee
+    if let Some(plane_treasury_acc_pubkey) = plane_treasury_acc_pubkey {
+        accounts.push(AccountMeta::new(*plane_treasury_acc_pubkey, false));
+    }
 
     // return instruction
     Ok(Instruction {
@@ -215,4 +1224,1392 @@ pub fn mint_next_land_asset(
         accounts,
         data,
     })
-}
\ No newline at end of file
+}
+
+/// Creates an `InitialiseLandPlaneStats` instruction.
+/// 
+/// * `land_program_acc_pubkey`
+///     Public key of the land program account - aka. program ID.
+/// * `[signer] rent_payer_acc_pubkey`
+///     Public key of account responsible for paying required rent for the new
+///     land_plane_stats_acc.
+/// * `[writable] land_plane_stats_acc_pubkey`
+///     This key should be a PDA of:
+///     (['solsspace-stats', land_plane_acc_pubkey], land_program_acc_pubkey)
+/// * `[] land_plane_acc_pubkey`
+///     Public key of the land plane account to which the stats belong.
+pub fn initialize_land_plane_stats(
+    land_program_acc_pubkey: &Pubkey,
+    rent_payer_acc_pubkey: &Pubkey,
+    land_plane_stats_acc_pubkey: &Pubkey,
+    land_plane_acc_pubkey: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(land_program_acc_pubkey)?;
+    let data = LandInstruction::InitialiseLandPlaneStats.try_to_vec().unwrap();
+
+    // prepare list of account to pass to the instruction
+    let accounts = vec![
+        // 1st
+        // Addresses requiring signatures are 1st, and in the following order:
+        //
+        // those that require write access
+        AccountMeta::new(*rent_payer_acc_pubkey, true),
+
+        // 2nd
+        // Addresses not requiring signatures are 2nd, and in the following order:
+        //
+        // those that require write access
+        AccountMeta::new(*land_plane_stats_acc_pubkey, false),
+        // those that require read-only access
+        AccountMeta::new_readonly(*land_plane_acc_pubkey, false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *land_program_acc_pubkey,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an `InitialiseLandPlaneIdempotent` instruction.
+/// 
+/// * `land_program_acc_pubkey`
+///     Public key of the land program account - aka. program ID.
+/// * `[writable] land_plane_acc_pubkey`
+///     Public key of the land plane account to initialise.
+/// * `coordinate_ceiling`
+///     Optional hard ceiling on the x and z co-ordinates of minted land.
+/// * `require_frozen_mint`
+///     Only allow land to be bound to NFTs whose mint authority is disabled.
+/// * `mint_fee`
+///     Lamports charged for each piece of land minted.
+/// * `owner`
+///     Optional owner of the land plane.
+/// * `mint_pattern`
+///     Order in which land is assigned to each mint. RandomFill requires
+///     a coordinate_ceiling.
+/// * `one_parcel_per_nft`
+///     Only allow land to be bound to NFTs not already bound to land.
+/// * `royalty_bps`
+///     Royalty, in basis points of the sale price, paid on each transfer.
+/// * `compact_addresses`
+///     Encode co-ordinates in land asset addresses in as few bytes as the
+///     coordinate ceiling allows. Requires a coordinate_ceiling.
+/// * `required_collection`
+///     Only allow land to be bound to NFTs in this verified Metaplex collection.
+/// * `max_supply`
+///     Maximum number of pieces of land that may be minted from the land
+///     plane, regardless of its coordinate_ceiling.
+/// * `mint_cooldown_slots`
+///     Minimum number of slots between mints of land by the same wallet, or
+///     zero for no cooldown.
+/// * `price_oracle`
+///     Price feed account from which MintNextLandAsset reads the mint fee,
+///     in place of mint_fee, or None to charge mint_fee.
+/// * `reserve_claimant`
+///     Account that may claim land left unminted through ClaimReservedParcel
+///     once the sale window has closed, or None for no reserve claimant.
+/// * `closes_at_slot`
+///     Slot after which the sale window is closed, or None if it never closes.
+/// 
+/// The program config account is derived and passed to the instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_land_plane_idempotent(
+    land_program_acc_pubkey: &Pubkey,
+    land_plane_acc_pubkey: &Pubkey,
+    coordinate_ceiling: Option<u64>,
+    require_frozen_mint: bool,
+    mint_fee: u64,
+    owner: Option<Pubkey>,
+    mint_pattern: MintPattern,
+    one_parcel_per_nft: bool,
+    royalty_bps: u16,
+    compact_addresses: bool,
+    required_collection: Option<Pubkey>,
+    max_supply: Option<u64>,
+    mint_cooldown_slots: u64,
+    price_oracle: Option<Pubkey>,
+    reserve_claimant: Option<Pubkey>,
+    closes_at_slot: Option<u64>,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(land_program_acc_pubkey)?;
+    let data = LandInstruction::InitialiseLandPlaneIdempotent {
+        coordinate_ceiling,
+        require_frozen_mint,
+        mint_fee,
+        owner,
+        mint_pattern,
+        one_parcel_per_nft,
+        royalty_bps,
+        compact_addresses,
+        required_collection,
+        max_supply,
+        mint_cooldown_slots,
+        price_oracle,
+        reserve_claimant,
+        closes_at_slot,
+    }.try_to_vec().unwrap();
+
+    // prepare list of account to pass to the instruction
+    let accounts = vec![
+        // 1st
+        // Addresses requiring signatures are 1st, and in the following order:
+        //
+        // those that require write access
+        // those that require read-only access
+
+        // 2nd
+        // Addresses not requiring signatures are 2nd, and in the following order:
+        //
+        // those that require write access
+        AccountMeta::new(*land_plane_acc_pubkey, false),
+        // those that require read-only access
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        // those that require write access
+        AccountMeta::new(program_config_acc_pubkey(land_program_acc_pubkey), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *land_program_acc_pubkey,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `StampCoordinateUri` instruction.
+/// 
+/// * `land_program_acc_pubkey`
+///     Public key of the land program account - aka. program ID.
+/// * `[signer] update_authority_acc_pubkey`
+///     Public key of the update authority of the NFT's metadata account.
+/// * `[] land_asset_acc_pubkey`
+///     This key should be the PDA of the piece of land at (x, z).
+///     i.e. PDA of (['solsspace-land', land_plane_acc_pubkey, x, y], land_program_acc_pubkey)
+/// * `[] land_plane_acc_pubkey`
+///     Public key of the land plane account from which the land was minted.
+/// * `[writable] nft_metadata_acc_pubkey`
+///     Public key of the Metaplex metadata account of the NFT to which the land is bound.
+/// * `x`, `z`
+///     Co-ordinate of the piece of land.
+pub fn stamp_coordinate_uri(
+    land_program_acc_pubkey: &Pubkey,
+    update_authority_acc_pubkey: &Pubkey,
+    land_asset_acc_pubkey: &Pubkey,
+    land_plane_acc_pubkey: &Pubkey,
+    nft_metadata_acc_pubkey: &Pubkey,
+    x: u64,
+    z: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(land_program_acc_pubkey)?;
+    let data = LandInstruction::StampCoordinateUri { x, z }.try_to_vec().unwrap();
+
+    // prepare list of account to pass to the instruction
+    let accounts = vec![
+        // 1st
+        // Addresses requiring signatures are 1st, and in the following order:
+        //
+        // those that require write access
+        // those that require read-only access
+        AccountMeta::new_readonly(*update_authority_acc_pubkey, true),
+
+        // 2nd
+        // Addresses not requiring signatures are 2nd, and in the following order:
+        //
+        // those that require read-only access
+        AccountMeta::new_readonly(*land_asset_acc_pubkey, false),
+        AccountMeta::new_readonly(*land_plane_acc_pubkey, false),
+        // those that require write access
+        AccountMeta::new(*nft_metadata_acc_pubkey, false),
+        // those that require read-only access
+        AccountMeta::new_readonly(token_metadata_program::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *land_program_acc_pubkey,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an `InitialiseProgramConfig` instruction.
+/// 
+/// * `land_program_acc_pubkey`
+///     Public key of the land program account - aka. program ID.
+/// * `[signer, writable] upgrade_authority_acc_pubkey`
+///     Public key of the upgrade authority of the land program, responsible for
+///     paying required rent for the new program_config_acc.
+/// * `[writable] program_config_acc_pubkey`
+///     This key should be a PDA of:
+///     (['solsspace-config'], land_program_acc_pubkey)
+/// * `protocol_fee`
+///     Lamports charged for each piece of land minted from any land plane.
+/// * `protocol_treasury`
+///     Account into which protocol fees are paid.
+pub fn initialize_program_config(
+    land_program_acc_pubkey: &Pubkey,
+    upgrade_authority_acc_pubkey: &Pubkey,
+    program_config_acc_pubkey: &Pubkey,
+    protocol_fee: u64,
+    protocol_treasury: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(land_program_acc_pubkey)?;
+    let data = LandInstruction::InitialiseProgramConfig {
+        protocol_fee,
+        protocol_treasury: *protocol_treasury,
+    }.try_to_vec().unwrap();
+
+    // derive the program data account of the land program
+    let (program_data_acc_pubkey, _) = Pubkey::find_program_address(
+        &[land_program_acc_pubkey.as_ref()],
+        &bpf_loader_upgradeable::id(),
+    );
+
+    // prepare list of account to pass to the instruction
+    let accounts = vec![
+        // 1st
+        // Addresses requiring signatures are 1st, and in the following order:
+        //
+        // those that require write access
+        AccountMeta::new(*upgrade_authority_acc_pubkey, true),
+
+        // 2nd
+        // Addresses not requiring signatures are 2nd, and in the following order:
+        //
+        // those that require write access
+        AccountMeta::new(*program_config_acc_pubkey, false),
+        // those that require read-only access
+        AccountMeta::new_readonly(program_data_acc_pubkey, false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *land_program_acc_pubkey,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `TransferLand` instruction.
+/// 
+/// * `land_program_acc_pubkey`
+///     Public key of the land program account - aka. program ID.
+/// * `[signer, writable] payer_acc_pubkey`
+///     Public key of the account responsible for paying the royalty of the
+///     land plane, and required rent for the land asset by mint account of
+///     the new NFT.
+/// * `[signer] nft_assoc_token_acc_owner_pubkey`
+///     Public key of the holder of the NFT to which the land is currently bound.
+/// * `[writable] land_asset_acc_pubkey`
+///     i.e. PDA of (['solsspace-land', land_plane_acc_pubkey, x, y], land_program_acc_pubkey)
+/// * `[writable] land_plane_acc_pubkey`
+///     Public key of the land plane account from which the land was minted.
+/// * `[] nft_assoc_token_acc_pubkey`
+///     Public key of the SPL NFT holding account of the current NFT.
+/// * `nft_mint_acc_pubkey`
+///     Public key of the SPL NFT Mint account to which the land is currently bound.
+/// * `[] new_nft_mint_acc_pubkey`
+///     Public key of the SPL NFT Mint account to which the land will be bound.
+/// * `[writable] plane_treasury_acc_pubkey`
+///     Public key of the account into which the land plane's royalty is paid,
+///     i.e. LandPlane::fee_recipient, if the land plane charges one.
+/// * `x`, `z`
+///     Co-ordinate of the piece of land.
+/// * `sale_price`
+///     Lamports for which the land was sold, on which the royalty is charged.
+/// 
+/// The land asset by mint accounts of both NFT mints are derived and
+/// passed to the instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_land(
+    land_program_acc_pubkey: &Pubkey,
+    payer_acc_pubkey: &Pubkey,
+    nft_assoc_token_acc_owner_pubkey: &Pubkey,
+    land_asset_acc_pubkey: &Pubkey,
+    land_plane_acc_pubkey: &Pubkey,
+    nft_assoc_token_acc_pubkey: &Pubkey,
+    nft_mint_acc_pubkey: &Pubkey,
+    new_nft_mint_acc_pubkey: &Pubkey,
+    plane_treasury_acc_pubkey: Option<&Pubkey>,
+    x: u64,
+    z: u64,
+    sale_price: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(land_program_acc_pubkey)?;
+    let data = LandInstruction::TransferLand { x, z, sale_price }.try_to_vec().unwrap();
+
+    // prepare list of account to pass to the instruction
+    let mut accounts = vec![
+        // 1st
+        // Addresses requiring signatures are 1st, and in the following order:
+        //
+        // those that require write access
+        AccountMeta::new(*payer_acc_pubkey, true),
+        // those that require read-only access
+        AccountMeta::new_readonly(*nft_assoc_token_acc_owner_pubkey, true),
+
+        // 2nd
+        // Addresses not requiring signatures are 2nd, and in the following order:
+        //
+        // those that require write access
+        AccountMeta::new(*land_asset_acc_pubkey, false),
+        AccountMeta::new(*land_plane_acc_pubkey, false),
+        // those that require read-only access
+        AccountMeta::new_readonly(*nft_assoc_token_acc_pubkey, false),
+        // those that require write access
+        AccountMeta::new(land_asset_by_mint_acc_pubkey(land_program_acc_pubkey, nft_mint_acc_pubkey), false),
+        // those that require read-only access
+        AccountMeta::new_readonly(*new_nft_mint_acc_pubkey, false),
+        // those that require write access
+        AccountMeta::new(land_asset_by_mint_acc_pubkey(land_program_acc_pubkey, new_nft_mint_acc_pubkey), false),
+        // those that require read-only access
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    // the fee recipient is only paid for land planes that charge a royalty
+    if let Some(plane_treasury_acc_pubkey) = plane_treasury_acc_pubkey {
+        accounts.push(AccountMeta::new(*plane_treasury_acc_pubkey, false));
+    }
+
+    Ok(Instruction {
+        program_id: *land_program_acc_pubkey,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `RepairParcelCoordinate` instruction.
+/// 
+/// * `land_program_acc_pubkey`
+///     Public key of the land program account - aka. program ID.
+/// * `[signer] land_plane_owner_acc_pubkey`
+///     Public key of the owner of the land plane.
+/// * `[writable] land_asset_acc_pubkey`
+///     i.e. PDA of (['solsspace-land', land_plane_acc_pubkey, x, y], land_program_acc_pubkey)
+/// * `[] land_plane_acc_pubkey`
+///     Public key of the land plane account from which the land was minted.
+pub fn repair_parcel_coordinate(
+    land_program_acc_pubkey: &Pubkey,
+    land_plane_owner_acc_pubkey: &Pubkey,
+    land_asset_acc_pubkey: &Pubkey,
+    land_plane_acc_pubkey: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(land_program_acc_pubkey)?;
+    let data = LandInstruction::RepairParcelCoordinate.try_to_vec().unwrap();
+
+    // prepare list of account to pass to the instruction
+    let accounts = vec![
+        // 1st
+        // Addresses requiring signatures are 1st, and in the following order:
+        //
+        // those that require write access
+        // those that require read-only access
+        AccountMeta::new_readonly(*land_plane_owner_acc_pubkey, true),
+
+        // 2nd
+        // Addresses not requiring signatures are 2nd, and in the following order:
+        //
+        // those that require write access
+        AccountMeta::new(*land_asset_acc_pubkey, false),
+        // those that require read-only access
+        AccountMeta::new_readonly(*land_plane_acc_pubkey, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *land_program_acc_pubkey,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `ProcessBatch` instruction.
+/// 
+/// * `land_program_acc_pubkey`
+///     Public key of the land program account - aka. program ID.
+/// * `instructions`
+///     Land program instructions to process in order, e.g. as created by
+///     `initialize_next_land_asset` and `mint_next_land_asset`.
+/// 
+/// The accounts of the given instructions are merged so that each is
+/// passed once, as a signer and writable if any instruction requires it.
+pub fn process_batch(
+    land_program_acc_pubkey: &Pubkey,
+    instructions: &[Instruction],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(land_program_acc_pubkey)?;
+
+    // prepare list of account to pass to the instruction, and
+    // the indices into it of the accounts of each sub-instruction
+    let mut accounts: Vec<AccountMeta> = Vec::new();
+    let mut batched_instructions = Vec::with_capacity(instructions.len());
+    for instruction in instructions {
+        check_program_account(&instruction.program_id)?;
+        let mut account_indices = Vec::with_capacity(instruction.accounts.len());
+        for account_meta in &instruction.accounts {
+            let index = match accounts.iter().position(|a| a.pubkey == account_meta.pubkey) {
+                Some(index) => {
+                    accounts[index].is_signer |= account_meta.is_signer;
+                    accounts[index].is_writable |= account_meta.is_writable;
+                    index
+                }
+                None => {
+                    accounts.push(account_meta.clone());
+                    accounts.len() - 1
+                }
+            };
+            account_indices.push(u8::try_from(index).map_err(|_| ProgramError::InvalidArgument)?);
+        }
+        batched_instructions.push(BatchedInstruction {
+            account_indices,
+            instruction: LandInstruction::try_from_slice(&instruction.data)?,
+        });
+    }
+
+    let data = LandInstruction::ProcessBatch {
+        instructions: batched_instructions,
+    }.try_to_vec().unwrap();
+
+    Ok(Instruction {
+        program_id: *land_program_acc_pubkey,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `SetupPlane` instruction.
+/// 
+/// * `land_program_acc_pubkey`
+///     Public key of the land program account - aka. program ID.
+/// * `[signer, writable] payer_acc_pubkey`
+///     Public key of the account responsible for paying required rent for
+///     the new land plane and land plane stats accounts.
+/// * `[signer, writable] land_plane_acc_pubkey`
+///     Public key of the new land plane account.
+/// * `[writable] land_plane_stats_acc_pubkey`
+///     i.e. PDA of (['solsspace-stats', land_plane_acc_pubkey], land_program_acc_pubkey)
+/// 
+/// The remaining arguments are as for `initialize_land_plane`. The program
+/// config account is derived and passed to the instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn setup_plane(
+    land_program_acc_pubkey: &Pubkey,
+    payer_acc_pubkey: &Pubkey,
+    land_plane_acc_pubkey: &Pubkey,
+    land_plane_stats_acc_pubkey: &Pubkey,
+    coordinate_ceiling: Option<u64>,
+    require_frozen_mint: bool,
+    mint_fee: u64,
+    owner: Option<Pubkey>,
+    mint_pattern: MintPattern,
+    one_parcel_per_nft: bool,
+    royalty_bps: u16,
+    compact_addresses: bool,
+    required_collection: Option<Pubkey>,
+    max_supply: Option<u64>,
+    mint_cooldown_slots: u64,
+    price_oracle: Option<Pubkey>,
+    reserve_claimant: Option<Pubkey>,
+    closes_at_slot: Option<u64>,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(land_program_acc_pubkey)?;
+    let data = LandInstruction::SetupPlane {
+        coordinate_ceiling,
+        require_frozen_mint,
+        mint_fee,
+        owner,
+        mint_pattern,
+        one_parcel_per_nft,
+        royalty_bps,
+        compact_addresses,
+        required_collection,
+        max_supply,
+        mint_cooldown_slots,
+        price_oracle,
+        reserve_claimant,
+        closes_at_slot,
+    }.try_to_vec().unwrap();
+
+    // prepare list of account to pass to the instruction
+    let accounts = vec![
+        // 1st
+        // Addresses requiring signatures are 1st, and in the following order:
+        //
+        // those that require write access
+        AccountMeta::new(*payer_acc_pubkey, true),
+        AccountMeta::new(*land_plane_acc_pubkey, true),
+
+        // 2nd
+        // Addresses not requiring signatures are 2nd, and in the following order:
+        //
+        // those that require write access
+        AccountMeta::new(*land_plane_stats_acc_pubkey, false),
+        // those that require read-only access
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        // those that require write access
+        AccountMeta::new(program_config_acc_pubkey(land_program_acc_pubkey), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *land_program_acc_pubkey,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `MigrateAssetsBatch` instruction.
+/// 
+/// * `land_program_acc_pubkey`
+///     Public key of the land program account - aka. program ID.
+/// * `[signer] land_plane_owner_acc_pubkey`
+///     Public key of the owner of the land plane.
+/// * `[writable] land_plane_acc_pubkey`
+///     Public key of the land plane account from which the land was minted.
+/// * `start_index`
+///     Index in mint order of the first land asset account to migrate.
+/// * `[writable] land_asset_acc_pubkeys`
+///     Land asset accounts of the land minted at each index from
+///     start_index, in order, e.g. as given by asset_address_at_index.
+pub fn migrate_assets_batch(
+    land_program_acc_pubkey: &Pubkey,
+    land_plane_owner_acc_pubkey: &Pubkey,
+    land_plane_acc_pubkey: &Pubkey,
+    start_index: u64,
+    land_asset_acc_pubkeys: &[Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(land_program_acc_pubkey)?;
+    let data = LandInstruction::MigrateAssetsBatch {
+        start_index,
+        count: u8::try_from(land_asset_acc_pubkeys.len()).map_err(|_| ProgramError::InvalidArgument)?,
+    }.try_to_vec().unwrap();
+
+    // prepare list of account to pass to the instruction
+    let mut accounts = vec![
+        // 1st
+        // Addresses requiring signatures are 1st, and in the following order:
+        //
+        // those that require write access
+        // those that require read-only access
+        AccountMeta::new_readonly(*land_plane_owner_acc_pubkey, true),
+
+        // 2nd
+        // Addresses not requiring signatures are 2nd, and in the following order:
+        //
+        // those that require write access
+        AccountMeta::new(*land_plane_acc_pubkey, false),
+    ];
+    accounts.extend(land_asset_acc_pubkeys.iter().map(|pubkey| AccountMeta::new(*pubkey, false)));
+
+    Ok(Instruction {
+        program_id: *land_program_acc_pubkey,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `ForceSetCursor` instruction.
+/// 
+/// * `land_program_acc_pubkey`
+///     Public key of the land program account - aka. program ID.
+/// * `[signer] upgrade_authority_acc_pubkey`
+///     Public key of the upgrade authority of the land program.
+/// * `[writable] land_plane_acc_pubkey`
+///     Public key of the land plane account.
+/// * `next_x`, `next_z`
+///     Next co-ordinate to be minted from the land plane.
+/// * `depth`
+///     Depth of the shell holding the next co-ordinate.
+pub fn force_set_cursor(
+    land_program_acc_pubkey: &Pubkey,
+    upgrade_authority_acc_pubkey: &Pubkey,
+    land_plane_acc_pubkey: &Pubkey,
+    next_x: u64,
+    next_z: u64,
+    depth: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(land_program_acc_pubkey)?;
+    let data = LandInstruction::ForceSetCursor { next_x, next_z, depth }.try_to_vec().unwrap();
+
+    // derive the program data account of the land program
+    let (program_data_acc_pubkey, _) = Pubkey::find_program_address(
+        &[land_program_acc_pubkey.as_ref()],
+        &bpf_loader_upgradeable::id(),
+    );
+
+    // prepare list of account to pass to the instruction
+    let accounts = vec![
+        // 1st
+        // Addresses requiring signatures are 1st, and in the following order:
+        //
+        // those that require write access
+        // those that require read-only access
+        AccountMeta::new_readonly(*upgrade_authority_acc_pubkey, true),
+
+        // 2nd
+        // Addresses not requiring signatures are 2nd, and in the following order:
+        //
+        // those that require write access
+        AccountMeta::new(*land_plane_acc_pubkey, false),
+        // those that require read-only access
+        AccountMeta::new_readonly(program_data_acc_pubkey, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *land_program_acc_pubkey,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `SetParcelAttributes` instruction.
+/// 
+/// * `land_program_acc_pubkey`
+///     Public key of the land program account - aka. program ID.
+/// * `[signer] land_plane_owner_acc_pubkey`
+///     Public key of the owner of the land plane.
+/// * `[] land_plane_acc_pubkey`
+///     Public key of the land plane account of the land.
+/// * `[writable] land_asset_acc_pubkey`
+///     Public key of the land asset account of the piece of land.
+/// * `mask`, `value`
+///     Bits of the attributes to set, and their values.
+pub fn set_parcel_attributes(
+    land_program_acc_pubkey: &Pubkey,
+    land_plane_owner_acc_pubkey: &Pubkey,
+    land_plane_acc_pubkey: &Pubkey,
+    land_asset_acc_pubkey: &Pubkey,
+    mask: u32,
+    value: u32,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(land_program_acc_pubkey)?;
+    let data = LandInstruction::SetParcelAttributes { mask, value }.try_to_vec().unwrap();
+
+    // prepare list of account to pass to the instruction
+    let accounts = vec![
+        // 1st
+        // Addresses requiring signatures are 1st, and in the following order:
+        //
+        // those that require write access
+        // those that require read-only access
+        AccountMeta::new_readonly(*land_plane_owner_acc_pubkey, true),
+
+        // 2nd
+        // Addresses not requiring signatures are 2nd, and in the following order:
+        //
+        // those that require write access
+        AccountMeta::new(*land_asset_acc_pubkey, false),
+        // those that require read-only access
+        AccountMeta::new_readonly(*land_plane_acc_pubkey, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *land_program_acc_pubkey,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `MergeParcels` instruction.
+/// 
+/// * `land_program_acc_pubkey`
+///     Public key of the land program account - aka. program ID.
+/// * `[signer] nft_assoc_token_acc_owner_pubkey`
+///     Public key of the holder of the NFTs to which the land is bound.
+/// * `[] land_plane_acc_pubkey`
+///     Public key of the land plane account of the land.
+/// * `[writable] land_asset_acc_pubkeys`
+///     Public keys of the land asset accounts of the pieces of land at
+///     (x, z), (x + 1, z), (x, z + 1) and (x + 1, z + 1).
+/// * `[] nft_assoc_token_acc_pubkeys`
+///     Public keys of the SPL NFT holding accounts of the NFTs to which
+///     each piece of land is bound, in the same order.
+pub fn merge_parcels(
+    land_program_acc_pubkey: &Pubkey,
+    nft_assoc_token_acc_owner_pubkey: &Pubkey,
+    land_plane_acc_pubkey: &Pubkey,
+    land_asset_acc_pubkeys: &[Pubkey; 4],
+    nft_assoc_token_acc_pubkeys: &[Pubkey; 4],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(land_program_acc_pubkey)?;
+    let data = LandInstruction::MergeParcels.try_to_vec().unwrap();
+
+    // prepare list of account to pass to the instruction
+    let mut accounts = vec![
+        // 1st
+        // Addresses requiring signatures are 1st, and in the following order:
+        //
+        // those that require write access
+        // those that require read-only access
+        AccountMeta::new_readonly(*nft_assoc_token_acc_owner_pubkey, true),
+
+        // 2nd
+        // Addresses not requiring signatures are 2nd, and in the following order:
+        //
+        // those that require write access
+    ];
+    for land_asset_acc_pubkey in land_asset_acc_pubkeys {
+        accounts.push(AccountMeta::new(*land_asset_acc_pubkey, false));
+    }
+    // those that require read-only access
+    accounts.push(AccountMeta::new_readonly(*land_plane_acc_pubkey, false));
+    for nft_assoc_token_acc_pubkey in nft_assoc_token_acc_pubkeys {
+        accounts.push(AccountMeta::new_readonly(*nft_assoc_token_acc_pubkey, false));
+    }
+
+    Ok(Instruction {
+        program_id: *land_program_acc_pubkey,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `SetPlaneMetadata` instruction.
+/// 
+/// * `land_program_acc_pubkey`
+///     Public key of the land program account - aka. program ID.
+/// * `[signer, writable] land_plane_owner_acc_pubkey`
+///     Public key of the owner of the land plane, who pays the rent for
+///     the plane metadata account.
+/// * `[] land_plane_acc_pubkey`
+///     Public key of the land plane account.
+/// * `name`, `symbol`, `base_uri`
+///     Metadata of the land plane. FieldTooLong is returned if any is
+///     longer than MAX_PLANE_NAME_LEN, MAX_PLANE_SYMBOL_LEN or
+///     MAX_PLANE_BASE_URI_LEN bytes respectively.
+pub fn set_plane_metadata(
+    land_program_acc_pubkey: &Pubkey,
+    land_plane_owner_acc_pubkey: &Pubkey,
+    land_plane_acc_pubkey: &Pubkey,
+    name: &str,
+    symbol: &str,
+    base_uri: &str,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(land_program_acc_pubkey)?;
+    let data = LandInstruction::SetPlaneMetadata {
+        name: BoundedString::try_from(name)?,
+        symbol: BoundedString::try_from(symbol)?,
+        base_uri: BoundedString::try_from(base_uri)?,
+    }.try_to_vec().unwrap();
+
+    // prepare list of account to pass to the instruction
+    let accounts = vec![
+        // 1st
+        // Addresses requiring signatures are 1st, and in the following order:
+        //
+        // those that require write access
+        AccountMeta::new(*land_plane_owner_acc_pubkey, true),
+
+        // 2nd
+        // Addresses not requiring signatures are 2nd, and in the following order:
+        //
+        // those that require write access
+        AccountMeta::new(
+            plane_metadata_acc_pubkey(land_program_acc_pubkey, land_plane_acc_pubkey),
+            false,
+        ),
+        // those that require read-only access
+        AccountMeta::new_readonly(*land_plane_acc_pubkey, false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *land_program_acc_pubkey,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `VerifyParcelOwnership` instruction.
+/// 
+/// * `land_program_acc_pubkey`
+///     Public key of the land program account - aka. program ID.
+/// * `[signer] nft_assoc_token_acc_owner_pubkey`
+///     Public key of the holder of the NFT to which the land is bound.
+/// * `[] land_asset_acc_pubkey`
+///     Public key of the land asset account of the piece of land.
+/// * `[] land_plane_acc_pubkey`
+///     Public key of the land plane account from which the land was minted.
+/// * `[] nft_assoc_token_acc_pubkey`
+///     Public key of the SPL NFT holding account of the NFT to which the
+///     land is bound.
+pub fn verify_parcel_ownership(
+    land_program_acc_pubkey: &Pubkey,
+    nft_assoc_token_acc_owner_pubkey: &Pubkey,
+    land_asset_acc_pubkey: &Pubkey,
+    land_plane_acc_pubkey: &Pubkey,
+    nft_assoc_token_acc_pubkey: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(land_program_acc_pubkey)?;
+    let data = LandInstruction::VerifyParcelOwnership.try_to_vec().unwrap();
+
+    // prepare list of account to pass to the instruction
+    let accounts = vec![
+        // 1st
+        // Addresses requiring signatures are 1st, and in the following order:
+        //
+        // those that require write access
+        // those that require read-only access
+        AccountMeta::new_readonly(*nft_assoc_token_acc_owner_pubkey, true),
+
+        // 2nd
+        // Addresses not requiring signatures are 2nd, and in the following order:
+        //
+        // those that require write access
+        // those that require read-only access
+        AccountMeta::new_readonly(*land_asset_acc_pubkey, false),
+        AccountMeta::new_readonly(*land_plane_acc_pubkey, false),
+        AccountMeta::new_readonly(*nft_assoc_token_acc_pubkey, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *land_program_acc_pubkey,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an `AdminRelinkParcel` instruction.
+/// 
+/// * `land_program_acc_pubkey`
+///     Public key of the land program account - aka. program ID.
+/// * `[signer, writable] land_plane_owner_acc_pubkey`
+///     Public key of the owner of the land plane.
+/// * `[writable] land_asset_acc_pubkey`
+///     Public key of the land asset account of the piece of land.
+/// * `[] land_plane_acc_pubkey`
+///     Public key of the land plane account from which the land was minted.
+/// * `nft_mint_acc_pubkey`
+///     Public key of the SPL NFT Mint account of the NFT to which the land
+///     is currently bound, from which its land asset by mint account is
+///     derived.
+/// * `[] new_mint`
+///     Public key of the SPL NFT Mint account of the NFT to which the land
+///     will be bound.
+pub fn admin_relink_parcel(
+    land_program_acc_pubkey: &Pubkey,
+    land_plane_owner_acc_pubkey: &Pubkey,
+    land_asset_acc_pubkey: &Pubkey,
+    land_plane_acc_pubkey: &Pubkey,
+    nft_mint_acc_pubkey: &Pubkey,
+    new_mint: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(land_program_acc_pubkey)?;
+    let data = LandInstruction::AdminRelinkParcel { new_mint: *new_mint }.try_to_vec().unwrap();
+
+    // prepare list of account to pass to the instruction
+    let accounts = vec![
+        // 1st
+        // Addresses requiring signatures are 1st, and in the following order:
+        //
+        // those that require write access
+        AccountMeta::new(*land_plane_owner_acc_pubkey, true),
+        // those that require read-only access
+
+        // 2nd
+        // Addresses not requiring signatures are 2nd, and in the following order:
+        //
+        // those that require write access
+        AccountMeta::new(*land_asset_acc_pubkey, false),
+        // those that require read-only access
+        AccountMeta::new_readonly(*land_plane_acc_pubkey, false),
+        // those that require write access
+        AccountMeta::new(land_asset_by_mint_acc_pubkey(land_program_acc_pubkey, nft_mint_acc_pubkey), false),
+        // those that require read-only access
+        AccountMeta::new_readonly(*new_mint, false),
+        // those that require write access
+        AccountMeta::new(land_asset_by_mint_acc_pubkey(land_program_acc_pubkey, new_mint), false),
+        // those that require read-only access
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *land_program_acc_pubkey,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `MintNextWithAttributes` instruction.
+/// 
+/// Takes the arguments of `mint_next_land_asset`, with the payer as the
+/// owner of the land plane if attributes is nonzero, and:
+/// 
+/// * `attributes`
+///     Attributes of the minted land, zero for none.
+#[allow(clippy::too_many_arguments)]
+pub fn mint_next_with_attributes(
+    land_program_acc_pubkey: &Pubkey,
+    payer_acc_pubkey: &Pubkey,
+    nft_assoc_token_acc_owner_pubkey: &Pubkey,
+    land_asset_acc_pubkey: &Pubkey,
+    land_plane_acc_pubkey: &Pubkey,
+    nft_assoc_token_acc_pubkey: &Pubkey,
+    nft_mint_acc_pubkey: &Pubkey,
+    land_plane_stats_acc_pubkey: &Pubkey,
+    program_config_acc_pubkey: &Pubkey,
+    protocol_treasury_acc_pubkey: &Pubkey,
+    price_oracle_acc_pubkey: Option<&Pubkey>,
+    plane_treasury_acc_pubkey: Option<&Pubkey>,
+    attributes: u32,
+) -> Result<Instruction, ProgramError> {
+    // the accounts are those of MintNextLandAsset
+    let mut instruction = mint_next_land_asset(
+        land_program_acc_pubkey,
+        payer_acc_pubkey,
+        nft_assoc_token_acc_owner_pubkey,
+        land_asset_acc_pubkey,
+        land_plane_acc_pubkey,
+        nft_assoc_token_acc_pubkey,
+        nft_mint_acc_pubkey,
+        land_plane_stats_acc_pubkey,
+        program_config_acc_pubkey,
+        protocol_treasury_acc_pubkey,
+        price_oracle_acc_pubkey,
+        plane_treasury_acc_pubkey,
+    )?;
+    instruction.data = LandInstruction::MintNextWithAttributes { attributes }.try_to_vec().unwrap();
+    Ok(instruction)
+}
+
+/// Creates a `LockParcel` instruction.
+/// 
+/// * `land_program_acc_pubkey`
+///     Public key of the land program account - aka. program ID.
+/// * `[signer] nft_assoc_token_acc_owner_pubkey`
+///     Public key of the holder of the NFT to which the land is bound.
+/// * `[writable] land_asset_acc_pubkey`
+///     Public key of the land asset account of the piece of land.
+/// * `[] land_plane_acc_pubkey`
+///     Public key of the land plane account from which the land was minted.
+/// * `[] nft_assoc_token_acc_pubkey`
+///     Public key of the SPL NFT holding account of the NFT to which the
+///     land is bound.
+/// * `locker_pubkey`
+///     Public key of the account that may unlock the land.
+pub fn lock_parcel(
+    land_program_acc_pubkey: &Pubkey,
+    nft_assoc_token_acc_owner_pubkey: &Pubkey,
+    land_asset_acc_pubkey: &Pubkey,
+    land_plane_acc_pubkey: &Pubkey,
+    nft_assoc_token_acc_pubkey: &Pubkey,
+    locker_pubkey: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(land_program_acc_pubkey)?;
+    let data = LandInstruction::LockParcel { locker: *locker_pubkey }.try_to_vec().unwrap();
+
+    // prepare list of account to pass to the instruction
+    let accounts = vec![
+        // 1st
+        // Addresses requiring signatures are 1st, and in the following order:
+        //
+        // those that require write access
+        // those that require read-only access
+        AccountMeta::new_readonly(*nft_assoc_token_acc_owner_pubkey, true),
+
+        // 2nd
+        // Addresses not requiring signatures are 2nd, and in the following order:
+        //
+        // those that require write access
+        AccountMeta::new(*land_asset_acc_pubkey, false),
+        // those that require read-only access
+        AccountMeta::new_readonly(*land_plane_acc_pubkey, false),
+        AccountMeta::new_readonly(*nft_assoc_token_acc_pubkey, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *land_program_acc_pubkey,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an `UnlockParcel` instruction.
+/// 
+/// * `land_program_acc_pubkey`
+///     Public key of the land program account - aka. program ID.
+/// * `[signer] locker_pubkey`
+///     Public key of the locker by which the land was locked.
+/// * `[writable] land_asset_acc_pubkey`
+///     Public key of the land asset account of the piece of land.
+/// * `[] land_plane_acc_pubkey`
+///     Public key of the land plane account from which the land was minted.
+pub fn unlock_parcel(
+    land_program_acc_pubkey: &Pubkey,
+    locker_pubkey: &Pubkey,
+    land_asset_acc_pubkey: &Pubkey,
+    land_plane_acc_pubkey: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(land_program_acc_pubkey)?;
+    let data = LandInstruction::UnlockParcel.try_to_vec().unwrap();
+
+    // prepare list of account to pass to the instruction
+    let accounts = vec![
+        // 1st
+        // Addresses requiring signatures are 1st, and in the following order:
+        //
+        // those that require write access
+        // those that require read-only access
+        AccountMeta::new_readonly(*locker_pubkey, true),
+
+        // 2nd
+        // Addresses not requiring signatures are 2nd, and in the following order:
+        //
+        // those that require write access
+        AccountMeta::new(*land_asset_acc_pubkey, false),
+        // those that require read-only access
+        AccountMeta::new_readonly(*land_plane_acc_pubkey, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *land_program_acc_pubkey,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `MintNextWithDeadline` instruction.
+/// 
+/// Takes the arguments of `mint_next_land_asset`, and:
+/// 
+/// * `valid_until_slot`
+///     Last slot at which the land may be minted.
+#[allow(clippy::too_many_arguments)]
+pub fn mint_next_with_deadline(
+    land_program_acc_pubkey: &Pubkey,
+    payer_acc_pubkey: &Pubkey,
+    nft_assoc_token_acc_owner_pubkey: &Pubkey,
+    land_asset_acc_pubkey: &Pubkey,
+    land_plane_acc_pubkey: &Pubkey,
+    nft_assoc_token_acc_pubkey: &Pubkey,
+    nft_mint_acc_pubkey: &Pubkey,
+    land_plane_stats_acc_pubkey: &Pubkey,
+    program_config_acc_pubkey: &Pubkey,
+    protocol_treasury_acc_pubkey: &Pubkey,
+    price_oracle_acc_pubkey: Option<&Pubkey>,
+    plane_treasury_acc_pubkey: Option<&Pubkey>,
+    valid_until_slot: u64,
+) -> Result<Instruction, ProgramError> {
+    // the accounts are those of MintNextLandAsset
+    let mut instruction = mint_next_land_asset(
+        land_program_acc_pubkey,
+        payer_acc_pubkey,
+        nft_assoc_token_acc_owner_pubkey,
+        land_asset_acc_pubkey,
+        land_plane_acc_pubkey,
+        nft_assoc_token_acc_pubkey,
+        nft_mint_acc_pubkey,
+        land_plane_stats_acc_pubkey,
+        program_config_acc_pubkey,
+        protocol_treasury_acc_pubkey,
+        price_oracle_acc_pubkey,
+        plane_treasury_acc_pubkey,
+    )?;
+    instruction.data = LandInstruction::MintNextWithDeadline { valid_until_slot }.try_to_vec().unwrap();
+    Ok(instruction)
+}
+
+/// Creates a `MintNextLandAsset` instruction for a land plane filled at
+/// random.
+/// 
+/// Takes the arguments of `mint_next_land_asset`, where the land asset
+/// account is that of the first probe whose land has not been minted,
+/// and:
+/// 
+/// * `[] probed_land_asset_acc_pubkeys`
+///     Public keys of the land asset accounts of the co-ordinates probed
+///     past, whose land has already been minted.
+#[allow(clippy::too_many_arguments)]
+pub fn mint_next_random_fill(
+    land_program_acc_pubkey: &Pubkey,
+    payer_acc_pubkey: &Pubkey,
+    nft_assoc_token_acc_owner_pubkey: &Pubkey,
+    land_asset_acc_pubkey: &Pubkey,
+    land_plane_acc_pubkey: &Pubkey,
+    nft_assoc_token_acc_pubkey: &Pubkey,
+    nft_mint_acc_pubkey: &Pubkey,
+    land_plane_stats_acc_pubkey: &Pubkey,
+    program_config_acc_pubkey: &Pubkey,
+    protocol_treasury_acc_pubkey: &Pubkey,
+    price_oracle_acc_pubkey: Option<&Pubkey>,
+    plane_treasury_acc_pubkey: Option<&Pubkey>,
+    probed_land_asset_acc_pubkeys: &[Pubkey],
+) -> Result<Instruction, ProgramError> {
+    // the accounts are those of MintNextLandAsset, followed
+    // by the land asset accounts probed past
+    let mut instruction = mint_next_land_asset(
+        land_program_acc_pubkey,
+        payer_acc_pubkey,
+        nft_assoc_token_acc_owner_pubkey,
+        land_asset_acc_pubkey,
+        land_plane_acc_pubkey,
+        nft_assoc_token_acc_pubkey,
+        nft_mint_acc_pubkey,
+        land_plane_stats_acc_pubkey,
+        program_config_acc_pubkey,
+        protocol_treasury_acc_pubkey,
+        price_oracle_acc_pubkey,
+        plane_treasury_acc_pubkey,
+    )?;
+    instruction.accounts.extend(
+        probed_land_asset_acc_pubkeys
+            .iter()
+            .map(|probed_land_asset_acc_pubkey| AccountMeta::new_readonly(*probed_land_asset_acc_pubkey, false)),
+    );
+    Ok(instruction)
+}
+
+/// Creates a `ClaimReservedParcel` instruction.
+/// 
+/// * `land_program_acc_pubkey`
+///     Public key of the land program account - aka. program ID.
+/// * `[signer, writable] reserve_claimant_acc_pubkey`
+///     Public key of the reserve claimant of the land plane.
+/// * `[writable] land_asset_acc_pubkey`
+///     This key should be a PDA corresponding to the piece of land at (x, z).
+///     i.e. PDA of (['solsspace-land', land_plane_acc_pubkey, x, y], land_program_acc_pubkey)
+/// * `[writable] land_plane_acc_pubkey`
+///     Public key of the land plane account from which the land is claimed.
+/// * `[] nft_mint_acc_pubkey`
+///     Public key of the SPL NFT Mint account of the NFT to which the land
+///     will be bound.
+/// * `[writable] land_plane_stats_acc_pubkey`
+///     Public key of the land plane stats account.
+///     i.e. PDA of (['solsspace-stats', land_plane_acc_pubkey], land_program_acc_pubkey)
+/// * `x`
+///     x co-ordinate of the piece of land.
+/// * `z`
+///     z co-ordinate of the piece of land.
+/// 
+/// The land asset by mint account of the given NFT mint is derived and
+/// passed to the instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn claim_reserved_parcel(
+    land_program_acc_pubkey: &Pubkey,
+    reserve_claimant_acc_pubkey: &Pubkey,
+    land_asset_acc_pubkey: &Pubkey,
+    land_plane_acc_pubkey: &Pubkey,
+    nft_mint_acc_pubkey: &Pubkey,
+    land_plane_stats_acc_pubkey: &Pubkey,
+    x: u64,
+    z: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(land_program_acc_pubkey)?;
+    let data = LandInstruction::ClaimReservedParcel { x, z }.try_to_vec().unwrap();
+
+    // prepare list of account to pass to the instruction
+    let accounts = vec![
+        // 1st
+        // Addresses requiring signatures are 1st, and in the following order:
+        //
+        // those that require write access
+        AccountMeta::new(*reserve_claimant_acc_pubkey, true),
+        // those that require read-only access
+
+        // 2nd
+        // Addresses not requiring signatures are 2nd, and in the following order:
+        //
+        // those that require write access
+        AccountMeta::new(*land_asset_acc_pubkey, false),
+        AccountMeta::new(*land_plane_acc_pubkey, false),
+        // those that require read-only access
+        AccountMeta::new_readonly(*nft_mint_acc_pubkey, false),
+        // those that require write access
+        AccountMeta::new(*land_plane_stats_acc_pubkey, false),
+        AccountMeta::new(land_asset_by_mint_acc_pubkey(land_program_acc_pubkey, nft_mint_acc_pubkey), false),
+        // those that require read-only access
+        AccountMeta::new_readonly(sysvar::clock::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *land_program_acc_pubkey,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `CheckCoordinate` instruction.
+/// 
+/// * `land_program_acc_pubkey`
+///     Public key of the land program account - aka. program ID.
+/// * `[] land_asset_acc_pubkey`
+///     Public key of the land asset account of the co-ordinate, which
+///     need not exist.
+/// * `[] land_plane_acc_pubkey`
+///     Public key of the land plane account on which the co-ordinate lies.
+/// * `x`
+///     x co-ordinate of the piece of land.
+/// * `z`
+///     z co-ordinate of the piece of land.
+pub fn check_coordinate(
+    land_program_acc_pubkey: &Pubkey,
+    land_asset_acc_pubkey: &Pubkey,
+    land_plane_acc_pubkey: &Pubkey,
+    x: u64,
+    z: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(land_program_acc_pubkey)?;
+    let data = LandInstruction::CheckCoordinate { x, z }.try_to_vec().unwrap();
+
+    // prepare list of account to pass to the instruction
+    let accounts = vec![
+        // 1st
+        // Addresses requiring signatures are 1st, and in the following order:
+        //
+        // those that require write access
+        // those that require read-only access
+
+        // 2nd
+        // Addresses not requiring signatures are 2nd, and in the following order:
+        //
+        // those that require write access
+        // those that require read-only access
+        AccountMeta::new_readonly(*land_asset_acc_pubkey, false),
+        AccountMeta::new_readonly(*land_plane_acc_pubkey, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *land_program_acc_pubkey,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an `EmitParcelRange` instruction.
+/// 
+/// * `land_program_acc_pubkey`
+///     Public key of the land program account - aka. program ID.
+/// * `[] land_plane_acc_pubkey`
+///     Public key of the land plane account from which the land was minted.
+/// * `start_index`
+///     Index in mint order of the first land asset account to log.
+/// * `[] land_asset_acc_pubkeys`
+///     Land asset accounts of the land minted at each index from
+///     start_index, in order, e.g. as given by asset_address_at_index.
+pub fn emit_parcel_range(
+    land_program_acc_pubkey: &Pubkey,
+    land_plane_acc_pubkey: &Pubkey,
+    start_index: u64,
+    land_asset_acc_pubkeys: &[Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(land_program_acc_pubkey)?;
+    let data = LandInstruction::EmitParcelRange {
+        start_index,
+        count: u8::try_from(land_asset_acc_pubkeys.len()).map_err(|_| ProgramError::InvalidArgument)?,
+    }.try_to_vec().unwrap();
+
+    // prepare list of account to pass to the instruction
+    let mut accounts = vec![
+        // 1st
+        // Addresses requiring signatures are 1st, and in the following order:
+        //
+        // those that require write access
+        // those that require read-only access
+
+        // 2nd
+        // Addresses not requiring signatures are 2nd, and in the following order:
+        //
+        // those that require write access
+        // those that require read-only access
+        AccountMeta::new_readonly(*land_plane_acc_pubkey, false),
+    ];
+    accounts.extend(land_asset_acc_pubkeys.iter().map(|pubkey| AccountMeta::new_readonly(*pubkey, false)));
+
+    Ok(Instruction {
+        program_id: *land_program_acc_pubkey,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `SetMintWindow` instruction.
+/// 
+/// * `land_program_acc_pubkey`
+///     Public key of the land program account - aka. program ID.
+/// * `[signer] land_plane_owner_acc_pubkey`
+///     Public key of the owner of the land plane.
+/// * `[writable] land_plane_acc_pubkey`
+///     Public key of the land plane account.
+/// * `mint_start_slot`
+///     Slot before which land may not be minted, if any.
+/// * `mint_end_slot`
+///     Slot after which land may not be minted, if any.
+pub fn set_mint_window(
+    land_program_acc_pubkey: &Pubkey,
+    land_plane_owner_acc_pubkey: &Pubkey,
+    land_plane_acc_pubkey: &Pubkey,
+    mint_start_slot: Option<u64>,
+    mint_end_slot: Option<u64>,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(land_program_acc_pubkey)?;
+    let data = LandInstruction::SetMintWindow { mint_start_slot, mint_end_slot }.try_to_vec().unwrap();
+
+    // prepare list of account to pass to the instruction
+    let accounts = vec![
+        // 1st
+        // Addresses requiring signatures are 1st, and in the following order:
+        //
+        // those that require write access
+        // those that require read-only access
+        AccountMeta::new_readonly(*land_plane_owner_acc_pubkey, true),
+
+        // 2nd
+        // Addresses not requiring signatures are 2nd, and in the following order:
+        //
+        // those that require write access
+        AccountMeta::new(*land_plane_acc_pubkey, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *land_program_acc_pubkey,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `SetPlaneTreasury` instruction.
+/// 
+/// * `land_program_acc_pubkey`
+///     Public key of the land program account - aka. program ID.
+/// * `[signer] land_plane_owner_acc_pubkey`
+///     Public key of the owner of the land plane.
+/// * `[writable] land_plane_acc_pubkey`
+///     Public key of the land plane account.
+/// * `plane_treasury`
+///     Account into which fees are paid, if not the owner.
+pub fn set_plane_treasury(
+    land_program_acc_pubkey: &Pubkey,
+    land_plane_owner_acc_pubkey: &Pubkey,
+    land_plane_acc_pubkey: &Pubkey,
+    plane_treasury: Option<Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(land_program_acc_pubkey)?;
+    let data = LandInstruction::SetPlaneTreasury { plane_treasury }.try_to_vec().unwrap();
+
+    // prepare list of account to pass to the instruction
+    let accounts = vec![
+        // 1st
+        // Addresses requiring signatures are 1st, and in the following order:
+        //
+        // those that require write access
+        // those that require read-only access
+        AccountMeta::new_readonly(*land_plane_owner_acc_pubkey, true),
+
+        // 2nd
+        // Addresses not requiring signatures are 2nd, and in the following order:
+        //
+        // those that require write access
+        AccountMeta::new(*land_plane_acc_pubkey, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *land_program_acc_pubkey,
+        accounts,
+        data,
+    })
+}
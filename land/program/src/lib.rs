@@ -1,10 +1,19 @@
 //! A solsspace Land program for the Solana blockchain.
 
+pub mod bounded_string;
+pub mod checked;
+#[cfg(feature = "client")]
+pub mod client;
 pub mod entrypoint;
 pub mod error;
 pub mod instruction;
+pub mod metadata;
 pub mod processor;
 pub mod state;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
+#[cfg(any(test, feature = "test-vectors"))]
+pub mod test_vectors;
 pub mod utils;
 // Export current sdk types for downstream users building with a different sdk version
 pub use solana_program;
@@ -0,0 +1,174 @@
+//! Minimal mirror of the Metaplex Token Metadata program's account
+//! layout and `UpdateMetadataAccount` instruction, sufficient for the
+//! land program to update the URI of an NFT's metadata and to read
+//! the collection to which it belongs.
+
+use {
+    crate::{
+        error::LandError,
+        state::Coordinate,
+    },
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::{
+        account_info::AccountInfo,
+        borsh::try_from_slice_unchecked,
+        instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+};
+
+/// Metaplex Token Metadata program
+pub mod token_metadata_program {
+    solana_program::declare_id!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+}
+
+pub const METADATA_ACC_PREFIX: &str = "metadata";
+
+pub const MAX_URI_LENGTH: usize = 200;
+
+/// Index of the `UpdateMetadataAccount` instruction in the Metaplex
+/// Token Metadata program's instruction enum.
+const UPDATE_METADATA_ACCOUNT_INSTRUCTION: u8 = 1;
+
+#[derive(Clone, BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+pub struct Creator {
+    pub address: Pubkey,
+    pub verified: bool,
+    pub share: u8,
+}
+
+#[derive(Clone, BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+pub struct Data {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub seller_fee_basis_points: u16,
+    pub creators: Option<Vec<Creator>>,
+}
+
+#[derive(Clone, BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+pub struct Collection {
+    pub verified: bool,
+    pub key: Pubkey,
+}
+
+/// Metadata account layout. Fields following is_mutable were added by
+/// later versions of the Metaplex program, and read as None from the
+/// zero padding of older accounts. Those following collection are not
+/// mirrored.
+#[derive(Clone, BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+pub struct Metadata {
+    pub key: u8,
+    pub update_authority: Pubkey,
+    pub mint: Pubkey,
+    pub data: Data,
+    pub primary_sale_happened: bool,
+    pub is_mutable: bool,
+    pub edition_nonce: Option<u8>,
+    /// Metaplex TokenStandard, held by its index
+    pub token_standard: Option<u8>,
+    pub collection: Option<Collection>,
+}
+
+impl Metadata {
+    pub fn from_account_info(a: &AccountInfo) -> Result<Metadata, ProgramError> {
+        // metadata accounts are padded beyond their serialized length
+        let result: Metadata = try_from_slice_unchecked(&a.data.borrow())?;
+        Ok(result)
+    }
+
+    /// In_verified_collection returns true if the NFT belongs to the
+    /// given collection, as verified by the collection's authority.
+    pub fn in_verified_collection(&self, collection_pubkey: &Pubkey) -> bool {
+        matches!(&self.collection, Some(c) if c.verified && &c.key == collection_pubkey)
+    }
+}
+
+#[derive(Clone, BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+pub struct UpdateMetadataAccountArgs {
+    pub data: Option<Data>,
+    pub update_authority: Option<Pubkey>,
+    pub primary_sale_happened: Option<bool>,
+}
+
+/// Metadata_acc_pubkey returns the PDA of the metadata account
+/// belonging to the given NFT mint.
+/// i.e. PDA of (['metadata', token_metadata_program_id, mint_pubkey], token_metadata_program_id)
+pub fn metadata_acc_pubkey(mint_pubkey: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            METADATA_ACC_PREFIX.as_bytes(),
+            token_metadata_program::id().as_ref(),
+            mint_pubkey.as_ref(),
+        ],
+        &token_metadata_program::id(),
+    ).0
+}
+
+/// Creates a Metaplex `UpdateMetadataAccount` instruction replacing
+/// the data of the given metadata account.
+pub fn update_metadata_account(
+    metadata_acc_pubkey: &Pubkey,
+    update_authority_pubkey: &Pubkey,
+    data: Data,
+) -> Instruction {
+    let mut instruction_data = vec![UPDATE_METADATA_ACCOUNT_INSTRUCTION];
+    instruction_data.extend(
+        UpdateMetadataAccountArgs {
+            data: Some(data),
+            update_authority: None,
+            primary_sale_happened: None,
+        }.try_to_vec().unwrap()
+    );
+
+    Instruction {
+        program_id: token_metadata_program::id(),
+        accounts: vec![
+            AccountMeta::new(*metadata_acc_pubkey, false),
+            AccountMeta::new_readonly(*update_authority_pubkey, true),
+        ],
+        data: instruction_data,
+    }
+}
+
+/// Coordinate_uri returns the given URI with the co-ordinate of a
+/// piece of land appended as query parameters, e.g.
+///
+/// ```text
+/// https://example.com/nft.json?x=3&z=1
+/// ```
+///
+/// Metaplex pads stored strings with null bytes, these are stripped
+/// from the given URI first. UriTooLong is returned if the result
+/// would not fit in a metadata account.
+pub fn coordinate_uri(uri: &str, coordinate: Coordinate) -> Result<String, ProgramError> {
+    let uri = uri.trim_end_matches(char::from(0));
+    let separator = if uri.contains('?') { '&' } else { '?' };
+    let result = format!("{}{}x={}&z={}", uri, separator, coordinate.x, coordinate.z);
+    if result.len() > MAX_URI_LENGTH {
+        return Err(LandError::UriTooLong.into());
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coordinate_uri() {
+        assert_eq!(
+            Ok("https://example.com/nft.json?x=3&z=1".to_string()),
+            coordinate_uri("https://example.com/nft.json\0\0\0", Coordinate { x: 3, z: 1 }),
+        );
+        assert_eq!(
+            Ok("https://example.com/nft?id=7&x=0&z=12".to_string()),
+            coordinate_uri("https://example.com/nft?id=7", Coordinate { x: 0, z: 12 }),
+        );
+        assert_eq!(
+            Err(LandError::UriTooLong.into()),
+            coordinate_uri(&"a".repeat(MAX_URI_LENGTH), Coordinate { x: 0, z: 0 }),
+        );
+    }
+}
@@ -1,43 +1,239 @@
 use {
     crate::{
+        bounded_string::BoundedString,
+        checked::checked_add_err,
         error::LandError,
         instruction::{
+            BatchedInstruction,
             LandInstruction,
+            MAX_BATCH_COUNT,
+            MAX_PARCEL_RANGE_COUNT,
         },
         state::{
             LAND_PLANE_ACC_DATA_LEN,
+            MAX_PLANE_DEPTH,
+            MAX_RANDOM_FILL_PROBES,
             LAND_ASSET_ACC_PREFIX,
             LAND_ASSET_ACC_DATA_LEN,
+            LAND_ASSET_V4_ACC_DATA_LEN,
+            LAND_ASSET_V5_ACC_DATA_LEN,
+            LAND_ASSET_V6_ACC_DATA_LEN,
+            LAND_ASSET_V7_ACC_DATA_LEN,
             LandPlane,
+            LandPlaneConfig,
             LandPlaneVersion,
+            LAND_PLANE_STATS_ACC_PREFIX,
+            LAND_PLANE_STATS_ACC_DATA_LEN,
             LandAsset,
             LandAssetVersion,            
+            Coordinate,
+            LandPlaneStats,
+            LandPlaneStatsVersion,
+            PROGRAM_CONFIG_ACC_PREFIX,
+            PROGRAM_CONFIG_ACC_DATA_LEN,
+            ProgramConfig,
+            ProgramConfigVersion,
+            LAND_ASSET_BY_MINT_ACC_PREFIX,
+            LAND_ASSET_BY_MINT_ACC_DATA_LEN,
+            LandAssetByMint,
+            LandAssetByMintVersion,
+            MintPattern,
+            addr_width,
+            coordinate_at_index,
+            coordinate_seed,
+            is_unset_pubkey,
+            WALLET_MINT_RECORD_ACC_PREFIX,
+            WALLET_MINT_RECORD_ACC_DATA_LEN,
+            WalletMintRecord,
+            WalletMintRecordVersion,
+            PLANE_METADATA_ACC_PREFIX,
+            PLANE_METADATA_ACC_DATA_LEN,
+            MAX_PLANE_NAME_LEN,
+            MAX_PLANE_SYMBOL_LEN,
+            MAX_PLANE_BASE_URI_LEN,
+            PlaneMetadata,
+            PlaneMetadataVersion,
+            PriceFeed,
+            PriceFeedVersion,
+            land_asset_by_mint_acc_pubkey,
+            require_compatible_versions,
         },
         utils::{
+            close_account,
             create_or_allocate_account_raw,
-        }
+            minimum_balance,
+        },
+        metadata::{
+            coordinate_uri,
+            metadata_acc_pubkey,
+            token_metadata_program,
+            update_metadata_account,
+            Metadata,
+        },
     },
     borsh::{BorshDeserialize,BorshSerialize},
     solana_program::{
         account_info::{next_account_info, AccountInfo},
+        bpf_loader_upgradeable::{self, UpgradeableLoaderState},
         entrypoint::ProgramResult,
         msg,
-        sysvar::{rent::Rent, Sysvar},
+        program::invoke,
+        program_error::ProgramError,
+        program_pack::Pack,
+        sysvar::{self, clock::Clock, rent::Rent, Sysvar},
         pubkey::Pubkey,
+        system_instruction,
+        system_program,
     },
+    spl_token::state::{Account, AccountState, Mint},
+    std::convert::TryInto,
 };
 
+/// Prefix of the log line written on a successful mint, followed by
+/// the co-ordinate of the minted land, e.g.
+///
+/// ```text
+/// Minted land asset: x=3, z=1
+/// ```
+pub const MINTED_LAND_ASSET_LOG_PREFIX: &str = "Minted land asset: ";
+
+/// Prefix of the log line written on a successful mint, after the
+/// co-ordinate, followed by the base-58 encoded LandPlane::state_hash
+/// of the land plane once the mint is complete, e.g.
+///
+/// ```text
+/// Land plane state hash: 5Hf3ZkvNtwVSKmf8WypvGZF4PY7e4EBnWnVzkm2tbXvC
+/// ```
+pub const LAND_PLANE_STATE_HASH_LOG_PREFIX: &str = "Land plane state hash: ";
+
+/// First of the five values of the event logged with sol_log_64 on a
+/// successful mint in place of the minted land asset line, when built
+/// with the compact-logs feature. It is followed by the co-ordinate of
+/// the minted land and two zeros, e.g. for land at (3, 1)
+///
+/// ```text
+/// 0x4d494e54, 0x3, 0x1, 0x0, 0x0
+/// ```
+pub const MINTED_LAND_ASSET_EVENT_TAG: u64 = 0x4d49_4e54; // "MINT"
+
+/// First of the five values of the event logged with sol_log_64 on a
+/// successful mint in place of the land plane state hash line, when
+/// built with the compact-logs feature. It is followed by the four
+/// 8 byte chunks of the state hash, each read as a little-endian u64.
+pub const LAND_PLANE_STATE_HASH_EVENT_TAG: u64 = 0x4841_5348; // "HASH"
+
+/// Prefix of the log line written by VerifyParcelOwnership, followed by
+/// the co-ordinate of the land and whether the signer holds the NFT to
+/// which it is bound, e.g.
+///
+/// ```text
+/// Parcel ownership: x=3, z=1, held=true
+/// ```
+pub const PARCEL_OWNERSHIP_LOG_PREFIX: &str = "Parcel ownership: ";
+
+/// First of the five values of the event logged with sol_log_64 by
+/// VerifyParcelOwnership in place of the parcel ownership line, when
+/// built with the compact-logs feature. It is followed by the
+/// co-ordinate of the land, 1 if the signer holds the NFT to which it
+/// is bound or else 0, and a zero.
+pub const PARCEL_OWNERSHIP_EVENT_TAG: u64 = 0x4f57_4e53; // "OWNS"
+
+/// Prefix of the log line written by CheckCoordinate, followed by the
+/// co-ordinate, whether its land asset account has been initialised,
+/// whether the land has been minted, and the NFT mint to which it is
+/// bound, being the default public key if it is not, e.g.
+///
+/// ```text
+/// Coordinate occupancy: x=3, z=1, initialised=true, minted=true, mint=5Hf3ZkvNtwVSKmf8WypvGZF4PY7e4EBnWnVzkm2tbXvC
+/// ```
+pub const COORDINATE_OCCUPANCY_LOG_PREFIX: &str = "Coordinate occupancy: ";
+
+/// First of the five values of the event logged with sol_log_64 by
+/// CheckCoordinate in place of the coordinate occupancy line, when
+/// built with the compact-logs feature. It is followed by the
+/// co-ordinate, 1 if its land asset account has been initialised or
+/// else 0, and 1 if the land has been minted or else 0. The NFT mint
+/// to which the land is bound is then logged with sol_log_pubkey.
+pub const COORDINATE_OCCUPANCY_EVENT_TAG: u64 = 0x4f43_4355; // "OCCU"
+
+/// Prefix of the log line written by EmitParcelRange, followed by the
+/// index in mint order of the first piece of land and the hex encoding
+/// of the state of each land asset account in turn, each packed at
+/// LAND_ASSET_ACC_DATA_LEN bytes, zeroed for accounts that do not yet
+/// exist, e.g.
+///
+/// ```text
+/// Parcel range: start_index=0, data=08b5e4...
+/// ```
+///
+/// The line is already packed, so it is written the same way when built
+/// with the compact-logs feature.
+pub const PARCEL_RANGE_LOG_PREFIX: &str = "Parcel range: ";
+
+/// Decode a land program instruction from instruction data, without
+/// processing it
+pub fn decode_instruction(input: &[u8]) -> Result<LandInstruction, ProgramError> {
+    Ok(LandInstruction::try_from_slice(input)?)
+}
+
 pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     input: &[u8],
 ) -> ProgramResult {
-    let instruction = LandInstruction::try_from_slice(input)?;
+    let instruction = decode_instruction(input)?;
+
+    // confirm that the program ID has not been given in place of an
+    // account to be written, e.g. by a client mixing up its keys
+    if accounts.iter().any(|a| a.is_writable && a.key == program_id) {
+        return Err(LandError::InvalidAccountProgramId.into());
+    }
+
+    process_land_instruction(program_id, accounts, instruction)
+}
+
+/// Process a single land program instruction, either given at the top
+/// level or as a sub-instruction of a ProcessBatch instruction
+pub fn process_land_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction: LandInstruction,
+) -> ProgramResult {
     match instruction {
-        LandInstruction::InitialiseLandPlane => {
+        LandInstruction::InitialiseLandPlane {
+            coordinate_ceiling,
+            require_frozen_mint,
+            mint_fee,
+            owner,
+            mint_pattern,
+            one_parcel_per_nft,
+            royalty_bps,
+            compact_addresses,
+            required_collection,
+            max_supply,
+            mint_cooldown_slots,
+            price_oracle,
+            reserve_claimant,
+            closes_at_slot,
+        } => {
             msg!("Instruction: Initialise Land Plane");
             process_initialise_land_plane(
+                program_id,
                 accounts,
+                coordinate_ceiling,
+                require_frozen_mint,
+                mint_fee,
+                owner,
+                mint_pattern,
+                one_parcel_per_nft,
+                royalty_bps,
+                compact_addresses,
+                required_collection,
+                max_supply,
+                mint_cooldown_slots,
+                price_oracle,
+                reserve_claimant,
+                closes_at_slot,
             )
         },
         LandInstruction::InitialiseNextLandAsset => {
@@ -54,18 +250,244 @@ pub fn process_instruction(
                 accounts,
             )
         }
+        LandInstruction::InitialiseLandPlaneStats => {
+            msg!("Instruction: Initialise Land Plane Stats");
+            process_initialise_land_plane_stats(
+                program_id,
+                accounts,
+            )
+        }
+        LandInstruction::InitialiseLandPlaneIdempotent {
+            coordinate_ceiling,
+            require_frozen_mint,
+            mint_fee,
+            owner,
+            mint_pattern,
+            one_parcel_per_nft,
+            royalty_bps,
+            compact_addresses,
+            required_collection,
+            max_supply,
+            mint_cooldown_slots,
+            price_oracle,
+            reserve_claimant,
+            closes_at_slot,
+        } => {
+            msg!("Instruction: Initialise Land Plane Idempotent");
+            process_initialise_land_plane_idempotent(
+                program_id,
+                accounts,
+                coordinate_ceiling,
+                require_frozen_mint,
+                mint_fee,
+                owner,
+                mint_pattern,
+                one_parcel_per_nft,
+                royalty_bps,
+                compact_addresses,
+                required_collection,
+                max_supply,
+                mint_cooldown_slots,
+                price_oracle,
+                reserve_claimant,
+                closes_at_slot,
+            )
+        }
+        LandInstruction::StampCoordinateUri { x, z } => {
+            msg!("Instruction: Stamp Coordinate URI");
+            process_stamp_coordinate_uri(
+                program_id,
+                accounts,
+                Coordinate { x, z },
+            )
+        }
+        LandInstruction::InitialiseProgramConfig {
+            protocol_fee,
+            protocol_treasury,
+        } => {
+            msg!("Instruction: Initialise Program Config");
+            process_initialise_program_config(
+                program_id,
+                accounts,
+                protocol_fee,
+                protocol_treasury,
+            )
+        }
+        LandInstruction::TransferLand { x, z, sale_price } => {
+            msg!("Instruction: Transfer Land");
+            process_transfer_land(
+                program_id,
+                accounts,
+                Coordinate { x, z },
+                sale_price,
+            )
+        }
+        LandInstruction::RepairParcelCoordinate => {
+            msg!("Instruction: Repair Parcel Coordinate");
+            process_repair_parcel_coordinate(
+                program_id,
+                accounts,
+            )
+        }
+        LandInstruction::ProcessBatch { instructions } => {
+            msg!("Instruction: Process Batch");
+            process_batched_instructions(
+                program_id,
+                accounts,
+                instructions,
+            )
+        }
+        LandInstruction::SetupPlane {
+            coordinate_ceiling,
+            require_frozen_mint,
+            mint_fee,
+            owner,
+            mint_pattern,
+            one_parcel_per_nft,
+            royalty_bps,
+            compact_addresses,
+            required_collection,
+            max_supply,
+            mint_cooldown_slots,
+            price_oracle,
+            reserve_claimant,
+            closes_at_slot,
+        } => {
+            msg!("Instruction: Setup Plane");
+            process_setup_plane(
+                program_id,
+                accounts,
+                coordinate_ceiling,
+                require_frozen_mint,
+                mint_fee,
+                owner,
+                mint_pattern,
+                one_parcel_per_nft,
+                royalty_bps,
+                compact_addresses,
+                required_collection,
+                max_supply,
+                mint_cooldown_slots,
+                price_oracle,
+                reserve_claimant,
+                closes_at_slot,
+            )
+        }
+        LandInstruction::MigrateAssetsBatch { start_index, count } => {
+            msg!("Instruction: Migrate Assets Batch");
+            process_migrate_assets_batch(
+                program_id,
+                accounts,
+                start_index,
+                count,
+            )
+        }
+        LandInstruction::ForceSetCursor { next_x, next_z, depth } => {
+            msg!("Instruction: Force Set Cursor");
+            process_force_set_cursor(
+                program_id,
+                accounts,
+                next_x,
+                next_z,
+                depth,
+            )
+        }
+        LandInstruction::SetParcelAttributes { mask, value } => {
+            msg!("Instruction: Set Parcel Attributes");
+            process_set_parcel_attributes(
+                program_id,
+                accounts,
+                mask,
+                value,
+            )
+        }
+        LandInstruction::MergeParcels => {
+            msg!("Instruction: Merge Parcels");
+            process_merge_parcels(program_id, accounts)
+        }
+        LandInstruction::SetPlaneMetadata { name, symbol, base_uri } => {
+            msg!("Instruction: Set Plane Metadata");
+            process_set_plane_metadata(
+                program_id,
+                accounts,
+                name,
+                symbol,
+                base_uri,
+            )
+        }
+        LandInstruction::VerifyParcelOwnership => {
+            msg!("Instruction: Verify Parcel Ownership");
+            process_verify_parcel_ownership(program_id, accounts)
+        }
+        LandInstruction::AdminRelinkParcel { new_mint } => {
+            msg!("Instruction: Admin Relink Parcel");
+            process_admin_relink_parcel(program_id, accounts, &new_mint)
+        }
+        LandInstruction::MintNextWithAttributes { attributes } => {
+            msg!("Instruction: Mint Next With Attributes");
+            process_mint_next_with_attributes(program_id, accounts, attributes)
+        }
+        LandInstruction::LockParcel { locker } => {
+            msg!("Instruction: Lock Parcel");
+            process_lock_parcel(program_id, accounts, &locker)
+        }
+        LandInstruction::UnlockParcel => {
+            msg!("Instruction: Unlock Parcel");
+            process_unlock_parcel(program_id, accounts)
+        }
+        LandInstruction::MintNextWithDeadline { valid_until_slot } => {
+            msg!("Instruction: Mint Next With Deadline");
+            process_mint_next_with_deadline(program_id, accounts, valid_until_slot)
+        }
+        LandInstruction::ClaimReservedParcel { x, z } => {
+            msg!("Instruction: Claim Reserved Parcel");
+            process_claim_reserved_parcel(program_id, accounts, Coordinate { x, z })
+        }
+        LandInstruction::CheckCoordinate { x, z } => {
+            msg!("Instruction: Check Coordinate");
+            process_check_coordinate(program_id, accounts, Coordinate { x, z })
+        }
+        LandInstruction::EmitParcelRange { start_index, count } => {
+            msg!("Instruction: Emit Parcel Range");
+            process_emit_parcel_range(program_id, accounts, start_index, count)
+        }
+        LandInstruction::SetMintWindow { mint_start_slot, mint_end_slot } => {
+            msg!("Instruction: Set Mint Window");
+            process_set_mint_window(accounts, mint_start_slot, mint_end_slot)
+        }
+        LandInstruction::SetPlaneTreasury { plane_treasury } => {
+            msg!("Instruction: Set Plane Treasury");
+            process_set_plane_treasury(accounts, plane_treasury)
+        }
     }
 }
 
 /// Initialise a new Land Plane
+#[allow(clippy::too_many_arguments)]
 pub fn process_initialise_land_plane(
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
+    coordinate_ceiling: Option<u64>,
+    require_frozen_mint: bool,
+    mint_fee: u64,
+    owner: Option<Pubkey>,
+    mint_pattern: MintPattern,
+    one_parcel_per_nft: bool,
+    royalty_bps: u16,
+    compact_addresses: bool,
+    required_collection: Option<Pubkey>,
+    max_supply: Option<u64>,
+    mint_cooldown_slots: u64,
+    price_oracle: Option<Pubkey>,
+    reserve_claimant: Option<Pubkey>,
+    closes_at_slot: Option<u64>,
 ) -> ProgramResult {
     // prepare an account info iterator and get a handle
     // on required accounts
     let account_info_iter = &mut accounts.iter();
     let land_plane_acc_info = next_account_info(account_info_iter)?;
     let rent_acc_info = next_account_info(account_info_iter)?;
+    let program_config_acc_info = next_account_info(account_info_iter)?;
 
     // parse the uninitialised land plane account state
     let mut land_plane_acc_state = LandPlane::from_account_info(land_plane_acc_info)?;
@@ -78,23 +500,151 @@ pub fn process_initialise_land_plane(
     // parse rent from rent account info
     let rent = &Rent::from_account_info(rent_acc_info)?;    
 
-    // confirm that given land plane account is rent exempt
-    if !rent.is_exempt(land_plane_acc_info.lamports(), LAND_PLANE_ACC_DATA_LEN) {
+    // confirm that given land plane account is rent exempt, and
+    // funded even if no rent is charged, unless built for testing with
+    // the skip-rent-check feature, which must never be deployed
+    if !cfg!(feature = "skip-rent-check")
+        && land_plane_acc_info.lamports() < minimum_balance(rent, LAND_PLANE_ACC_DATA_LEN)
+    {
         return Err(LandError::NotRentExempt.into());
     }    
 
+    // confirm that land filled at random is bounded, so
+    // that there is a square from which to pick it
+    if mint_pattern == MintPattern::RandomFill && coordinate_ceiling.is_none() {
+        return Err(LandError::RandomFillRequiresCeiling.into());
+    }
+
+    // and that land with compact addresses is bounded, so that
+    // its co-ordinates fit in the width of their seeds
+    let addr_width = addr_width(coordinate_ceiling, compact_addresses)?;
+
     // initialise values
-    land_plane_acc_state.version = LandPlaneVersion::V1;
+    land_plane_acc_state.version = LandPlaneVersion::V18;
     land_plane_acc_state.next_x = 0;
     land_plane_acc_state.next_z = 0;
     land_plane_acc_state.depth = 0;
+    land_plane_acc_state.coordinate_ceiling = coordinate_ceiling;
+    land_plane_acc_state.require_frozen_mint = require_frozen_mint;
+    land_plane_acc_state.mint_fee = mint_fee;
+    land_plane_acc_state.owner = owner;
+    land_plane_acc_state.mint_pattern = mint_pattern;
+    land_plane_acc_state.one_parcel_per_nft = one_parcel_per_nft;
+    land_plane_acc_state.royalty_bps = royalty_bps;
+    land_plane_acc_state.addr_width = addr_width;
+    land_plane_acc_state.required_collection = required_collection;
+    land_plane_acc_state.migrated_count = 0;
+    land_plane_acc_state.max_supply = max_supply;
+    land_plane_acc_state.mint_cooldown_slots = mint_cooldown_slots;
+    land_plane_acc_state.price_oracle = price_oracle;
+    land_plane_acc_state.reserve_claimant = reserve_claimant;
+    land_plane_acc_state.closes_at_slot = closes_at_slot;
+    land_plane_acc_state.mint_start_slot = None;
+    land_plane_acc_state.mint_end_slot = None;
+    land_plane_acc_state.random_fill_seed = [0; 32];
+    land_plane_acc_state.plane_treasury = None;
+
+    // confirm correct program_config_acc was provided
+    let (program_config_acc_key, _) = Pubkey::find_program_address(
+        &[PROGRAM_CONFIG_ACC_PREFIX.as_bytes()],
+        program_id,
+    );
+    if program_config_acc_info.key != &program_config_acc_key {
+        return Err(LandError::InvalidProgramConfigAccKey.into());
+    }
+
+    // parse program config account state and confirm
+    // that the given account has been initialised
+    let mut program_config_acc_state = ProgramConfig::from_account_info(program_config_acc_info)?;
+    if program_config_acc_state.version == ProgramConfigVersion::Uninitialised {
+        return Err(LandError::ProgramConfigAccUninitialised.into());
+    }
+
+    // and count the new land plane on it
+    program_config_acc_state.record_plane();
 
-    // then serialize the land plane account state again
-    land_plane_acc_state.serialize(&mut *land_plane_acc_info.data.borrow_mut())?;
+    // then pack the land plane and program config account states again
+    land_plane_acc_state.pack_versioned(&mut land_plane_acc_info.data.borrow_mut())?;
+    program_config_acc_state.pack_versioned(&mut program_config_acc_info.data.borrow_mut())?;
     
     Ok(())
 }
 
+/// Initialise a new Land Plane, doing nothing if the land plane
+/// is already initialised with the given config
+#[allow(clippy::too_many_arguments)]
+pub fn process_initialise_land_plane_idempotent(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    coordinate_ceiling: Option<u64>,
+    require_frozen_mint: bool,
+    mint_fee: u64,
+    owner: Option<Pubkey>,
+    mint_pattern: MintPattern,
+    one_parcel_per_nft: bool,
+    royalty_bps: u16,
+    compact_addresses: bool,
+    required_collection: Option<Pubkey>,
+    max_supply: Option<u64>,
+    mint_cooldown_slots: u64,
+    price_oracle: Option<Pubkey>,
+    reserve_claimant: Option<Pubkey>,
+    closes_at_slot: Option<u64>,
+) -> ProgramResult {
+    // prepare an account info iterator and get a handle
+    // on required accounts
+    let account_info_iter = &mut accounts.iter();
+    let land_plane_acc_info = next_account_info(account_info_iter)?;
+
+    // parse the land plane account state
+    let land_plane_acc_state = LandPlane::from_account_info(land_plane_acc_info)?;
+
+    // if the account is not yet initialised then initialise it as usual
+    if land_plane_acc_state.version == LandPlaneVersion::Uninitialised {
+        return process_initialise_land_plane(
+            program_id,
+            accounts,
+            coordinate_ceiling,
+            require_frozen_mint,
+            mint_fee,
+            owner,
+            mint_pattern,
+            one_parcel_per_nft,
+            royalty_bps,
+            compact_addresses,
+            required_collection,
+            max_supply,
+            mint_cooldown_slots,
+            price_oracle,
+            reserve_claimant,
+            closes_at_slot,
+        );
+    }
+
+    // otherwise confirm that it was initialised with the same config
+    let config = LandPlaneConfig {
+        coordinate_ceiling,
+        require_frozen_mint,
+        mint_fee,
+        owner,
+        mint_pattern,
+        one_parcel_per_nft,
+        royalty_bps,
+        addr_width: addr_width(coordinate_ceiling, compact_addresses)?,
+        required_collection,
+        max_supply,
+        mint_cooldown_slots,
+        price_oracle,
+        reserve_claimant,
+        closes_at_slot,
+    };
+    if land_plane_acc_state.config() != config {
+        return Err(LandError::LandPlaneConfigMismatch.into());
+    }
+
+    Ok(())
+}
+
 /// Initialise a new Land Asset
 pub fn process_initialise_land_asset(
     program_id: &Pubkey,
@@ -108,13 +658,19 @@ pub fn process_initialise_land_asset(
     let land_plane_acc_info = next_account_info(account_info_iter)?;
     let rent_sysvar_acc_info = next_account_info(account_info_iter)?;
     let system_program_acc_info = next_account_info(account_info_iter)?;
+    // account only given where the authority is not the rent payer
+    let authority_acc_info = account_info_iter.next();
 
-    // parse land asset account state and confirm
-    // that the given account has NOT yet been initialised
-    let land_asset_acc_state = LandAsset::from_account_info(land_asset_acc_info)?;
-    if land_asset_acc_state.version != LandAssetVersion::Uninitialised {
-        return Err(LandError::LandAssetAccUninitialised.into());
-    }    
+    // confirm that the rent payer is a signatory on the transaction
+    if !rent_payer_acc_info.is_signer {
+        return Err(LandError::MissingSignature.into());
+    }
+
+    // confirm that the genuine system program was provided,
+    // since it is invoked to create the land asset account
+    if system_program_acc_info.key != &system_program::id() {
+        return Err(LandError::InvalidSystemProgram.into());
+    }
 
     // parse land plane account state and confirm
     // that the given account has been initialised
@@ -123,9 +679,158 @@ pub fn process_initialise_land_asset(
         return Err(LandError::LandPlaneAccUninitialised.into());
     }
 
+    // confirm that any separate authority is a signatory on the
+    // transaction and, for a land plane with an owner, is the owner
+    if let Some(authority_acc_info) = authority_acc_info {
+        if !authority_acc_info.is_signer {
+            return Err(LandError::MissingSignature.into());
+        }
+        if matches!(land_plane_acc_state.owner, Some(owner) if &owner != authority_acc_info.key) {
+            return Err(LandError::LandPlaneOwnerMismatch.into());
+        }
+    }
+
+    // confirm that the next piece of land can still be minted, so
+    // that no rent is stranded in an account that never will be
+    if land_plane_acc_state.max_supply_reached() || land_plane_acc_state.is_complete() {
+        return Err(LandError::CoordinateOutOfBounds.into());
+    }
+
+    // derive expected PDA for the next piece of land
+    // and confirm correct land_asset_acc was provided
+    let next = Coordinate { x: land_plane_acc_state.next_x, z: land_plane_acc_state.next_z };
+    let (land_asset_acc_key, land_asset_acc_bump) =
+        land_plane_acc_state.land_asset_address(program_id, land_plane_acc_info.key, next);
+    if land_asset_acc_info.key != &land_asset_acc_key {
+        return Err(LandError::InvalidLandAssetAccKey.into());
+    }
+
+    // confirm that the account has not already been initialised
+    if !land_asset_acc_info.data_is_empty() {
+        let land_asset_acc_state = LandAsset::from_account_info(land_asset_acc_info)?;
+        if land_asset_acc_state.version != LandAssetVersion::Uninitialised {
+            return Err(LandError::LandAssetAccAlreadyInitialised.into());
+        }
+    }
+
+    // create the land asset account
+    create_or_allocate_account_raw(
+        *program_id,
+        land_asset_acc_info,
+        rent_sysvar_acc_info,
+        system_program_acc_info,
+        rent_payer_acc_info,
+        LAND_ASSET_ACC_DATA_LEN,
+        &[
+            LAND_ASSET_ACC_PREFIX.as_bytes(),
+            land_plane_acc_info.key.as_ref(),
+            &coordinate_seed(next.x, land_plane_acc_state.addr_width),
+            &coordinate_seed(next.z, land_plane_acc_state.addr_width),
+            &[land_asset_acc_bump],
+        ],
+    )?;
+
+    // initialise values, leaving the land to be bound to an nft on mint,
+    // and keeping the bump so that the mint need not search for it
+    let mut land_asset_acc_state = LandAsset::from_account_info(land_asset_acc_info)?;
+    land_asset_acc_state.version = LandAssetVersion::V8;
+    land_asset_acc_state.bump = land_asset_acc_bump;
+    land_asset_acc_state.mint_pubkey = Pubkey::default();
+    land_asset_acc_state.minter = Pubkey::default();
+    land_asset_acc_state.land_plane = *land_plane_acc_info.key;
+    land_asset_acc_state.coordinate = Coordinate {
+        x: land_plane_acc_state.next_x,
+        z: land_plane_acc_state.next_z,
+    };
+
+    // then pack the land asset account state
+    land_asset_acc_state.pack_versioned(&mut land_asset_acc_info.data.borrow_mut())?;
+
     Ok(())
 }
 
+/// Land_asset_acc_bump returns the bump kept by the given land asset
+/// account, or None if it keeps none, either as it was allocated before
+/// V6 or as it is not a land asset account of this program.
+fn land_asset_acc_bump(program_id: &Pubkey, land_asset_acc_info: &AccountInfo) -> Option<u8> {
+    if land_asset_acc_info.owner != program_id || land_asset_acc_info.data_len() < LAND_ASSET_V6_ACC_DATA_LEN {
+        return None;
+    }
+    match LandAsset::from_account_info(land_asset_acc_info) {
+        Ok(land_asset_acc_state)
+            if matches!(
+                land_asset_acc_state.version,
+                LandAssetVersion::V6 | LandAssetVersion::V7 | LandAssetVersion::V8
+            ) =>
+        {
+            Some(land_asset_acc_state.bump)
+        }
+        _ => None,
+    }
+}
+
+/// Is_claimed_land_asset_acc returns true if the given account is the
+/// genuine land asset account of a piece of land on the given land plane
+/// that the land plane has already passed in its minting pattern.
+fn is_claimed_land_asset_acc(
+    program_id: &Pubkey,
+    land_plane_acc_pubkey: &Pubkey,
+    land_plane_acc_state: &LandPlane,
+    land_asset_acc_info: &AccountInfo,
+) -> bool {
+    if land_asset_acc_info.owner != program_id || land_asset_acc_info.data_is_empty() {
+        return false;
+    }
+    let land_asset_acc_state = match LandAsset::from_account_info(land_asset_acc_info) {
+        Ok(land_asset_acc_state) => land_asset_acc_state,
+        Err(_) => return false,
+    };
+    land_asset_acc_state.version != LandAssetVersion::Uninitialised
+        && &land_asset_acc_state.land_plane == land_plane_acc_pubkey
+        && land_plane_acc_state.has_passed(land_asset_acc_state.coordinate)
+        && land_asset_acc_info.key == &land_plane_acc_state.land_asset_address(
+            program_id,
+            land_plane_acc_pubkey,
+            land_asset_acc_state.coordinate,
+        ).0
+}
+
+/// Random_fill_target returns the co-ordinate of the next piece of land
+/// minted from the given land plane filled at random whose land asset
+/// account has the given key, probing from its candidate co-ordinate
+/// for the given recent slot hash.
+/// The land asset account of each co-ordinate probed past is found by
+/// key among the given accounts, and must show its land to be minted.
+fn random_fill_target(
+    program_id: &Pubkey,
+    land_plane_acc_pubkey: &Pubkey,
+    land_plane_acc_state: &LandPlane,
+    land_asset_acc_pubkey: &Pubkey,
+    recent_slot_hash: &[u8; 32],
+    accounts: &[AccountInfo],
+) -> Result<Coordinate, ProgramError> {
+    for probe in 0..=MAX_RANDOM_FILL_PROBES {
+        let coordinate = land_plane_acc_state.random_fill_coordinate(recent_slot_hash, probe)?;
+        let (address, _) = land_plane_acc_state.land_asset_address(program_id, land_plane_acc_pubkey, coordinate);
+        if &address == land_asset_acc_pubkey {
+            return Ok(coordinate);
+        }
+
+        // the land probed past must already have been minted
+        let probed_acc_info = accounts
+            .iter()
+            .find(|acc_info| *acc_info.key == address)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        if probed_acc_info.owner != program_id
+            || probed_acc_info.data_is_empty()
+            || !LandAsset::from_account_info(probed_acc_info)?.is_minted()
+        {
+            return Err(LandError::ProbedLandNotMinted.into());
+        }
+    }
+    Err(LandError::InvalidLandAssetAccKey.into())
+}
+
 /// Mint next piece of land
 pub fn process_mint_next_land_asset(
     program_id: &Pubkey,
@@ -134,258 +839,10555 @@ pub fn process_mint_next_land_asset(
     // prepare an account info iterator and get a handle
     // on required accounts
     let account_info_iter = &mut accounts.iter();
+    let payer_acc_info = next_account_info(account_info_iter)?;
     let nft_assoc_token_acc_owner_acc_info = next_account_info(account_info_iter)?;
     let land_asset_acc_info = next_account_info(account_info_iter)?;
     let land_plane_acc_info = next_account_info(account_info_iter)?;
-    let _nft_assoc_token_acc_owner_acc_info = next_account_info(account_info_iter)?;
-    let _nft_mint_acc_info = next_account_info(account_info_iter)?;
+    let nft_assoc_token_acc_info = next_account_info(account_info_iter)?;
+    let nft_mint_acc_info = next_account_info(account_info_iter)?;
+    let land_plane_stats_acc_info = next_account_info(account_info_iter)?;
+    let system_program_acc_info = next_account_info(account_info_iter)?;
+    let program_config_acc_info = next_account_info(account_info_iter)?;
+    let protocol_treasury_acc_info = next_account_info(account_info_iter)?;
+    let land_asset_by_mint_acc_info = next_account_info(account_info_iter)?;
+    let rent_sysvar_acc_info = next_account_info(account_info_iter)?;
+    // accounts only read for some land planes, which may be omitted otherwise
+    let slot_hashes_sysvar_acc_info = account_info_iter.next();
+    let nft_metadata_acc_info = account_info_iter.next();
+    let clock_sysvar_acc_info = account_info_iter.next();
+    let wallet_mint_record_acc_info = account_info_iter.next();
+    let price_oracle_acc_info = account_info_iter.next();
 
-    // confirm that given nft associated token acc owner is a signatory
-    // on the transaction
-    if !nft_assoc_token_acc_owner_acc_info.is_signer {
-        return Err(LandError::SignatureError.into());
+    // confirm that the payer is a signatory on the transaction
+    if !payer_acc_info.is_signer {
+        return Err(LandError::MissingSignature.into());
     }
 
     // parse land plane account state and confirm
     // that the given account has been initialised
-    let land_plane_acc_state = LandPlane::from_account_info(land_plane_acc_info)?;
+    let mut land_plane_acc_state = LandPlane::from_account_info(land_plane_acc_info)?;
     if land_plane_acc_state.version == LandPlaneVersion::Uninitialised {
         return Err(LandError::LandPlaneAccUninitialised.into());
     }
 
-    // derive expected PDA for next piece of land
-    let (next_land_asset_acc_key, _) = Pubkey::find_program_address(
-        &[
-            LAND_ASSET_ACC_PREFIX.as_bytes(),
-            land_plane_acc_info.key.as_ref(),
-            &land_plane_acc_state.next_x.to_le_bytes(),
-            &land_plane_acc_state.next_z.to_le_bytes(),
-        ],
-        program_id,
-    );
+    // confirm that the land plane is part way through the minting pattern
+    land_plane_acc_state.validate_invariants()?;
 
-    // confirm correct land_asset_acc was provided
-    if land_asset_acc_info.key != &next_land_asset_acc_key {
-        return Err(LandError::InvalidLandAssetAccKey.into());
+    // and, if it has a max supply, that the supply has not been reached
+    if land_plane_acc_state.max_supply_reached() {
+        return Err(LandError::MaxSupplyReached.into());
     }
 
-    // parse land asset account state and confirm
-    // that the given account has been initialised
-    let land_asset_acc_state = LandAsset::from_account_info(land_asset_acc_info)?;
-    if land_asset_acc_state.version == LandAssetVersion::Uninitialised {
-        return Err(LandError::LandAssetAccUninitialised.into());
+    // and, if it has a mint window, that the window is open
+    if land_plane_acc_state.mint_start_slot.is_some() || land_plane_acc_state.mint_end_slot.is_some() {
+        let clock_sysvar_acc_info =
+            clock_sysvar_acc_info.ok_or(ProgramError::NotEnoughAccountKeys)?;
+        land_plane_acc_state.check_mint_window(Clock::from_account_info(clock_sysvar_acc_info)?.slot)?;
     }
 
-    Ok(())
-}
+    // and, if it has a mint cooldown, that the recipient has not been
+    // minted land from it within the cooldown, as shown by their wallet
+    // mint record, which is kept to be updated once the mint is done
+    let wallet_mint = if land_plane_acc_state.mint_cooldown_slots > 0 {
+        let clock_sysvar_acc_info =
+            clock_sysvar_acc_info.ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let wallet_mint_record_acc_info =
+            wallet_mint_record_acc_info.ok_or(ProgramError::NotEnoughAccountKeys)?;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate :: {
-        instruction::{
-            initialize_land_plane,
-            mint_next_land_asset,
-        },
-    };
-    use solana_program::{
-        system_program,
-        program_error::{PrintProgramError, ProgramError},
-        instruction::Instruction,
+        // confirm correct wallet_mint_record_acc was provided
+        let (wallet_mint_record_acc_key, wallet_mint_record_acc_bump) = Pubkey::find_program_address(
+            &[
+                WALLET_MINT_RECORD_ACC_PREFIX.as_bytes(),
+                land_plane_acc_info.key.as_ref(),
+                nft_assoc_token_acc_owner_acc_info.key.as_ref(),
+            ],
+            program_id,
+        );
+        if wallet_mint_record_acc_info.key != &wallet_mint_record_acc_key {
+            return Err(LandError::InvalidWalletMintRecordAccKey.into());
+        }
+
+        // a wallet without a record has never been minted land
+        let current_slot = Clock::from_account_info(clock_sysvar_acc_info)?.slot;
+        if !wallet_mint_record_acc_info.data_is_empty() {
+            let wallet_mint_record = WalletMintRecord::from_account_info(wallet_mint_record_acc_info)?;
+            if wallet_mint_record.version != WalletMintRecordVersion::Uninitialised
+                && land_plane_acc_state.mint_cooldown_active(wallet_mint_record.last_mint_slot, current_slot)
+            {
+                return Err(LandError::MintCooldownActive.into());
+            }
+        }
+        Some((wallet_mint_record_acc_info, wallet_mint_record_acc_bump, current_slot))
+    } else {
+        None
     };
-    use solana_sdk::account::{
-        create_account_for_test, create_is_signer_account_infos, Account as SolanaAccount,
+
+    // determine the co-ordinate of the piece of land being minted, which
+    // is the next in the minting pattern unless the land plane is filled
+    // at random, in which case it is that of the given land asset account
+    // among the probes from the candidate picked by the random fill seed
+    // and the most recent slot hash, which is kept to roll the seed on
+    // once minted.
+    let (minted, recent_slot_hash) = match land_plane_acc_state.mint_pattern {
+        MintPattern::Shell => (
+            Coordinate {
+                x: land_plane_acc_state.next_x,
+                z: land_plane_acc_state.next_z,
+            },
+            None,
+        ),
+        MintPattern::RandomFill => {
+            let slot_hashes_sysvar_acc_info =
+                slot_hashes_sysvar_acc_info.ok_or(ProgramError::NotEnoughAccountKeys)?;
+            let recent_slot_hash = recent_slot_hash(slot_hashes_sysvar_acc_info)?;
+            let minted = random_fill_target(
+                program_id,
+                land_plane_acc_info.key,
+                &land_plane_acc_state,
+                land_asset_acc_info.key,
+                &recent_slot_hash,
+                accounts,
+            )?;
+            (minted, Some(recent_slot_hash))
+        }
     };
 
-    ///
-    /// testing utils
-    /// 
+    // confirm correct land_asset_acc was provided, i.e. that of the land
+    // plane's current target unless the land plane is filled at random,
+    // in which case it was found among the probes above.
+    // The bump kept by land asset accounts since V6 saves searching for
+    // the address, which is the costliest part of the check.
+    let valid_land_asset_acc = match land_plane_acc_state.mint_pattern {
+        MintPattern::Shell => match land_asset_acc_bump(program_id, land_asset_acc_info) {
+            Some(bump) => land_plane_acc_state.is_current_target_with_bump(
+                program_id,
+                land_plane_acc_info.key,
+                land_asset_acc_info.key,
+                bump,
+            ),
+            None => land_plane_acc_state.is_current_target(
+                program_id,
+                land_plane_acc_info.key,
+                land_asset_acc_info.key,
+            ),
+        },
+        MintPattern::RandomFill => true,
+    };
+    if !valid_land_asset_acc {
+        // the land asset account of land that the land plane has already
+        // passed means that another mint got there first, most likely one
+        // racing for the same target, rather than a wrong account
+        if land_plane_acc_state.mint_pattern == MintPattern::Shell
+            && is_claimed_land_asset_acc(program_id, land_plane_acc_info.key, &land_plane_acc_state, land_asset_acc_info)
+        {
+            return Err(LandError::ParcelAlreadyClaimed.into());
+        }
+        return Err(LandError::InvalidLandAssetAccKey.into());
+    }
 
-    fn return_land_error_as_program_error() -> ProgramError {
-        LandError::IncorrectDataSize.into()
+    // parse land asset account state and confirm that the given account
+    // has been initialised. Land filled at random cannot be initialised
+    // ahead of the mint, so its account is instead created if need be.
+    let create_land_asset_acc = land_plane_acc_state.mint_pattern == MintPattern::RandomFill
+        && land_asset_acc_info.data_is_empty();
+    let mut land_asset_acc_state = if create_land_asset_acc {
+        LandAsset::unpack_versioned(&[0; LAND_ASSET_ACC_DATA_LEN])?
+    } else {
+        LandAsset::from_account_info(land_asset_acc_info)?
+    };
+    if land_asset_acc_state.version == LandAssetVersion::Uninitialised
+        && land_plane_acc_state.mint_pattern == MintPattern::Shell
+    {
+        return Err(LandError::LandAssetAccUninitialised.into());
     }
 
-    fn rent_sysvar() -> SolanaAccount {
-        create_account_for_test(&Rent::default())
+    // and that the land has not already been minted
+    if land_asset_acc_state.is_minted() {
+        return Err(LandError::LandAssetAlreadyMinted.into());
     }
 
-    fn do_process_instruction(
-        instruction: Instruction,
-        accounts: Vec<&mut SolanaAccount>,
-    ) -> ProgramResult {
-        let mut meta = instruction
-            .accounts
-            .iter()
-            .zip(accounts)
-            .map(|(account_meta, account)| (&account_meta.pubkey, account_meta.is_signer, account))
-            .collect::<Vec<_>>();
+    // and that it was initialised for the given land plane, if
+    // the land asset records one
+    if !is_unset_pubkey(&land_asset_acc_state.land_plane)
+        && &land_asset_acc_state.land_plane != land_plane_acc_info.key
+    {
+        return Err(LandError::PlaneMismatch.into());
+    }
 
-        let account_infos = create_is_signer_account_infos(&mut meta);
-        process_instruction(&instruction.program_id, &account_infos, &instruction.data)
-    }  
-    
-    fn land_plane_minimum_balance() -> u64 {
-        Rent::default().minimum_balance(LAND_PLANE_ACC_DATA_LEN)
-    }    
+    // parse the nft holding token account and confirm that it
+    // has not been frozen, so that the holder is able to move the nft
+    if nft_assoc_token_acc_info.owner != &spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let nft_assoc_token_acc = Account::unpack(&nft_assoc_token_acc_info.data.borrow())?;
+    if nft_assoc_token_acc.state == AccountState::Frozen {
+        return Err(LandError::TokenAccountFrozen.into());
+    }
 
-    ///
-    /// tests
-    /// 
+    // confirm that the token account belongs to the recipient
+    // and that it holds the given nft
+    if &nft_assoc_token_acc.owner != nft_assoc_token_acc_owner_acc_info.key {
+        return Err(LandError::NftTokenAccOwnerMismatch.into());
+    }
+    if &nft_assoc_token_acc.mint != nft_mint_acc_info.key {
+        return Err(LandError::NftTokenAccMintMismatch.into());
+    }
+    if nft_assoc_token_acc.amount != 1 {
+        return Err(LandError::NftNotHeld.into());
+    }
 
-    #[test]
-    fn test_print_error() {
-        let error = return_land_error_as_program_error();
-        error.print::<LandError>();
-    }    
+    // if required by the land plane, confirm that the supply of the
+    // given NFT can no longer be inflated
+    if land_plane_acc_state.require_frozen_mint {
+        if nft_mint_acc_info.owner != &spl_token::id() {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        let nft_mint = Mint::unpack(&nft_mint_acc_info.data.borrow())?;
+        if nft_mint.mint_authority.is_some() {
+            return Err(LandError::MintAuthorityNotDisabled.into());
+        }
+    }
 
-    #[test]
-    fn test_initialise_land_plane_account() {
+    // if required by the land plane, confirm that the metadata of the
+    // given NFT shows it in the verified collection
+    if let Some(required_collection) = land_plane_acc_state.required_collection {
+        let nft_metadata_acc_info = nft_metadata_acc_info.ok_or(ProgramError::NotEnoughAccountKeys)?;
+        if nft_metadata_acc_info.key != &metadata_acc_pubkey(nft_mint_acc_info.key) {
+            return Err(LandError::InvalidNftMetadataAccKey.into());
+        }
+        // an NFT minted without metadata has no account at its metadata
+        // address, which is reported as such rather than as a bad owner
+        if nft_metadata_acc_info.data_is_empty() {
+            return Err(LandError::MissingMetadataAccount.into());
+        }
+        if nft_metadata_acc_info.owner != &token_metadata_program::id() {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        let nft_metadata = Metadata::from_account_info(nft_metadata_acc_info)?;
+        if !nft_metadata.in_verified_collection(&required_collection) {
+            return Err(LandError::WrongCollection.into());
+        }
+    }
+
+    // confirm correct land_plane_stats_acc was provided
+    let (land_plane_stats_acc_key, _) = Pubkey::find_program_address(
+        &[
+            LAND_PLANE_STATS_ACC_PREFIX.as_bytes(),
+            land_plane_acc_info.key.as_ref(),
+        ],
+        program_id,
+    );
+    if land_plane_stats_acc_info.key != &land_plane_stats_acc_key {
+        return Err(LandError::InvalidLandPlaneStatsAccKey.into());
+    }
+
+    // parse land plane stats account state and confirm
+    // that the given account has been initialised
+    let mut land_plane_stats_acc_state = LandPlaneStats::from_account_info(land_plane_stats_acc_info)?;
+    if land_plane_stats_acc_state.version == LandPlaneStatsVersion::Uninitialised {
+        return Err(LandError::LandPlaneStatsAccUninitialised.into());
+    }
+
+    // confirm correct land_asset_by_mint_acc was provided
+    if land_asset_by_mint_acc_info.key != &land_asset_by_mint_acc_pubkey(program_id, nft_mint_acc_info.key) {
+        return Err(LandError::InvalidLandAssetByMintAccKey.into());
+    }
+
+    // if required by the land plane, confirm that the nft
+    // is not already bound to other land
+    check_one_parcel_per_nft(&land_plane_acc_state, land_asset_by_mint_acc_info, land_asset_acc_info.key)?;
+
+    // move the land plane on to the next piece of land, and for land
+    // filled at random roll the random fill seed on, so that the next
+    // candidate is fixed by this slot, where the land plane account is
+    // long enough to hold the seed
+    land_plane_acc_state.increment_mint()?;
+    let roll_random_fill_seed = match recent_slot_hash {
+        Some(recent_slot_hash) if LandPlaneVersion::V17.fits(land_plane_acc_info.data_len()) => {
+            land_plane_acc_state.roll_random_fill_seed(&recent_slot_hash);
+            true
+        }
+        _ => false,
+    };
+
+    // link the new piece of land to the nft, recording who held
+    // the nft at the time of the mint and where the land lies, at
+    // the latest version the land asset account is long enough for
+    land_asset_acc_state.version = LandAssetVersion::latest_for_data_len(land_asset_acc_info.data_len())
+        .ok_or(LandError::IncorrectDataSize)?;
+    land_asset_acc_state.mint_pubkey = *nft_mint_acc_info.key;
+    land_asset_acc_state.minter = *nft_assoc_token_acc_owner_acc_info.key;
+    land_asset_acc_state.land_plane = *land_plane_acc_info.key;
+    land_asset_acc_state.coordinate = minted;
+
+    // confirm correct program_config_acc was provided
+    let (program_config_acc_key, _) = Pubkey::find_program_address(
+        &[PROGRAM_CONFIG_ACC_PREFIX.as_bytes()],
+        program_id,
+    );
+    if program_config_acc_info.key != &program_config_acc_key {
+        return Err(LandError::InvalidProgramConfigAccKey.into());
+    }
+
+    // parse program config account state and confirm
+    // that the given account has been initialised
+    let mut program_config_acc_state = ProgramConfig::from_account_info(program_config_acc_info)?;
+    if program_config_acc_state.version == ProgramConfigVersion::Uninitialised {
+        return Err(LandError::ProgramConfigAccUninitialised.into());
+    }
+
+    // and that it was written at a version compatible with the land plane
+    require_compatible_versions(&land_plane_acc_state.version, &program_config_acc_state.version)?;
+
+    // confirm the protocol fee is paid to the configured treasury
+    if protocol_treasury_acc_info.key != &program_config_acc_state.protocol_treasury {
+        return Err(LandError::InvalidProtocolTreasuryAcc.into());
+    }
+
+    // the land plane's mint fee is its own, unless it has a price
+    // oracle, in which case it is the price held by that price feed
+    let mint_fee = match land_plane_acc_state.price_oracle {
+        Some(price_oracle) => {
+            let price_oracle_acc_info =
+                price_oracle_acc_info.ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+            // confirm correct price_oracle_acc was provided
+            if price_oracle_acc_info.key != &price_oracle {
+                return Err(LandError::InvalidPriceOracleAccKey.into());
+            }
+
+            // parse price feed account state and confirm
+            // that the given account has been initialised
+            let price_feed_acc_state = PriceFeed::from_account_info(price_oracle_acc_info)?;
+            if price_feed_acc_state.version == PriceFeedVersion::Uninitialised {
+                return Err(LandError::PriceFeedAccUninitialised.into());
+            }
+            price_feed_acc_state.price
+        }
+        None => land_plane_acc_state.mint_fee,
+    };
+
+    // confirm the payer can cover both the land plane's mint fee and
+    // the protocol fee so that an under-funded payer gets a clear error
+    // rather than a failed transfer
+    let protocol_fee = program_config_acc_state.protocol_fee;
+    if payer_acc_info.lamports() < checked_add_err(mint_fee, protocol_fee)? {
+        return Err(LandError::InsufficientFeeFunds.into());
+    }
+
+    // charge the land plane's mint fee, if any, to the payer, paying it
+    // to the land plane's fee recipient, whose account is found by key
+    if mint_fee > 0 {
+        let fee_recipient = land_plane_acc_state.fee_recipient().ok_or(LandError::FeeRecipientUnset)?;
+        let fee_recipient_acc_info = accounts
+            .iter()
+            .find(|acc_info| *acc_info.key == fee_recipient)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        invoke(
+            &system_instruction::transfer(payer_acc_info.key, fee_recipient_acc_info.key, mint_fee),
+            &[
+                payer_acc_info.clone(),
+                fee_recipient_acc_info.clone(),
+                system_program_acc_info.clone(),
+            ],
+        )?;
+    }
+
+    // and the protocol fee, if any
+    if protocol_fee > 0 {
+        invoke(
+            &system_instruction::transfer(payer_acc_info.key, protocol_treasury_acc_info.key, protocol_fee),
+            &[
+                payer_acc_info.clone(),
+                protocol_treasury_acc_info.clone(),
+                system_program_acc_info.clone(),
+            ],
+        )?;
+    }
+
+    // record the mint
+    land_plane_stats_acc_state.record_mint(nft_assoc_token_acc_owner_acc_info.key, mint_fee);
+
+    // create the land asset account if need be
+    if create_land_asset_acc {
+        let (_, land_asset_acc_bump) =
+            land_plane_acc_state.land_asset_address(program_id, land_plane_acc_info.key, minted);
+        land_asset_acc_state.bump = land_asset_acc_bump;
+        create_or_allocate_account_raw(
+            *program_id,
+            land_asset_acc_info,
+            rent_sysvar_acc_info,
+            system_program_acc_info,
+            payer_acc_info,
+            LAND_ASSET_ACC_DATA_LEN,
+            &[
+                LAND_ASSET_ACC_PREFIX.as_bytes(),
+                land_plane_acc_info.key.as_ref(),
+                &coordinate_seed(minted.x, land_plane_acc_state.addr_width),
+                &coordinate_seed(minted.z, land_plane_acc_state.addr_width),
+                &[land_asset_acc_bump],
+            ],
+        )?;
+    }
+
+    // then serialize the updated account states, writing only the
+    // cursor of the land plane unless its random fill seed was rolled,
+    // since the mint changes nothing else
+    land_asset_acc_state.pack_versioned(&mut land_asset_acc_info.data.borrow_mut())?;
+    if roll_random_fill_seed {
+        land_plane_acc_state.pack_versioned(&mut land_plane_acc_info.data.borrow_mut())?;
+    } else {
+        land_plane_acc_state.write_cursor(&mut land_plane_acc_info.data.borrow_mut())?;
+    }
+    land_plane_stats_acc_state.serialize(&mut *land_plane_stats_acc_info.data.borrow_mut())?;
+
+    // count the mint across the program
+    program_config_acc_state.record_mint();
+    program_config_acc_state.pack_versioned(&mut program_config_acc_info.data.borrow_mut())?;
+
+    // and index the new piece of land by the nft mint
+    index_land_asset_by_mint(
+        program_id,
+        land_asset_by_mint_acc_info,
+        nft_mint_acc_info.key,
+        &LandAssetByMint {
+            version: LandAssetByMintVersion::V1,
+            land_plane: *land_plane_acc_info.key,
+            land_asset: *land_asset_acc_info.key,
+            coordinate: minted,
+        },
+        rent_sysvar_acc_info,
+        system_program_acc_info,
+        payer_acc_info,
+    )?;
+
+    // and record the mint against the recipient for the mint cooldown
+    if let Some((wallet_mint_record_acc_info, wallet_mint_record_acc_bump, current_slot)) = wallet_mint {
+        record_wallet_mint(
+            program_id,
+            wallet_mint_record_acc_info,
+            land_plane_acc_info.key,
+            nft_assoc_token_acc_owner_acc_info.key,
+            wallet_mint_record_acc_bump,
+            current_slot,
+            rent_sysvar_acc_info,
+            system_program_acc_info,
+            payer_acc_info,
+        )?;
+    }
+
+    // log the minted co-ordinate so that clients can report it, and
+    // commit to the new land plane state so that they can verify it
+    log_minted(minted, land_plane_acc_state.state_hash());
+
+    Ok(())
+}
+
+/// Log_minted logs the co-ordinate of newly minted land and the state
+/// hash of its land plane following the mint, as lines of text.
+#[cfg(not(feature = "compact-logs"))]
+fn log_minted(minted: Coordinate, state_hash: [u8; 32]) {
+    msg!("{}x={}, z={}", MINTED_LAND_ASSET_LOG_PREFIX, minted.x, minted.z);
+    msg!(
+        "{}{}",
+        LAND_PLANE_STATE_HASH_LOG_PREFIX,
+        solana_program::keccak::Hash::new_from_array(state_hash),
+    );
+}
+
+/// Log_minted logs the co-ordinate of newly minted land and the state
+/// hash of its land plane following the mint, as compact events, which
+/// cost far less compute than formatting the base-58 state hash.
+#[cfg(feature = "compact-logs")]
+fn log_minted(minted: Coordinate, state_hash: [u8; 32]) {
+    let word = |i: usize| u64::from_le_bytes(state_hash[i * 8..(i + 1) * 8].try_into().unwrap());
+    solana_program::log::sol_log_64(MINTED_LAND_ASSET_EVENT_TAG, minted.x, minted.z, 0, 0);
+    solana_program::log::sol_log_64(LAND_PLANE_STATE_HASH_EVENT_TAG, word(0), word(1), word(2), word(3));
+}
+
+/// Initialise a new Land Plane Stats account
+pub fn process_initialise_land_plane_stats(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    // prepare an account info iterator and get a handle
+    // on required accounts
+    let account_info_iter = &mut accounts.iter();
+    let rent_payer_acc_info = next_account_info(account_info_iter)?;
+    let land_plane_stats_acc_info = next_account_info(account_info_iter)?;
+    let land_plane_acc_info = next_account_info(account_info_iter)?;
+    let rent_sysvar_acc_info = next_account_info(account_info_iter)?;
+    let system_program_acc_info = next_account_info(account_info_iter)?;
+
+    // confirm that the rent payer is a signatory on the transaction
+    if !rent_payer_acc_info.is_signer {
+        return Err(LandError::MissingSignature.into());
+    }
+
+    // parse land plane account state and confirm
+    // that the given account has been initialised
+    let land_plane_acc_state = LandPlane::from_account_info(land_plane_acc_info)?;
+    if land_plane_acc_state.version == LandPlaneVersion::Uninitialised {
+        return Err(LandError::LandPlaneAccUninitialised.into());
+    }
+
+    // derive expected PDA for the land plane stats account
+    // and confirm correct land_plane_stats_acc was provided
+    let (land_plane_stats_acc_key, land_plane_stats_acc_bump) = Pubkey::find_program_address(
+        &[
+            LAND_PLANE_STATS_ACC_PREFIX.as_bytes(),
+            land_plane_acc_info.key.as_ref(),
+        ],
+        program_id,
+    );
+    if land_plane_stats_acc_info.key != &land_plane_stats_acc_key {
+        return Err(LandError::InvalidLandPlaneStatsAccKey.into());
+    }
+
+    // confirm that the account has not already been initialised
+    if !land_plane_stats_acc_info.data_is_empty() {
+        let land_plane_stats_acc_state = LandPlaneStats::from_account_info(land_plane_stats_acc_info)?;
+        if land_plane_stats_acc_state.version != LandPlaneStatsVersion::Uninitialised {
+            return Err(LandError::LandPlaneStatsAccAlreadyInitialised.into());
+        }
+    }
+
+    // create the land plane stats account
+    create_or_allocate_account_raw(
+        *program_id,
+        land_plane_stats_acc_info,
+        rent_sysvar_acc_info,
+        system_program_acc_info,
+        rent_payer_acc_info,
+        LAND_PLANE_STATS_ACC_DATA_LEN,
+        &[
+            LAND_PLANE_STATS_ACC_PREFIX.as_bytes(),
+            land_plane_acc_info.key.as_ref(),
+            &[land_plane_stats_acc_bump],
+        ],
+    )?;
+
+    // initialise values
+    let mut land_plane_stats_acc_state = LandPlaneStats::from_account_info(land_plane_stats_acc_info)?;
+    land_plane_stats_acc_state.version = LandPlaneStatsVersion::V1;
+    land_plane_stats_acc_state.mints = 0;
+    land_plane_stats_acc_state.fees_collected = 0;
+    land_plane_stats_acc_state.unique_wallets = 0;
+
+    // then serialize the land plane stats account state
+    land_plane_stats_acc_state.serialize(&mut *land_plane_stats_acc_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+/// Create and initialise a new Land Plane along with its Land Plane Stats
+#[allow(clippy::too_many_arguments)]
+pub fn process_setup_plane(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    coordinate_ceiling: Option<u64>,
+    require_frozen_mint: bool,
+    mint_fee: u64,
+    owner: Option<Pubkey>,
+    mint_pattern: MintPattern,
+    one_parcel_per_nft: bool,
+    royalty_bps: u16,
+    compact_addresses: bool,
+    required_collection: Option<Pubkey>,
+    max_supply: Option<u64>,
+    mint_cooldown_slots: u64,
+    price_oracle: Option<Pubkey>,
+    reserve_claimant: Option<Pubkey>,
+    closes_at_slot: Option<u64>,
+) -> ProgramResult {
+    // prepare an account info iterator and get a handle
+    // on required accounts
+    let account_info_iter = &mut accounts.iter();
+    let payer_acc_info = next_account_info(account_info_iter)?;
+    let land_plane_acc_info = next_account_info(account_info_iter)?;
+    let land_plane_stats_acc_info = next_account_info(account_info_iter)?;
+    let rent_sysvar_acc_info = next_account_info(account_info_iter)?;
+    let system_program_acc_info = next_account_info(account_info_iter)?;
+    let program_config_acc_info = next_account_info(account_info_iter)?;
+
+    // confirm that the payer and the new land plane
+    // account are signatories on the transaction
+    if !payer_acc_info.is_signer || !land_plane_acc_info.is_signer {
+        return Err(LandError::MissingSignature.into());
+    }
+
+    // confirm that the genuine system program was provided, since
+    // it is invoked to create the land plane and stats accounts
+    if system_program_acc_info.key != &system_program::id() {
+        return Err(LandError::InvalidSystemProgram.into());
+    }
+
+    // confirm that the land plane account is not already initialised
+    if !land_plane_acc_info.data_is_empty() {
+        let land_plane_acc_state = LandPlane::from_account_info(land_plane_acc_info)?;
+        if land_plane_acc_state.version != LandPlaneVersion::Uninitialised {
+            return Err(LandError::LandPlaneAccAlreadyInitialised.into());
+        }
+    }
+
+    // confirm the config is valid before creating any account
+    if mint_pattern == MintPattern::RandomFill && coordinate_ceiling.is_none() {
+        return Err(LandError::RandomFillRequiresCeiling.into());
+    }
+    addr_width(coordinate_ceiling, compact_addresses)?;
+
+    // create the land plane account, which signs for itself
+    create_or_allocate_account_raw(
+        *program_id,
+        land_plane_acc_info,
+        rent_sysvar_acc_info,
+        system_program_acc_info,
+        payer_acc_info,
+        LAND_PLANE_ACC_DATA_LEN,
+        &[],
+    )?;
+
+    // initialise it as usual
+    process_initialise_land_plane(
+        program_id,
+        &[
+            land_plane_acc_info.clone(),
+            rent_sysvar_acc_info.clone(),
+            program_config_acc_info.clone(),
+        ],
+        coordinate_ceiling,
+        require_frozen_mint,
+        mint_fee,
+        owner,
+        mint_pattern,
+        one_parcel_per_nft,
+        royalty_bps,
+        compact_addresses,
+        required_collection,
+        max_supply,
+        mint_cooldown_slots,
+        price_oracle,
+        reserve_claimant,
+        closes_at_slot,
+    )?;
+
+    // then create and initialise its stats account
+    process_initialise_land_plane_stats(
+        program_id,
+        &[
+            payer_acc_info.clone(),
+            land_plane_stats_acc_info.clone(),
+            land_plane_acc_info.clone(),
+            rent_sysvar_acc_info.clone(),
+            system_program_acc_info.clone(),
+        ],
+    )
+}
+
+/// Stamp the co-ordinate of a minted piece of land into the
+/// metadata URI of the NFT to which it is bound
+pub fn process_stamp_coordinate_uri(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    coordinate: Coordinate,
+) -> ProgramResult {
+    // prepare an account info iterator and get a handle
+    // on required accounts
+    let account_info_iter = &mut accounts.iter();
+    let update_authority_acc_info = next_account_info(account_info_iter)?;
+    let land_asset_acc_info = next_account_info(account_info_iter)?;
+    let land_plane_acc_info = next_account_info(account_info_iter)?;
+    let nft_metadata_acc_info = next_account_info(account_info_iter)?;
+    let token_metadata_program_acc_info = next_account_info(account_info_iter)?;
+
+    // confirm that the update authority is a signatory on the transaction
+    if !update_authority_acc_info.is_signer {
+        return Err(LandError::MissingSignature.into());
+    }
+
+    // confirm the correct token metadata program was provided
+    if token_metadata_program_acc_info.key != &token_metadata_program::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // parse land plane account state and confirm
+    // that the given account has been initialised
+    let land_plane_acc_state = LandPlane::from_account_info(land_plane_acc_info)?;
+    if land_plane_acc_state.version == LandPlaneVersion::Uninitialised {
+        return Err(LandError::LandPlaneAccUninitialised.into());
+    }
+
+    // derive expected PDA for the piece of land at the given
+    // co-ordinate and confirm correct land_asset_acc was provided
+    let (land_asset_acc_key, _) =
+        land_plane_acc_state.land_asset_address(program_id, land_plane_acc_info.key, coordinate);
+    if land_asset_acc_info.key != &land_asset_acc_key {
+        return Err(LandError::InvalidLandAssetAccKey.into());
+    }
+
+    // parse land asset account state and confirm
+    // that the land has been minted
+    let land_asset_acc_state = LandAsset::from_account_info(land_asset_acc_info)?;
+    if land_asset_acc_state.version == LandAssetVersion::Uninitialised {
+        return Err(LandError::LandAssetAccUninitialised.into());
+    }
+    if !land_asset_acc_state.is_minted() {
+        return Err(LandError::LandAssetNotMinted.into());
+    }
+
+    // confirm that the metadata account belongs to the nft
+    // to which the land is bound
+    if nft_metadata_acc_info.owner != &token_metadata_program::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if nft_metadata_acc_info.key != &metadata_acc_pubkey(&land_asset_acc_state.mint_pubkey) {
+        return Err(LandError::InvalidNftMetadataAccKey.into());
+    }
+
+    // parse the metadata and confirm that the signer is its update authority
+    let nft_metadata = Metadata::from_account_info(nft_metadata_acc_info)?;
+    if &nft_metadata.update_authority != update_authority_acc_info.key {
+        return Err(LandError::UpdateAuthorityMismatch.into());
+    }
+
+    // then update the metadata with the stamped uri
+    let mut data = nft_metadata.data;
+    data.uri = coordinate_uri(&data.uri, coordinate)?;
+    invoke(
+        &update_metadata_account(
+            nft_metadata_acc_info.key,
+            update_authority_acc_info.key,
+            data,
+        ),
+        &[
+            nft_metadata_acc_info.clone(),
+            update_authority_acc_info.clone(),
+            token_metadata_program_acc_info.clone(),
+        ],
+    )?;
+
+    Ok(())
+}
+
+/// Initialise the Program Config account
+pub fn process_initialise_program_config(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    protocol_fee: u64,
+    protocol_treasury: Pubkey,
+) -> ProgramResult {
+    // prepare an account info iterator and get a handle
+    // on required accounts
+    let account_info_iter = &mut accounts.iter();
+    let upgrade_authority_acc_info = next_account_info(account_info_iter)?;
+    let program_config_acc_info = next_account_info(account_info_iter)?;
+    let program_data_acc_info = next_account_info(account_info_iter)?;
+    let rent_sysvar_acc_info = next_account_info(account_info_iter)?;
+    let system_program_acc_info = next_account_info(account_info_iter)?;
+
+    // confirm that the signer is the program's upgrade authority
+    check_upgrade_authority(program_id, upgrade_authority_acc_info, program_data_acc_info)?;
+
+    // derive expected PDA for the program config account
+    // and confirm correct program_config_acc was provided
+    let (program_config_acc_key, program_config_acc_bump) = Pubkey::find_program_address(
+        &[PROGRAM_CONFIG_ACC_PREFIX.as_bytes()],
+        program_id,
+    );
+    if program_config_acc_info.key != &program_config_acc_key {
+        return Err(LandError::InvalidProgramConfigAccKey.into());
+    }
+
+    // confirm that the account has not already been initialised
+    if !program_config_acc_info.data_is_empty() {
+        let program_config_acc_state = ProgramConfig::from_account_info(program_config_acc_info)?;
+        if program_config_acc_state.version != ProgramConfigVersion::Uninitialised {
+            return Err(LandError::ProgramConfigAccAlreadyInitialised.into());
+        }
+    }
+
+    // create the program config account
+    create_or_allocate_account_raw(
+        *program_id,
+        program_config_acc_info,
+        rent_sysvar_acc_info,
+        system_program_acc_info,
+        upgrade_authority_acc_info,
+        PROGRAM_CONFIG_ACC_DATA_LEN,
+        &[
+            PROGRAM_CONFIG_ACC_PREFIX.as_bytes(),
+            &[program_config_acc_bump],
+        ],
+    )?;
+
+    // initialise values
+    let mut program_config_acc_state = ProgramConfig::from_account_info(program_config_acc_info)?;
+    program_config_acc_state.version = ProgramConfigVersion::V2;
+    program_config_acc_state.protocol_fee = protocol_fee;
+    program_config_acc_state.protocol_treasury = protocol_treasury;
+    program_config_acc_state.total_planes = 0;
+    program_config_acc_state.total_mints = 0;
+
+    // then serialize the program config account state
+    program_config_acc_state.pack_versioned(&mut program_config_acc_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+/// Transfer a minted piece of land to another NFT
+pub fn process_transfer_land(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    coordinate: Coordinate,
+    sale_price: u64,
+) -> ProgramResult {
+    // prepare an account info iterator and get a handle
+    // on required accounts
+    let account_info_iter = &mut accounts.iter();
+    let payer_acc_info = next_account_info(account_info_iter)?;
+    let nft_assoc_token_acc_owner_acc_info = next_account_info(account_info_iter)?;
+    let land_asset_acc_info = next_account_info(account_info_iter)?;
+    let land_plane_acc_info = next_account_info(account_info_iter)?;
+    let nft_assoc_token_acc_info = next_account_info(account_info_iter)?;
+    let land_asset_by_mint_acc_info = next_account_info(account_info_iter)?;
+    let new_nft_mint_acc_info = next_account_info(account_info_iter)?;
+    let new_land_asset_by_mint_acc_info = next_account_info(account_info_iter)?;
+    let rent_sysvar_acc_info = next_account_info(account_info_iter)?;
+    let system_program_acc_info = next_account_info(account_info_iter)?;
+
+    // confirm that the payer and the holder of the nft
+    // are signatories on the transaction
+    if !payer_acc_info.is_signer || !nft_assoc_token_acc_owner_acc_info.is_signer {
+        return Err(LandError::MissingSignature.into());
+    }
+
+    // parse land plane account state and confirm
+    // that the given account has been initialised
+    let land_plane_acc_state = LandPlane::from_account_info(land_plane_acc_info)?;
+    if land_plane_acc_state.version == LandPlaneVersion::Uninitialised {
+        return Err(LandError::LandPlaneAccUninitialised.into());
+    }
+
+    // derive expected PDA for the piece of land at the given
+    // co-ordinate and confirm correct land_asset_acc was provided
+    let (land_asset_acc_key, _) =
+        land_plane_acc_state.land_asset_address(program_id, land_plane_acc_info.key, coordinate);
+    if land_asset_acc_info.key != &land_asset_acc_key {
+        return Err(LandError::InvalidLandAssetAccKey.into());
+    }
+
+    // parse land asset account state and confirm
+    // that the land has been minted
+    let mut land_asset_acc_state = LandAsset::from_account_info(land_asset_acc_info)?;
+    if land_asset_acc_state.version == LandAssetVersion::Uninitialised {
+        return Err(LandError::LandAssetAccUninitialised.into());
+    }
+    if !land_asset_acc_state.is_minted() {
+        return Err(LandError::LandAssetNotMinted.into());
+    }
+
+    // confirm that the land was minted from the given land plane,
+    // if the land asset records one
+    if !is_unset_pubkey(&land_asset_acc_state.land_plane)
+        && &land_asset_acc_state.land_plane != land_plane_acc_info.key
+    {
+        return Err(LandError::PlaneMismatch.into());
+    }
+
+    // confirm that the land has not been locked, e.g. for staking
+    if land_asset_acc_state.is_locked() {
+        return Err(LandError::ParcelLocked.into());
+    }
+
+    // nor merged into an estate, whose parcels move only as one
+    if land_asset_acc_state.is_merged() {
+        return Err(LandError::ParcelAlreadyMerged.into());
+    }
+
+    // confirm that the signer holds the nft to which the land is bound
+    check_nft_held(
+        nft_assoc_token_acc_info,
+        nft_assoc_token_acc_owner_acc_info.key,
+        &land_asset_acc_state.mint_pubkey,
+    )?;
+
+    // confirm correct land_asset_by_mint_acc was provided
+    // for the nft to which the land is bound
+    if land_asset_by_mint_acc_info.key != &land_asset_by_mint_acc_pubkey(program_id, &land_asset_acc_state.mint_pubkey) {
+        return Err(LandError::InvalidLandAssetByMintAccKey.into());
+    }
+
+    // confirm that the land is being bound to the mint of an spl nft,
+    // i.e. one of a single token with no decimals
+    if new_nft_mint_acc_info.owner != &spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let new_nft_mint = Mint::unpack(&new_nft_mint_acc_info.data.borrow())?;
+    if new_nft_mint.supply != 1 || new_nft_mint.decimals != 0 {
+        return Err(LandError::NotAnNft.into());
+    }
+
+    // if required by the land plane, confirm that the new nft
+    // is not already bound to other land
+    if new_land_asset_by_mint_acc_info.key != &land_asset_by_mint_acc_pubkey(program_id, new_nft_mint_acc_info.key) {
+        return Err(LandError::InvalidLandAssetByMintAccKey.into());
+    }
+    check_one_parcel_per_nft(&land_plane_acc_state, new_land_asset_by_mint_acc_info, land_asset_acc_info.key)?;
+
+    // confirm the payer can cover the land plane's royalty on the sale
+    let royalty = land_plane_acc_state.royalty(sale_price);
+    if payer_acc_info.lamports() < royalty {
+        return Err(LandError::InsufficientRoyaltyFunds.into());
+    }
+
+    // and pay it, if any, to the land plane's fee recipient, whose
+    // account is found by key
+    if royalty > 0 {
+        let fee_recipient = land_plane_acc_state.fee_recipient().ok_or(LandError::FeeRecipientUnset)?;
+        let fee_recipient_acc_info = accounts
+            .iter()
+            .find(|acc_info| *acc_info.key == fee_recipient)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        invoke(
+            &system_instruction::transfer(payer_acc_info.key, fee_recipient_acc_info.key, royalty),
+            &[
+                payer_acc_info.clone(),
+                fee_recipient_acc_info.clone(),
+                system_program_acc_info.clone(),
+            ],
+        )?;
+    }
+
+    // close the index of the current nft if it points at this land. It
+    // may instead point at other land later bound to the same nft, or
+    // not exist for land minted before indexing began.
+    if !land_asset_by_mint_acc_info.data_is_empty()
+        && land_asset_by_mint_acc_info.key != new_land_asset_by_mint_acc_info.key
+    {
+        let land_asset_by_mint_acc_state = LandAssetByMint::from_account_info(land_asset_by_mint_acc_info)?;
+        if land_asset_by_mint_acc_state.version != LandAssetByMintVersion::Uninitialised
+            && &land_asset_by_mint_acc_state.land_asset == land_asset_acc_info.key
+        {
+            close_account(land_asset_by_mint_acc_info, payer_acc_info);
+        }
+    }
+
+    // index the land by the new nft mint
+    index_land_asset_by_mint(
+        program_id,
+        new_land_asset_by_mint_acc_info,
+        new_nft_mint_acc_info.key,
+        &LandAssetByMint {
+            version: LandAssetByMintVersion::V1,
+            land_plane: *land_plane_acc_info.key,
+            land_asset: *land_asset_acc_info.key,
+            coordinate,
+        },
+        rent_sysvar_acc_info,
+        system_program_acc_info,
+        payer_acc_info,
+    )?;
+
+    // then bind the land to the new nft, counting the transfer if the
+    // land asset account is long enough, i.e. allocated at V7 or later
+    land_asset_acc_state.mint_pubkey = *new_nft_mint_acc_info.key;
+    if land_asset_acc_info.data_len() >= LAND_ASSET_V7_ACC_DATA_LEN {
+        land_asset_acc_state.transfer_count = land_asset_acc_state.transfer_count.saturating_add(1);
+    }
+    land_asset_acc_state.pack_versioned(&mut land_asset_acc_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+/// Repair the co-ordinate recorded on a minted piece of land
+pub fn process_repair_parcel_coordinate(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    // prepare an account info iterator and get a handle
+    // on required accounts
+    let account_info_iter = &mut accounts.iter();
+    let land_plane_owner_acc_info = next_account_info(account_info_iter)?;
+    let land_asset_acc_info = next_account_info(account_info_iter)?;
+    let land_plane_acc_info = next_account_info(account_info_iter)?;
+
+    // confirm that the land plane owner is a signatory on the transaction
+    if !land_plane_owner_acc_info.is_signer {
+        return Err(LandError::MissingSignature.into());
+    }
+
+    // parse land plane account state and confirm
+    // that it is owned by the signer
+    let land_plane_acc_state = LandPlane::from_account_info(land_plane_acc_info)?;
+    if land_plane_acc_state.version == LandPlaneVersion::Uninitialised {
+        return Err(LandError::LandPlaneAccUninitialised.into());
+    }
+    if land_plane_acc_state.owner != Some(*land_plane_owner_acc_info.key) {
+        return Err(LandError::LandPlaneOwnerMismatch.into());
+    }
+
+    // parse land asset account state and confirm
+    // that the land has been minted
+    let mut land_asset_acc_state = LandAsset::from_account_info(land_asset_acc_info)?;
+    if land_asset_acc_state.version == LandAssetVersion::Uninitialised {
+        return Err(LandError::LandAssetAccUninitialised.into());
+    }
+    if !land_asset_acc_state.is_minted() {
+        return Err(LandError::LandAssetNotMinted.into());
+    }
+
+    // confirm that the land asset account is long enough to hold
+    // the co-ordinate, which is not the case for accounts written
+    // at a version older than V3
+    let version = LandAssetVersion::latest_for_data_len(land_asset_acc_info.data_len())
+        .ok_or(LandError::IncorrectDataSize)?;
+
+    // confirm that the land was minted from the given land plane,
+    // if the land asset records one
+    if !is_unset_pubkey(&land_asset_acc_state.land_plane)
+        && &land_asset_acc_state.land_plane != land_plane_acc_info.key
+    {
+        return Err(LandError::InvalidLandAssetAccKey.into());
+    }
+
+    // recover the co-ordinate of the land from the
+    // address of the land asset account
+    let coordinate = land_plane_acc_state
+        .find_minted_coordinate(program_id, land_plane_acc_info.key, land_asset_acc_info.key)
+        .ok_or(LandError::InvalidLandAssetAccKey)?;
+
+    // then record it on the land asset
+    land_asset_acc_state.version = version;
+    land_asset_acc_state.land_plane = *land_plane_acc_info.key;
+    land_asset_acc_state.coordinate = coordinate;
+    land_asset_acc_state.pack_versioned(&mut land_asset_acc_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+/// Migrate a run of land asset accounts, in mint order, to the current version
+pub fn process_migrate_assets_batch(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    start_index: u64,
+    count: u8,
+) -> ProgramResult {
+    // prepare an account info iterator and get a handle
+    // on required accounts
+    let account_info_iter = &mut accounts.iter();
+    let land_plane_owner_acc_info = next_account_info(account_info_iter)?;
+    let land_plane_acc_info = next_account_info(account_info_iter)?;
+
+    // confirm that the land plane owner is a signatory on the transaction
+    if !land_plane_owner_acc_info.is_signer {
+        return Err(LandError::MissingSignature.into());
+    }
+
+    // parse land plane account state and confirm
+    // that it is owned by the signer
+    let mut land_plane_acc_state = LandPlane::from_account_info(land_plane_acc_info)?;
+    if land_plane_acc_state.version == LandPlaneVersion::Uninitialised {
+        return Err(LandError::LandPlaneAccUninitialised.into());
+    }
+    if land_plane_acc_state.owner != Some(*land_plane_owner_acc_info.key) {
+        return Err(LandError::LandPlaneOwnerMismatch.into());
+    }
+
+    // confirm that the batch starts where the last one ended,
+    // so that no land asset account is passed over
+    if start_index != land_plane_acc_state.migrated_count {
+        return Err(LandError::MigrationOutOfOrder.into());
+    }
+    let end_index = checked_add_err(start_index, count as u64)?;
+
+    for index in start_index..end_index {
+        let land_asset_acc_info = next_account_info(account_info_iter)?;
+
+        // confirm correct land_asset_acc was provided
+        let coordinate = coordinate_at_index(index);
+        let (land_asset_acc_key, land_asset_acc_bump) =
+            land_plane_acc_state.land_asset_address(program_id, land_plane_acc_info.key, coordinate);
+        if land_asset_acc_info.key != &land_asset_acc_key {
+            return Err(LandError::InvalidLandAssetAccKey.into());
+        }
+
+        // pass over land not yet minted, and
+        // land already at the current version
+        if land_asset_acc_info.data_is_empty() {
+            continue;
+        }
+        let mut land_asset_acc_state = LandAsset::from_account_info(land_asset_acc_info)?;
+        if matches!(
+            land_asset_acc_state.version,
+            LandAssetVersion::Uninitialised
+                | LandAssetVersion::V3
+                | LandAssetVersion::V4
+                | LandAssetVersion::V5
+                | LandAssetVersion::V6
+                | LandAssetVersion::V7
+                | LandAssetVersion::V8
+        ) || !land_asset_acc_state.is_minted()
+        {
+            continue;
+        }
+
+        // pass over land asset accounts too short to hold the fields
+        // added since they were written, i.e. those allocated at V1 or
+        // V2, which cannot be grown to the current version
+        let version = match LandAssetVersion::latest_for_data_len(land_asset_acc_info.data_len()) {
+            Some(version) => version,
+            None => continue,
+        };
+
+        // then record the land plane and co-ordinate on the land asset,
+        // and the bump if the account is long enough to keep it
+        if matches!(version, LandAssetVersion::V6 | LandAssetVersion::V7 | LandAssetVersion::V8) {
+            land_asset_acc_state.bump = land_asset_acc_bump;
+        }
+        land_asset_acc_state.version = version;
+        land_asset_acc_state.land_plane = *land_plane_acc_info.key;
+        land_asset_acc_state.coordinate = coordinate;
+        land_asset_acc_state.pack_versioned(&mut land_asset_acc_info.data.borrow_mut())?;
+    }
+
+    // record progress, so that the next batch starts from here
+    land_plane_acc_state.migrated_count = end_index;
+    land_plane_acc_state.pack_versioned(&mut land_plane_acc_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+/// Force the next co-ordinate of a land plane to the given one, to
+/// recover from a bug that left it wrong
+pub fn process_force_set_cursor(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    next_x: u64,
+    next_z: u64,
+    depth: u64,
+) -> ProgramResult {
+    // prepare an account info iterator and get a handle
+    // on required accounts
+    let account_info_iter = &mut accounts.iter();
+    let upgrade_authority_acc_info = next_account_info(account_info_iter)?;
+    let land_plane_acc_info = next_account_info(account_info_iter)?;
+    let program_data_acc_info = next_account_info(account_info_iter)?;
+
+    // confirm that the signer is the program's upgrade authority
+    check_upgrade_authority(program_id, upgrade_authority_acc_info, program_data_acc_info)?;
+
+    // parse land plane account state and confirm
+    // that the given account has been initialised
+    let mut land_plane_acc_state = LandPlane::from_account_info(land_plane_acc_info)?;
+    if land_plane_acc_state.version == LandPlaneVersion::Uninitialised {
+        return Err(LandError::LandPlaneAccUninitialised.into());
+    }
+
+    // set the new co-ordinate and confirm that it is reachable by
+    // the minting pattern and lies within the coordinate ceiling,
+    // unless it is where the cursor is left once the land plane is
+    // complete, i.e. the first co-ordinate of the next shell
+    land_plane_acc_state.next_x = next_x;
+    land_plane_acc_state.next_z = next_z;
+    land_plane_acc_state.depth = depth;
+    land_plane_acc_state.validate_invariants()?;
+    let ceiling = land_plane_acc_state.coordinate_ceiling.unwrap_or(MAX_PLANE_DEPTH);
+    if land_plane_acc_state.is_complete() && (next_x, next_z) != (ceiling + 1, 0) {
+        return Err(LandError::InconsistentPlaneState.into());
+    }
+
+    // then pack the land plane account state
+    land_plane_acc_state.pack_versioned(&mut land_plane_acc_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+/// Set attributes of a piece of land
+pub fn process_set_parcel_attributes(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    mask: u32,
+    value: u32,
+) -> ProgramResult {
+    // prepare an account info iterator and get a handle
+    // on required accounts
+    let account_info_iter = &mut accounts.iter();
+    let land_plane_owner_acc_info = next_account_info(account_info_iter)?;
+    let land_asset_acc_info = next_account_info(account_info_iter)?;
+    let land_plane_acc_info = next_account_info(account_info_iter)?;
+
+    // confirm that the land plane owner is a signatory on the transaction
+    if !land_plane_owner_acc_info.is_signer {
+        return Err(LandError::MissingSignature.into());
+    }
+
+    // parse land plane account state and confirm
+    // that it is owned by the signer
+    let land_plane_acc_state = LandPlane::from_account_info(land_plane_acc_info)?;
+    if land_plane_acc_state.version == LandPlaneVersion::Uninitialised {
+        return Err(LandError::LandPlaneAccUninitialised.into());
+    }
+    if land_plane_acc_state.owner != Some(*land_plane_owner_acc_info.key) {
+        return Err(LandError::LandPlaneOwnerMismatch.into());
+    }
+
+    // parse land asset account state and confirm
+    // that the given account has been initialised
+    let mut land_asset_acc_state = LandAsset::from_account_info(land_asset_acc_info)?;
+    if land_asset_acc_state.version == LandAssetVersion::Uninitialised {
+        return Err(LandError::LandAssetAccUninitialised.into());
+    }
+
+    // confirm that the land lies on the given land plane
+    let (land_asset_acc_key, _) = land_plane_acc_state.land_asset_address(
+        program_id,
+        land_plane_acc_info.key,
+        land_asset_acc_state.coordinate,
+    );
+    if &land_asset_acc_state.land_plane != land_plane_acc_info.key
+        || land_asset_acc_info.key != &land_asset_acc_key
+    {
+        return Err(LandError::InvalidLandAssetAccKey.into());
+    }
+
+    // confirm that the land asset account is long enough to hold
+    // the attributes, which is not the case for accounts allocated
+    // at a version older than V4
+    if land_asset_acc_info.data_len() < LAND_ASSET_V4_ACC_DATA_LEN {
+        return Err(LandError::IncorrectDataSize.into());
+    }
+
+    // then set the attributes, at the latest version the account holds
+    land_asset_acc_state.version = LandAssetVersion::latest_for_data_len(land_asset_acc_info.data_len())
+        .ok_or(LandError::IncorrectDataSize)?;
+    land_asset_acc_state.set_attributes(mask, value);
+    land_asset_acc_state.pack_versioned(&mut land_asset_acc_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+/// Mint the next piece of land from a Land Plane, setting its attributes
+pub fn process_mint_next_with_attributes(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    attributes: u32,
+) -> ProgramResult {
+    // prepare an account info iterator and get a handle
+    // on the accounts needed besides those of the mint
+    let account_info_iter = &mut accounts.iter();
+    let payer_acc_info = next_account_info(account_info_iter)?;
+    let _nft_assoc_token_acc_owner_acc_info = next_account_info(account_info_iter)?;
+    let land_asset_acc_info = next_account_info(account_info_iter)?;
+    let land_plane_acc_info = next_account_info(account_info_iter)?;
+
+    // without attributes this is an ordinary mint
+    if attributes == 0 {
+        return process_mint_next_land_asset(program_id, accounts);
+    }
+
+    // otherwise confirm that the payer is the land plane owner, so
+    // that holders cannot give their own land special attributes
+    if !payer_acc_info.is_signer {
+        return Err(LandError::MissingSignature.into());
+    }
+    let land_plane_acc_state = LandPlane::from_account_info(land_plane_acc_info)?;
+    if land_plane_acc_state.version == LandPlaneVersion::Uninitialised {
+        return Err(LandError::LandPlaneAccUninitialised.into());
+    }
+    if land_plane_acc_state.owner != Some(*payer_acc_info.key) {
+        return Err(LandError::LandPlaneOwnerMismatch.into());
+    }
+
+    // and that the land asset account, if it already exists, is long
+    // enough to hold the attributes, which is not the case for accounts
+    // allocated at a version older than V4
+    if !land_asset_acc_info.data_is_empty() && land_asset_acc_info.data_len() < LAND_ASSET_V4_ACC_DATA_LEN {
+        return Err(LandError::IncorrectDataSize.into());
+    }
+
+    // mint the land as usual
+    process_mint_next_land_asset(program_id, accounts)?;
+
+    // then set its attributes, at the latest version the account holds
+    let mut land_asset_acc_state = LandAsset::from_account_info(land_asset_acc_info)?;
+    land_asset_acc_state.version = LandAssetVersion::latest_for_data_len(land_asset_acc_info.data_len())
+        .ok_or(LandError::IncorrectDataSize)?;
+    land_asset_acc_state.set_attributes(u32::MAX, attributes);
+    land_asset_acc_state.pack_versioned(&mut land_asset_acc_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+/// Mint the next piece of land from a Land Plane, unless the given
+/// slot has passed
+pub fn process_mint_next_with_deadline(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    valid_until_slot: u64,
+) -> ProgramResult {
+    // get a handle on the clock sysvar, which, unlike for the mint, is
+    // required, and is found by its key as the optional accounts given
+    // ahead of it in the mint may be omitted
+    let clock_sysvar_acc_info = accounts
+        .iter()
+        .find(|acc_info| *acc_info.key == sysvar::clock::id())
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    // confirm that the deadline has not passed
+    if Clock::from_account_info(clock_sysvar_acc_info)?.slot > valid_until_slot {
+        return Err(LandError::TransactionExpired.into());
+    }
+
+    // then mint the land as usual
+    process_mint_next_land_asset(program_id, accounts)
+}
+
+/// Mint land left unminted once the sale window of a Land Plane has
+/// closed, on behalf of its reserve claimant
+pub fn process_claim_reserved_parcel(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    coordinate: Coordinate,
+) -> ProgramResult {
+    // prepare an account info iterator and get a handle
+    // on required accounts
+    let account_info_iter = &mut accounts.iter();
+    let reserve_claimant_acc_info = next_account_info(account_info_iter)?;
+    let land_asset_acc_info = next_account_info(account_info_iter)?;
+    let land_plane_acc_info = next_account_info(account_info_iter)?;
+    let nft_mint_acc_info = next_account_info(account_info_iter)?;
+    let land_plane_stats_acc_info = next_account_info(account_info_iter)?;
+    let land_asset_by_mint_acc_info = next_account_info(account_info_iter)?;
+    let clock_sysvar_acc_info = next_account_info(account_info_iter)?;
+    let rent_sysvar_acc_info = next_account_info(account_info_iter)?;
+    let system_program_acc_info = next_account_info(account_info_iter)?;
+
+    // confirm that the reserve claimant is a signatory on the transaction
+    if !reserve_claimant_acc_info.is_signer {
+        return Err(LandError::MissingSignature.into());
+    }
+
+    // parse land plane account state and confirm
+    // that the given account has been initialised
+    let mut land_plane_acc_state = LandPlane::from_account_info(land_plane_acc_info)?;
+    if land_plane_acc_state.version == LandPlaneVersion::Uninitialised {
+        return Err(LandError::LandPlaneAccUninitialised.into());
+    }
+
+    // confirm that the signer is the reserve claimant of the land plane
+    if land_plane_acc_state.reserve_claimant != Some(*reserve_claimant_acc_info.key) {
+        return Err(LandError::InvalidReserveClaimant.into());
+    }
+
+    // and that the sale window has closed, which it never does
+    // for a land plane without a close slot
+    let current_slot = Clock::from_account_info(clock_sysvar_acc_info)?.slot;
+    match land_plane_acc_state.closes_at_slot {
+        Some(closes_at_slot) if current_slot > closes_at_slot => {}
+        _ => return Err(LandError::SaleWindowOpen.into()),
+    }
+
+    // confirm that the land plane is part way through the minting pattern
+    land_plane_acc_state.validate_invariants()?;
+
+    // and, if it has a max supply, that the supply has not been reached
+    if land_plane_acc_state.max_supply_reached() {
+        return Err(LandError::MaxSupplyReached.into());
+    }
+
+    // confirm that the land may be claimed, i.e. that it is the next in
+    // the minting pattern unless the land plane is filled at random, in
+    // which case it must lie within the coordinate ceiling
+    match land_plane_acc_state.mint_pattern {
+        MintPattern::Shell => {
+            if coordinate != (Coordinate { x: land_plane_acc_state.next_x, z: land_plane_acc_state.next_z }) {
+                if land_plane_acc_state.has_passed(coordinate) {
+                    return Err(LandError::ParcelAlreadyClaimed.into());
+                }
+                return Err(LandError::InvalidLandAssetAccKey.into());
+            }
+        }
+        MintPattern::RandomFill => {
+            let ceiling = land_plane_acc_state.coordinate_ceiling.ok_or(LandError::RandomFillRequiresCeiling)?;
+            if coordinate.x > ceiling || coordinate.z > ceiling {
+                return Err(LandError::CoordinateOutOfBounds.into());
+            }
+        }
+    }
+
+    // derive expected PDA for the piece of land at the given
+    // co-ordinate and confirm correct land_asset_acc was provided
+    let (land_asset_acc_key, land_asset_acc_bump) =
+        land_plane_acc_state.land_asset_address(program_id, land_plane_acc_info.key, coordinate);
+    if land_asset_acc_info.key != &land_asset_acc_key {
+        return Err(LandError::InvalidLandAssetAccKey.into());
+    }
+
+    // parse land asset account state and confirm that the given account
+    // has been initialised. Land filled at random cannot be initialised
+    // ahead of the claim, so its account is instead created if need be.
+    let create_land_asset_acc = land_plane_acc_state.mint_pattern == MintPattern::RandomFill
+        && land_asset_acc_info.data_is_empty();
+    let mut land_asset_acc_state = if create_land_asset_acc {
+        LandAsset::unpack_versioned(&[0; LAND_ASSET_ACC_DATA_LEN])?
+    } else {
+        LandAsset::from_account_info(land_asset_acc_info)?
+    };
+    if land_asset_acc_state.version == LandAssetVersion::Uninitialised
+        && land_plane_acc_state.mint_pattern == MintPattern::Shell
+    {
+        return Err(LandError::LandAssetAccUninitialised.into());
+    }
+
+    // and that the land has not already been minted
+    if land_asset_acc_state.is_minted() {
+        return Err(LandError::LandAssetAlreadyMinted.into());
+    }
+
+    // confirm that the land is being bound to the mint of an spl nft,
+    // i.e. one of a single token with no decimals
+    if nft_mint_acc_info.owner != &spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let nft_mint = Mint::unpack(&nft_mint_acc_info.data.borrow())?;
+    if nft_mint.supply != 1 || nft_mint.decimals != 0 {
+        return Err(LandError::NotAnNft.into());
+    }
+
+    // confirm correct land_plane_stats_acc was provided
+    let (land_plane_stats_acc_key, _) = Pubkey::find_program_address(
+        &[
+            LAND_PLANE_STATS_ACC_PREFIX.as_bytes(),
+            land_plane_acc_info.key.as_ref(),
+        ],
+        program_id,
+    );
+    if land_plane_stats_acc_info.key != &land_plane_stats_acc_key {
+        return Err(LandError::InvalidLandPlaneStatsAccKey.into());
+    }
+
+    // parse land plane stats account state and confirm
+    // that the given account has been initialised
+    let mut land_plane_stats_acc_state = LandPlaneStats::from_account_info(land_plane_stats_acc_info)?;
+    if land_plane_stats_acc_state.version == LandPlaneStatsVersion::Uninitialised {
+        return Err(LandError::LandPlaneStatsAccUninitialised.into());
+    }
+
+    // confirm correct land_asset_by_mint_acc was provided
+    if land_asset_by_mint_acc_info.key != &land_asset_by_mint_acc_pubkey(program_id, nft_mint_acc_info.key) {
+        return Err(LandError::InvalidLandAssetByMintAccKey.into());
+    }
+
+    // if required by the land plane, confirm that the nft
+    // is not already bound to other land
+    check_one_parcel_per_nft(&land_plane_acc_state, land_asset_by_mint_acc_info, land_asset_acc_info.key)?;
+
+    // move the land plane on to the next piece of land
+    land_plane_acc_state.increment_mint()?;
+
+    // link the claimed piece of land to the nft, recording the reserve
+    // claimant as its minter, at the latest version the land asset
+    // account is long enough for
+    land_asset_acc_state.version = LandAssetVersion::latest_for_data_len(land_asset_acc_info.data_len())
+        .ok_or(LandError::IncorrectDataSize)?;
+    land_asset_acc_state.mint_pubkey = *nft_mint_acc_info.key;
+    land_asset_acc_state.minter = *reserve_claimant_acc_info.key;
+    land_asset_acc_state.land_plane = *land_plane_acc_info.key;
+    land_asset_acc_state.coordinate = coordinate;
+
+    // record the mint, for which no fee is charged
+    land_plane_stats_acc_state.record_mint(reserve_claimant_acc_info.key, 0);
+
+    // create the land asset account if need be
+    if create_land_asset_acc {
+        land_asset_acc_state.bump = land_asset_acc_bump;
+        create_or_allocate_account_raw(
+            *program_id,
+            land_asset_acc_info,
+            rent_sysvar_acc_info,
+            system_program_acc_info,
+            reserve_claimant_acc_info,
+            LAND_ASSET_ACC_DATA_LEN,
+            &[
+                LAND_ASSET_ACC_PREFIX.as_bytes(),
+                land_plane_acc_info.key.as_ref(),
+                &coordinate_seed(coordinate.x, land_plane_acc_state.addr_width),
+                &coordinate_seed(coordinate.z, land_plane_acc_state.addr_width),
+                &[land_asset_acc_bump],
+            ],
+        )?;
+    }
+
+    // then serialize the updated account states
+    land_asset_acc_state.pack_versioned(&mut land_asset_acc_info.data.borrow_mut())?;
+    land_plane_acc_state.pack_versioned(&mut land_plane_acc_info.data.borrow_mut())?;
+    land_plane_stats_acc_state.serialize(&mut *land_plane_stats_acc_info.data.borrow_mut())?;
+
+    // and index the claimed piece of land by the nft mint
+    index_land_asset_by_mint(
+        program_id,
+        land_asset_by_mint_acc_info,
+        nft_mint_acc_info.key,
+        &LandAssetByMint {
+            version: LandAssetByMintVersion::V1,
+            land_plane: *land_plane_acc_info.key,
+            land_asset: *land_asset_acc_info.key,
+            coordinate,
+        },
+        rent_sysvar_acc_info,
+        system_program_acc_info,
+        reserve_claimant_acc_info,
+    )?;
+
+    // log the claimed co-ordinate and the new land plane state as for a mint
+    log_minted(coordinate, land_plane_acc_state.state_hash());
+
+    Ok(())
+}
+
+/// Merge a 2x2 block of land into an estate
+pub fn process_merge_parcels(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    // prepare an account info iterator and get a handle
+    // on required accounts
+    let account_info_iter = &mut accounts.iter();
+    let nft_assoc_token_acc_owner_acc_info = next_account_info(account_info_iter)?;
+    let land_asset_acc_infos = [
+        next_account_info(account_info_iter)?,
+        next_account_info(account_info_iter)?,
+        next_account_info(account_info_iter)?,
+        next_account_info(account_info_iter)?,
+    ];
+    let land_plane_acc_info = next_account_info(account_info_iter)?;
+    let nft_assoc_token_acc_infos = [
+        next_account_info(account_info_iter)?,
+        next_account_info(account_info_iter)?,
+        next_account_info(account_info_iter)?,
+        next_account_info(account_info_iter)?,
+    ];
+
+    // confirm that the holder of the nfts is a signatory on the transaction
+    if !nft_assoc_token_acc_owner_acc_info.is_signer {
+        return Err(LandError::MissingSignature.into());
+    }
+
+    // parse land plane account state and confirm
+    // that the given account has been initialised
+    let land_plane_acc_state = LandPlane::from_account_info(land_plane_acc_info)?;
+    if land_plane_acc_state.version == LandPlaneVersion::Uninitialised {
+        return Err(LandError::LandPlaneAccUninitialised.into());
+    }
+
+    let mut land_asset_acc_states = Vec::with_capacity(land_asset_acc_infos.len());
+    for (land_asset_acc_info, nft_assoc_token_acc_info) in land_asset_acc_infos
+        .iter()
+        .zip(nft_assoc_token_acc_infos.iter())
+    {
+        // parse land asset account state and confirm
+        // that the land has been minted
+        let land_asset_acc_state = LandAsset::from_account_info(land_asset_acc_info)?;
+        if land_asset_acc_state.version == LandAssetVersion::Uninitialised {
+            return Err(LandError::LandAssetAccUninitialised.into());
+        }
+        if !land_asset_acc_state.is_minted() {
+            return Err(LandError::LandAssetNotMinted.into());
+        }
+
+        // confirm that the land lies on the given land plane
+        let (land_asset_acc_key, _) = land_plane_acc_state.land_asset_address(
+            program_id,
+            land_plane_acc_info.key,
+            land_asset_acc_state.coordinate,
+        );
+        if &land_asset_acc_state.land_plane != land_plane_acc_info.key
+            || land_asset_acc_info.key != &land_asset_acc_key
+        {
+            return Err(LandError::InvalidLandAssetAccKey.into());
+        }
+
+        // and is not already part of an estate
+        if land_asset_acc_state.is_merged() {
+            return Err(LandError::ParcelAlreadyMerged.into());
+        }
+
+        // confirm that the land asset account is long enough to record
+        // the estate, which is not the case for accounts allocated at a
+        // version older than V5
+        if land_asset_acc_info.data_len() < LAND_ASSET_V5_ACC_DATA_LEN {
+            return Err(LandError::IncorrectDataSize.into());
+        }
+
+        // confirm that the signer holds the nft to which the land is bound
+        check_nft_held(
+            nft_assoc_token_acc_info,
+            nft_assoc_token_acc_owner_acc_info.key,
+            &land_asset_acc_state.mint_pubkey,
+        )?;
+
+        land_asset_acc_states.push(land_asset_acc_state);
+    }
+
+    // confirm that the land forms a 2x2 block, in the expected order,
+    // with the primary parcel at its lowest x and z
+    let Coordinate { x, z } = land_asset_acc_states[0].coordinate;
+    let (x1, z1) = (checked_add_err(x, 1)?, checked_add_err(z, 1)?);
+    let expected = [
+        Coordinate { x, z },
+        Coordinate { x: x1, z },
+        Coordinate { x, z: z1 },
+        Coordinate { x: x1, z: z1 },
+    ];
+    if land_asset_acc_states
+        .iter()
+        .zip(expected.iter())
+        .any(|(land_asset_acc_state, coordinate)| &land_asset_acc_state.coordinate != coordinate)
+    {
+        return Err(LandError::ParcelsNotContiguous.into());
+    }
+
+    // then record the estate on the primary parcel, and mark
+    // the others as merged into it
+    let primary_land_asset_acc_key = *land_asset_acc_infos[0].key;
+    for (i, (land_asset_acc_info, land_asset_acc_state)) in land_asset_acc_infos
+        .iter()
+        .zip(land_asset_acc_states.iter_mut())
+        .enumerate()
+    {
+        land_asset_acc_state.version = LandAssetVersion::latest_for_data_len(land_asset_acc_info.data_len())
+            .ok_or(LandError::IncorrectDataSize)?;
+        if i == 0 {
+            land_asset_acc_state.estate_footprint = 2;
+        } else {
+            land_asset_acc_state.merged_into = primary_land_asset_acc_key;
+        }
+        land_asset_acc_state.pack_versioned(&mut land_asset_acc_info.data.borrow_mut())?;
+    }
+
+    Ok(())
+}
+
+/// Set the collection level metadata of a Land Plane
+pub fn process_set_plane_metadata(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    name: BoundedString<MAX_PLANE_NAME_LEN>,
+    symbol: BoundedString<MAX_PLANE_SYMBOL_LEN>,
+    base_uri: BoundedString<MAX_PLANE_BASE_URI_LEN>,
+) -> ProgramResult {
+    // prepare an account info iterator and get a handle
+    // on required accounts
+    let account_info_iter = &mut accounts.iter();
+    let land_plane_owner_acc_info = next_account_info(account_info_iter)?;
+    let plane_metadata_acc_info = next_account_info(account_info_iter)?;
+    let land_plane_acc_info = next_account_info(account_info_iter)?;
+    let rent_sysvar_acc_info = next_account_info(account_info_iter)?;
+    let system_program_acc_info = next_account_info(account_info_iter)?;
+
+    // confirm that the land plane owner is a signatory on the transaction
+    if !land_plane_owner_acc_info.is_signer {
+        return Err(LandError::MissingSignature.into());
+    }
+
+    // parse land plane account state and confirm
+    // that it is owned by the signer
+    let land_plane_acc_state = LandPlane::from_account_info(land_plane_acc_info)?;
+    if land_plane_acc_state.version == LandPlaneVersion::Uninitialised {
+        return Err(LandError::LandPlaneAccUninitialised.into());
+    }
+    if land_plane_acc_state.owner != Some(*land_plane_owner_acc_info.key) {
+        return Err(LandError::LandPlaneOwnerMismatch.into());
+    }
+
+    // derive expected PDA for the plane metadata account
+    // and confirm correct plane_metadata_acc was provided
+    let (plane_metadata_acc_key, plane_metadata_acc_bump) = Pubkey::find_program_address(
+        &[
+            PLANE_METADATA_ACC_PREFIX.as_bytes(),
+            land_plane_acc_info.key.as_ref(),
+        ],
+        program_id,
+    );
+    if plane_metadata_acc_info.key != &plane_metadata_acc_key {
+        return Err(LandError::InvalidPlaneMetadataAccKey.into());
+    }
+
+    // create the account if it does not yet exist
+    if plane_metadata_acc_info.data_is_empty() {
+        // confirm that the genuine system program was provided,
+        // since it is invoked to create the plane metadata account
+        if system_program_acc_info.key != &system_program::id() {
+            return Err(LandError::InvalidSystemProgram.into());
+        }
+        create_or_allocate_account_raw(
+            *program_id,
+            plane_metadata_acc_info,
+            rent_sysvar_acc_info,
+            system_program_acc_info,
+            land_plane_owner_acc_info,
+            PLANE_METADATA_ACC_DATA_LEN,
+            &[
+                PLANE_METADATA_ACC_PREFIX.as_bytes(),
+                land_plane_acc_info.key.as_ref(),
+                &[plane_metadata_acc_bump],
+            ],
+        )?;
+    }
+
+    // then pack the metadata, overwriting any set earlier
+    PlaneMetadata::from_account_info(plane_metadata_acc_info)?;
+    PlaneMetadata {
+        version: PlaneMetadataVersion::V1,
+        name,
+        symbol,
+        base_uri,
+    }.pack_into_slice(&mut plane_metadata_acc_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+/// Verify that the signer holds the NFT to which a piece of land is bound
+pub fn process_verify_parcel_ownership(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    // prepare an account info iterator and get a handle
+    // on required accounts
+    let account_info_iter = &mut accounts.iter();
+    let nft_assoc_token_acc_owner_acc_info = next_account_info(account_info_iter)?;
+    let land_asset_acc_info = next_account_info(account_info_iter)?;
+    let land_plane_acc_info = next_account_info(account_info_iter)?;
+    let nft_assoc_token_acc_info = next_account_info(account_info_iter)?;
+
+    // confirm that the holder of the nft is a signatory on the transaction
+    if !nft_assoc_token_acc_owner_acc_info.is_signer {
+        return Err(LandError::MissingSignature.into());
+    }
+
+    // parse land plane account state and confirm
+    // that the given account has been initialised
+    let land_plane_acc_state = LandPlane::from_account_info(land_plane_acc_info)?;
+    if land_plane_acc_state.version == LandPlaneVersion::Uninitialised {
+        return Err(LandError::LandPlaneAccUninitialised.into());
+    }
+
+    // parse land asset account state and confirm
+    // that the land has been minted
+    let land_asset_acc_state = LandAsset::from_account_info(land_asset_acc_info)?;
+    if land_asset_acc_state.version == LandAssetVersion::Uninitialised {
+        return Err(LandError::LandAssetAccUninitialised.into());
+    }
+    if !land_asset_acc_state.is_minted() {
+        return Err(LandError::LandAssetNotMinted.into());
+    }
+
+    // confirm that the land lies on the given land plane
+    let (land_asset_acc_key, _) = land_plane_acc_state.land_asset_address(
+        program_id,
+        land_plane_acc_info.key,
+        land_asset_acc_state.coordinate,
+    );
+    if &land_asset_acc_state.land_plane != land_plane_acc_info.key
+        || land_asset_acc_info.key != &land_asset_acc_key
+    {
+        return Err(LandError::InvalidLandAssetAccKey.into());
+    }
+
+    // then check that the signer holds the nft to which the land is
+    // bound, logging the outcome either way
+    let held = check_nft_held(
+        nft_assoc_token_acc_info,
+        nft_assoc_token_acc_owner_acc_info.key,
+        &land_asset_acc_state.mint_pubkey,
+    );
+    log_parcel_ownership(land_asset_acc_state.coordinate, held.is_ok());
+    held
+}
+
+/// Log_parcel_ownership logs the outcome of VerifyParcelOwnership for
+/// the land at the given co-ordinate, as a line of text.
+#[cfg(not(feature = "compact-logs"))]
+fn log_parcel_ownership(coordinate: Coordinate, held: bool) {
+    // formatted first, since msg! with five arguments is taken as sol_log_64
+    msg!(&format!("{}x={}, z={}, held={}", PARCEL_OWNERSHIP_LOG_PREFIX, coordinate.x, coordinate.z, held));
+}
+
+/// Log_parcel_ownership logs the outcome of VerifyParcelOwnership for
+/// the land at the given co-ordinate, as a compact event.
+#[cfg(feature = "compact-logs")]
+fn log_parcel_ownership(coordinate: Coordinate, held: bool) {
+    solana_program::log::sol_log_64(PARCEL_OWNERSHIP_EVENT_TAG, coordinate.x, coordinate.z, held as u64, 0);
+}
+
+/// Report whether the land at a co-ordinate of a land plane has been
+/// initialised and minted, and to which NFT it is bound
+pub fn process_check_coordinate(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    coordinate: Coordinate,
+) -> ProgramResult {
+    // prepare an account info iterator and get a handle
+    // on required accounts
+    let account_info_iter = &mut accounts.iter();
+    let land_asset_acc_info = next_account_info(account_info_iter)?;
+    let land_plane_acc_info = next_account_info(account_info_iter)?;
+
+    // parse land plane account state and confirm
+    // that the given account has been initialised
+    let land_plane_acc_state = LandPlane::from_account_info(land_plane_acc_info)?;
+    if land_plane_acc_state.version == LandPlaneVersion::Uninitialised {
+        return Err(LandError::LandPlaneAccUninitialised.into());
+    }
+
+    // derive the PDA of the land at the co-ordinate and confirm that
+    // the given land asset account is it before trusting its state
+    let (land_asset_acc_key, _) =
+        land_plane_acc_state.land_asset_address(program_id, land_plane_acc_info.key, coordinate);
+    if land_asset_acc_info.key != &land_asset_acc_key {
+        return Err(LandError::InvalidLandAssetAccKey.into());
+    }
+
+    // the account of land yet to be initialised may not exist at all,
+    // otherwise parse its state
+    let land_asset_acc_state = if land_asset_acc_info.owner != program_id || land_asset_acc_info.data_is_empty() {
+        None
+    } else {
+        LandAsset::try_load(land_asset_acc_info)?
+    };
+
+    // then log what lies at the co-ordinate
+    let initialised = land_asset_acc_state.is_some();
+    let mint_pubkey = land_asset_acc_state.map(|a| a.mint_pubkey).unwrap_or_default();
+    log_coordinate_occupancy(coordinate, initialised, &mint_pubkey);
+
+    Ok(())
+}
+
+/// Log_coordinate_occupancy logs the outcome of CheckCoordinate for
+/// the land at the given co-ordinate, as a line of text.
+#[cfg(not(feature = "compact-logs"))]
+fn log_coordinate_occupancy(coordinate: Coordinate, initialised: bool, mint_pubkey: &Pubkey) {
+    msg!(
+        "{}x={}, z={}, initialised={}, minted={}, mint={}",
+        COORDINATE_OCCUPANCY_LOG_PREFIX,
+        coordinate.x,
+        coordinate.z,
+        initialised,
+        !is_unset_pubkey(mint_pubkey),
+        mint_pubkey,
+    );
+}
+
+/// Log_coordinate_occupancy logs the outcome of CheckCoordinate for
+/// the land at the given co-ordinate, as a compact event followed by
+/// the NFT mint to which the land is bound.
+#[cfg(feature = "compact-logs")]
+fn log_coordinate_occupancy(coordinate: Coordinate, initialised: bool, mint_pubkey: &Pubkey) {
+    let minted = !is_unset_pubkey(mint_pubkey);
+    solana_program::log::sol_log_64(COORDINATE_OCCUPANCY_EVENT_TAG, coordinate.x, coordinate.z, initialised as u64, minted as u64);
+    mint_pubkey.log();
+}
+
+/// Log the state of the land asset accounts of a run of land minted
+/// consecutively from a land plane
+pub fn process_emit_parcel_range(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    start_index: u64,
+    count: u8,
+) -> ProgramResult {
+    // prepare an account info iterator and get a handle
+    // on required accounts
+    let account_info_iter = &mut accounts.iter();
+    let land_plane_acc_info = next_account_info(account_info_iter)?;
+
+    // confirm that the range is bounded in size, so that its
+    // log line stays within the log size limit of a transaction
+    if count as usize > MAX_PARCEL_RANGE_COUNT {
+        return Err(LandError::ParcelRangeTooLarge.into());
+    }
+
+    // parse land plane account state and confirm
+    // that the given account has been initialised
+    let land_plane_acc_state = LandPlane::from_account_info(land_plane_acc_info)?;
+    if land_plane_acc_state.version == LandPlaneVersion::Uninitialised {
+        return Err(LandError::LandPlaneAccUninitialised.into());
+    }
+    let end_index = checked_add_err(start_index, count as u64)?;
+
+    let mut data = vec![0; count as usize * LAND_ASSET_ACC_DATA_LEN];
+    for (index, packed) in (start_index..end_index).zip(data.chunks_exact_mut(LAND_ASSET_ACC_DATA_LEN)) {
+        let land_asset_acc_info = next_account_info(account_info_iter)?;
+
+        // confirm correct land_asset_acc was provided
+        let (land_asset_acc_key, _) = land_plane_acc_state.land_asset_address(
+            program_id,
+            land_plane_acc_info.key,
+            coordinate_at_index(index),
+        );
+        if land_asset_acc_info.key != &land_asset_acc_key {
+            return Err(LandError::InvalidLandAssetAccKey.into());
+        }
+
+        // the account of land yet to be initialised may not exist at all,
+        // and is left zeroed, otherwise pack its state at the current layout
+        if land_asset_acc_info.owner != program_id || land_asset_acc_info.data_is_empty() {
+            continue;
+        }
+        LandAsset::from_account_info(land_asset_acc_info)?.pack_into_slice(packed);
+    }
+
+    // then log the packed states
+    msg!("{}start_index={}, data={}", PARCEL_RANGE_LOG_PREFIX, start_index, to_hex(&data));
+
+    Ok(())
+}
+
+/// To_hex returns the lower case hex encoding of the given bytes,
+/// without the compute cost of formatting each byte.
+fn to_hex(data: &[u8]) -> String {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut hex = String::with_capacity(data.len() * 2);
+    for b in data {
+        hex.push(DIGITS[(b >> 4) as usize] as char);
+        hex.push(DIGITS[(b & 0xf) as usize] as char);
+    }
+    hex
+}
+
+/// Set the slots between which land may be minted from a land plane
+/// on behalf of its owner
+pub fn process_set_mint_window(
+    accounts: &[AccountInfo],
+    mint_start_slot: Option<u64>,
+    mint_end_slot: Option<u64>,
+) -> ProgramResult {
+    // prepare an account info iterator and get a handle
+    // on required accounts
+    let account_info_iter = &mut accounts.iter();
+    let land_plane_owner_acc_info = next_account_info(account_info_iter)?;
+    let land_plane_acc_info = next_account_info(account_info_iter)?;
+
+    // confirm that the land plane owner is a signatory on the transaction
+    if !land_plane_owner_acc_info.is_signer {
+        return Err(LandError::MissingSignature.into());
+    }
+
+    // parse land plane account state and confirm
+    // that it is owned by the signer
+    let mut land_plane_acc_state = LandPlane::from_account_info(land_plane_acc_info)?;
+    if land_plane_acc_state.version == LandPlaneVersion::Uninitialised {
+        return Err(LandError::LandPlaneAccUninitialised.into());
+    }
+    if land_plane_acc_state.owner != Some(*land_plane_owner_acc_info.key) {
+        return Err(LandError::LandPlaneOwnerMismatch.into());
+    }
+
+    // confirm that the window does not end before it starts,
+    // which would close the land plane to mints for good
+    if let (Some(start), Some(end)) = (mint_start_slot, mint_end_slot) {
+        if end < start {
+            return Err(LandError::InvalidMintWindow.into());
+        }
+    }
+
+    // then record the window
+    land_plane_acc_state.mint_start_slot = mint_start_slot;
+    land_plane_acc_state.mint_end_slot = mint_end_slot;
+    land_plane_acc_state.pack_versioned(&mut land_plane_acc_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+/// Set the account into which the fees of a land plane are paid
+pub fn process_set_plane_treasury(
+    accounts: &[AccountInfo],
+    plane_treasury: Option<Pubkey>,
+) -> ProgramResult {
+    // prepare an account info iterator and get a handle
+    // on required accounts
+    let account_info_iter = &mut accounts.iter();
+    let land_plane_owner_acc_info = next_account_info(account_info_iter)?;
+    let land_plane_acc_info = next_account_info(account_info_iter)?;
+
+    // confirm that the land plane owner is a signatory on the transaction
+    if !land_plane_owner_acc_info.is_signer {
+        return Err(LandError::MissingSignature.into());
+    }
+
+    // parse land plane account state and confirm
+    // that it is owned by the signer
+    let mut land_plane_acc_state = LandPlane::from_account_info(land_plane_acc_info)?;
+    if land_plane_acc_state.version == LandPlaneVersion::Uninitialised {
+        return Err(LandError::LandPlaneAccUninitialised.into());
+    }
+    if land_plane_acc_state.owner != Some(*land_plane_owner_acc_info.key) {
+        return Err(LandError::LandPlaneOwnerMismatch.into());
+    }
+
+    // then record the plane treasury
+    land_plane_acc_state.plane_treasury = plane_treasury;
+    land_plane_acc_state.pack_versioned(&mut land_plane_acc_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+/// Rebind a minted piece of land to another NFT on behalf of the
+/// owner of its land plane
+pub fn process_admin_relink_parcel(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    new_mint: &Pubkey,
+) -> ProgramResult {
+    // prepare an account info iterator and get a handle
+    // on required accounts
+    let account_info_iter = &mut accounts.iter();
+    let land_plane_owner_acc_info = next_account_info(account_info_iter)?;
+    let land_asset_acc_info = next_account_info(account_info_iter)?;
+    let land_plane_acc_info = next_account_info(account_info_iter)?;
+    let land_asset_by_mint_acc_info = next_account_info(account_info_iter)?;
+    let new_nft_mint_acc_info = next_account_info(account_info_iter)?;
+    let new_land_asset_by_mint_acc_info = next_account_info(account_info_iter)?;
+    let rent_sysvar_acc_info = next_account_info(account_info_iter)?;
+    let system_program_acc_info = next_account_info(account_info_iter)?;
+
+    // confirm that the land plane owner is a signatory on the transaction
+    if !land_plane_owner_acc_info.is_signer {
+        return Err(LandError::MissingSignature.into());
+    }
+
+    // parse land plane account state and confirm
+    // that it is owned by the signer
+    let land_plane_acc_state = LandPlane::from_account_info(land_plane_acc_info)?;
+    if land_plane_acc_state.version == LandPlaneVersion::Uninitialised {
+        return Err(LandError::LandPlaneAccUninitialised.into());
+    }
+    if land_plane_acc_state.owner != Some(*land_plane_owner_acc_info.key) {
+        return Err(LandError::LandPlaneOwnerMismatch.into());
+    }
+
+    // parse land asset account state and confirm
+    // that the land has been minted
+    let mut land_asset_acc_state = LandAsset::from_account_info(land_asset_acc_info)?;
+    if land_asset_acc_state.version == LandAssetVersion::Uninitialised {
+        return Err(LandError::LandAssetAccUninitialised.into());
+    }
+    if !land_asset_acc_state.is_minted() {
+        return Err(LandError::LandAssetNotMinted.into());
+    }
+
+    // confirm that the land lies on the given land plane
+    let (land_asset_acc_key, _) = land_plane_acc_state.land_asset_address(
+        program_id,
+        land_plane_acc_info.key,
+        land_asset_acc_state.coordinate,
+    );
+    if &land_asset_acc_state.land_plane != land_plane_acc_info.key
+        || land_asset_acc_info.key != &land_asset_acc_key
+    {
+        return Err(LandError::InvalidLandAssetAccKey.into());
+    }
+
+    // confirm that the land has not been locked, e.g. for staking
+    if land_asset_acc_state.is_locked() {
+        return Err(LandError::ParcelLocked.into());
+    }
+
+    // nor merged into an estate, whose parcels move only as one
+    if land_asset_acc_state.is_merged() {
+        return Err(LandError::ParcelAlreadyMerged.into());
+    }
+
+    // confirm correct land_asset_by_mint_acc was provided
+    // for the nft to which the land is bound
+    if land_asset_by_mint_acc_info.key != &land_asset_by_mint_acc_pubkey(program_id, &land_asset_acc_state.mint_pubkey) {
+        return Err(LandError::InvalidLandAssetByMintAccKey.into());
+    }
+
+    // confirm that the land is being bound to the mint of an spl nft,
+    // i.e. one of a single token with no decimals
+    if new_nft_mint_acc_info.key != new_mint || new_nft_mint_acc_info.owner != &spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let new_nft_mint = Mint::unpack(&new_nft_mint_acc_info.data.borrow())?;
+    if new_nft_mint.supply != 1 || new_nft_mint.decimals != 0 {
+        return Err(LandError::NotAnNft.into());
+    }
+
+    // if required by the land plane, confirm that the new nft
+    // is not already bound to other land
+    if new_land_asset_by_mint_acc_info.key != &land_asset_by_mint_acc_pubkey(program_id, new_mint) {
+        return Err(LandError::InvalidLandAssetByMintAccKey.into());
+    }
+    check_one_parcel_per_nft(&land_plane_acc_state, new_land_asset_by_mint_acc_info, land_asset_acc_info.key)?;
+
+    // close the index of the current nft if it points at this land,
+    // as for TransferLand
+    if !land_asset_by_mint_acc_info.data_is_empty()
+        && land_asset_by_mint_acc_info.key != new_land_asset_by_mint_acc_info.key
+    {
+        let land_asset_by_mint_acc_state = LandAssetByMint::from_account_info(land_asset_by_mint_acc_info)?;
+        if land_asset_by_mint_acc_state.version != LandAssetByMintVersion::Uninitialised
+            && &land_asset_by_mint_acc_state.land_asset == land_asset_acc_info.key
+        {
+            close_account(land_asset_by_mint_acc_info, land_plane_owner_acc_info);
+        }
+    }
+
+    // index the land by the new nft mint
+    index_land_asset_by_mint(
+        program_id,
+        new_land_asset_by_mint_acc_info,
+        new_mint,
+        &LandAssetByMint {
+            version: LandAssetByMintVersion::V1,
+            land_plane: *land_plane_acc_info.key,
+            land_asset: *land_asset_acc_info.key,
+            coordinate: land_asset_acc_state.coordinate,
+        },
+        rent_sysvar_acc_info,
+        system_program_acc_info,
+        land_plane_owner_acc_info,
+    )?;
+
+    // then bind the land to the new nft
+    land_asset_acc_state.mint_pubkey = *new_mint;
+    land_asset_acc_state.pack_versioned(&mut land_asset_acc_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+/// Process the sub-instructions of a ProcessBatch instruction in order
+pub fn process_batched_instructions(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instructions: Vec<BatchedInstruction>,
+) -> ProgramResult {
+    // confirm that the batch is bounded in size, so that a single
+    // batch may not create an unbounded number of accounts
+    if instructions.len() > MAX_BATCH_COUNT {
+        return Err(LandError::BatchTooLarge.into());
+    }
+
+    for batched in instructions {
+        // confirm that batches are not nested, which
+        // would allow for unbounded recursion
+        if let LandInstruction::ProcessBatch { .. } = batched.instruction {
+            return Err(LandError::NestedProcessBatch.into());
+        }
+
+        // gather the accounts of the sub-instruction, which share
+        // their data and lamports with those of the batch
+        let sub_accounts = batched
+            .account_indices
+            .iter()
+            .map(|i| accounts.get(*i as usize).cloned().ok_or(ProgramError::NotEnoughAccountKeys))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // then process it. Any failure fails the whole
+        // batch, undoing earlier sub-instructions
+        process_land_instruction(program_id, &sub_accounts, batched.instruction)?;
+    }
+
+    Ok(())
+}
+
+/// Confirm that the given account is a signatory on the transaction and
+/// is the upgrade authority of this program, as recorded in the given
+/// program data account
+fn check_upgrade_authority(
+    program_id: &Pubkey,
+    upgrade_authority_acc_info: &AccountInfo,
+    program_data_acc_info: &AccountInfo,
+) -> ProgramResult {
+    // confirm that the upgrade authority is a signatory on the transaction
+    if !upgrade_authority_acc_info.is_signer {
+        return Err(LandError::MissingSignature.into());
+    }
+
+    // confirm that the program data account is that of this program
+    let (program_data_acc_key, _) = Pubkey::find_program_address(
+        &[program_id.as_ref()],
+        &bpf_loader_upgradeable::id(),
+    );
+    if program_data_acc_info.key != &program_data_acc_key
+        || program_data_acc_info.owner != &bpf_loader_upgradeable::id()
+    {
+        return Err(LandError::InvalidProgramDataAcc.into());
+    }
+
+    // and that the signer is the program's upgrade authority
+    let upgrade_authority_address = match program_data_acc_info.deserialize_data() {
+        Ok(UpgradeableLoaderState::ProgramData { upgrade_authority_address, .. }) => {
+            upgrade_authority_address
+        }
+        _ => return Err(LandError::InvalidProgramDataAcc.into()),
+    };
+    if upgrade_authority_address != Some(*upgrade_authority_acc_info.key) {
+        return Err(LandError::SignatureError.into());
+    }
+    Ok(())
+}
+
+/// Read the hash of the most recent slot from the slot hashes sysvar
+/// account. Only the leading entry is read, rather than deserializing
+/// the whole sysvar, which is large.
+fn recent_slot_hash(slot_hashes_sysvar_acc_info: &AccountInfo) -> Result<[u8; 32], ProgramError> {
+    // confirm the genuine slot hashes sysvar was provided
+    if slot_hashes_sysvar_acc_info.key != &sysvar::slot_hashes::id() {
+        return Err(LandError::InvalidSlotHashesSysvar.into());
+    }
+
+    // the sysvar holds a u64 count of entries followed by
+    // (slot: u64, hash: [u8; 32]) entries, most recent first
+    let data = slot_hashes_sysvar_acc_info.data.borrow();
+    if data.len() < 48 || u64::from_le_bytes(data[..8].try_into().unwrap()) == 0 {
+        return Err(LandError::InvalidSlotHashesSysvar.into());
+    }
+    Ok(data[16..48].try_into().unwrap())
+}
+
+/// Confirm that the given token account belongs to the given holder
+/// and holds the nft of the given mint
+fn check_nft_held(
+    nft_assoc_token_acc_info: &AccountInfo,
+    nft_assoc_token_acc_owner_acc_key: &Pubkey,
+    nft_mint_pubkey: &Pubkey,
+) -> ProgramResult {
+    if nft_assoc_token_acc_info.owner != &spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let nft_assoc_token_acc = Account::unpack(&nft_assoc_token_acc_info.data.borrow())?;
+    if &nft_assoc_token_acc.owner != nft_assoc_token_acc_owner_acc_key {
+        return Err(LandError::NftTokenAccOwnerMismatch.into());
+    }
+    if &nft_assoc_token_acc.mint != nft_mint_pubkey {
+        return Err(LandError::NftTokenAccMintMismatch.into());
+    }
+    if nft_assoc_token_acc.amount != 1 {
+        return Err(LandError::NftNotHeld.into());
+    }
+    Ok(())
+}
+
+/// Confirm, if the land plane allows only one parcel per nft, that the
+/// land asset by mint account of an nft about to be bound to the given
+/// land shows it bound to no other land
+fn check_one_parcel_per_nft(
+    land_plane: &LandPlane,
+    land_asset_by_mint_acc_info: &AccountInfo,
+    land_asset_acc_key: &Pubkey,
+) -> ProgramResult {
+    if !land_plane.one_parcel_per_nft || land_asset_by_mint_acc_info.data_is_empty() {
+        return Ok(());
+    }
+    let land_asset_by_mint_acc_state = LandAssetByMint::from_account_info(land_asset_by_mint_acc_info)?;
+    if land_asset_by_mint_acc_state.version != LandAssetByMintVersion::Uninitialised
+        && &land_asset_by_mint_acc_state.land_asset != land_asset_acc_key
+    {
+        return Err(LandError::NftAlreadyOwnsLand.into());
+    }
+    Ok(())
+}
+
+/// Point the land asset by mint account of the given nft mint at the
+/// given land, creating the account first if it does not yet exist.
+/// Where the nft is bound to more than one piece of land the account
+/// points at the last to be bound.
+#[allow(clippy::too_many_arguments)]
+fn index_land_asset_by_mint<'a>(
+    program_id: &Pubkey,
+    land_asset_by_mint_acc_info: &AccountInfo<'a>,
+    nft_mint_acc_key: &Pubkey,
+    land_asset_by_mint: &LandAssetByMint,
+    rent_sysvar_acc_info: &AccountInfo<'a>,
+    system_program_acc_info: &AccountInfo<'a>,
+    payer_acc_info: &AccountInfo<'a>,
+) -> ProgramResult {
+    // derive expected PDA for the land asset by mint account
+    // and confirm correct land_asset_by_mint_acc was provided
+    let (land_asset_by_mint_acc_key, land_asset_by_mint_acc_bump) = Pubkey::find_program_address(
+        &[
+            LAND_ASSET_BY_MINT_ACC_PREFIX.as_bytes(),
+            nft_mint_acc_key.as_ref(),
+        ],
+        program_id,
+    );
+    if land_asset_by_mint_acc_info.key != &land_asset_by_mint_acc_key {
+        return Err(LandError::InvalidLandAssetByMintAccKey.into());
+    }
+
+    // create the account if it does not yet exist
+    if land_asset_by_mint_acc_info.data_is_empty() {
+        create_or_allocate_account_raw(
+            *program_id,
+            land_asset_by_mint_acc_info,
+            rent_sysvar_acc_info,
+            system_program_acc_info,
+            payer_acc_info,
+            LAND_ASSET_BY_MINT_ACC_DATA_LEN,
+            &[
+                LAND_ASSET_BY_MINT_ACC_PREFIX.as_bytes(),
+                nft_mint_acc_key.as_ref(),
+                &[land_asset_by_mint_acc_bump],
+            ],
+        )?;
+    }
+
+    // then serialize the index, overwriting any earlier entry
+    LandAssetByMint::from_account_info(land_asset_by_mint_acc_info)?;
+    land_asset_by_mint.serialize(&mut *land_asset_by_mint_acc_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+/// Record the given slot as that of the last mint of land from the
+/// given land plane to the given wallet, creating the wallet mint record
+/// account first if it does not yet exist. The account key must already
+/// have been confirmed as the PDA with the given bump.
+#[allow(clippy::too_many_arguments)]
+fn record_wallet_mint<'a>(
+    program_id: &Pubkey,
+    wallet_mint_record_acc_info: &AccountInfo<'a>,
+    land_plane_acc_key: &Pubkey,
+    wallet_key: &Pubkey,
+    wallet_mint_record_acc_bump: u8,
+    slot: u64,
+    rent_sysvar_acc_info: &AccountInfo<'a>,
+    system_program_acc_info: &AccountInfo<'a>,
+    payer_acc_info: &AccountInfo<'a>,
+) -> ProgramResult {
+    // create the account if it does not yet exist
+    if wallet_mint_record_acc_info.data_is_empty() {
+        create_or_allocate_account_raw(
+            *program_id,
+            wallet_mint_record_acc_info,
+            rent_sysvar_acc_info,
+            system_program_acc_info,
+            payer_acc_info,
+            WALLET_MINT_RECORD_ACC_DATA_LEN,
+            &[
+                WALLET_MINT_RECORD_ACC_PREFIX.as_bytes(),
+                land_plane_acc_key.as_ref(),
+                wallet_key.as_ref(),
+                &[wallet_mint_record_acc_bump],
+            ],
+        )?;
+    }
+
+    // then serialize the record, overwriting any earlier slot
+    WalletMintRecord::from_account_info(wallet_mint_record_acc_info)?;
+    WalletMintRecord {
+        version: WalletMintRecordVersion::V1,
+        last_mint_slot: slot,
+    }.serialize(&mut *wallet_mint_record_acc_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+/// Lock a minted piece of land on behalf of the holder of the NFT to
+/// which it is bound, until the given locker unlocks it
+pub fn process_lock_parcel(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    locker: &Pubkey,
+) -> ProgramResult {
+    // prepare an account info iterator and get a handle
+    // on required accounts
+    let account_info_iter = &mut accounts.iter();
+    let nft_assoc_token_acc_owner_acc_info = next_account_info(account_info_iter)?;
+    let land_asset_acc_info = next_account_info(account_info_iter)?;
+    let land_plane_acc_info = next_account_info(account_info_iter)?;
+    let nft_assoc_token_acc_info = next_account_info(account_info_iter)?;
+
+    // confirm that the holder of the nft is a signatory on the transaction
+    if !nft_assoc_token_acc_owner_acc_info.is_signer {
+        return Err(LandError::MissingSignature.into());
+    }
+
+    // parse land plane account state and confirm
+    // that the given account has been initialised
+    let land_plane_acc_state = LandPlane::from_account_info(land_plane_acc_info)?;
+    if land_plane_acc_state.version == LandPlaneVersion::Uninitialised {
+        return Err(LandError::LandPlaneAccUninitialised.into());
+    }
+
+    // parse land asset account state and confirm
+    // that the land has been minted
+    let mut land_asset_acc_state = LandAsset::from_account_info(land_asset_acc_info)?;
+    if land_asset_acc_state.version == LandAssetVersion::Uninitialised {
+        return Err(LandError::LandAssetAccUninitialised.into());
+    }
+    if !land_asset_acc_state.is_minted() {
+        return Err(LandError::LandAssetNotMinted.into());
+    }
+
+    // confirm that the land lies on the given land plane
+    let (land_asset_acc_key, _) = land_plane_acc_state.land_asset_address(
+        program_id,
+        land_plane_acc_info.key,
+        land_asset_acc_state.coordinate,
+    );
+    if &land_asset_acc_state.land_plane != land_plane_acc_info.key
+        || land_asset_acc_info.key != &land_asset_acc_key
+    {
+        return Err(LandError::InvalidLandAssetAccKey.into());
+    }
+
+    // confirm that the signer holds the nft to which the land is bound
+    check_nft_held(
+        nft_assoc_token_acc_info,
+        nft_assoc_token_acc_owner_acc_info.key,
+        &land_asset_acc_state.mint_pubkey,
+    )?;
+
+    // confirm that the land has not already been locked
+    if land_asset_acc_state.is_locked() {
+        return Err(LandError::ParcelLocked.into());
+    }
+
+    // confirm that the land asset account is long enough to hold
+    // the locker, which is not the case for accounts allocated
+    // at a version older than V8
+    if land_asset_acc_info.data_len() < LAND_ASSET_ACC_DATA_LEN {
+        return Err(LandError::IncorrectDataSize.into());
+    }
+
+    // then record the locker
+    land_asset_acc_state.version = LandAssetVersion::latest_for_data_len(land_asset_acc_info.data_len())
+        .ok_or(LandError::IncorrectDataSize)?;
+    land_asset_acc_state.locked_by = *locker;
+    land_asset_acc_state.pack_versioned(&mut land_asset_acc_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+/// Unlock a piece of land on behalf of the locker that locked it
+pub fn process_unlock_parcel(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    // prepare an account info iterator and get a handle
+    // on required accounts
+    let account_info_iter = &mut accounts.iter();
+    let locker_acc_info = next_account_info(account_info_iter)?;
+    let land_asset_acc_info = next_account_info(account_info_iter)?;
+    let land_plane_acc_info = next_account_info(account_info_iter)?;
+
+    // confirm that the locker is a signatory on the transaction
+    if !locker_acc_info.is_signer {
+        return Err(LandError::MissingSignature.into());
+    }
+
+    // parse land plane account state and confirm
+    // that the given account has been initialised
+    let land_plane_acc_state = LandPlane::from_account_info(land_plane_acc_info)?;
+    if land_plane_acc_state.version == LandPlaneVersion::Uninitialised {
+        return Err(LandError::LandPlaneAccUninitialised.into());
+    }
+
+    // parse land asset account state and confirm
+    // that the given account has been initialised
+    let mut land_asset_acc_state = LandAsset::from_account_info(land_asset_acc_info)?;
+    if land_asset_acc_state.version == LandAssetVersion::Uninitialised {
+        return Err(LandError::LandAssetAccUninitialised.into());
+    }
+
+    // confirm that the land lies on the given land plane
+    let (land_asset_acc_key, _) = land_plane_acc_state.land_asset_address(
+        program_id,
+        land_plane_acc_info.key,
+        land_asset_acc_state.coordinate,
+    );
+    if &land_asset_acc_state.land_plane != land_plane_acc_info.key
+        || land_asset_acc_info.key != &land_asset_acc_key
+    {
+        return Err(LandError::InvalidLandAssetAccKey.into());
+    }
+
+    // confirm that the land was locked by the signer, which is
+    // never the case for land that is not locked
+    if !land_asset_acc_state.is_locked() || &land_asset_acc_state.locked_by != locker_acc_info.key {
+        return Err(LandError::LockerMismatch.into());
+    }
+
+    // then clear the locker
+    land_asset_acc_state.version = LandAssetVersion::latest_for_data_len(land_asset_acc_info.data_len())
+        .ok_or(LandError::IncorrectDataSize)?;
+    land_asset_acc_state.locked_by = Pubkey::default();
+    land_asset_acc_state.pack_versioned(&mut land_asset_acc_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate :: {
+        instruction::{
+            admin_relink_parcel,
+            check_coordinate,
+            claim_reserved_parcel,
+            emit_parcel_range,
+            force_set_cursor,
+            initialize_land_plane,
+            initialize_land_plane_idempotent,
+            initialize_land_plane_stats,
+            initialize_next_land_asset,
+            initialize_program_config,
+            lock_parcel,
+            merge_parcels,
+            migrate_assets_batch,
+            mint_next_land_asset,
+            mint_next_with_attributes,
+            mint_next_random_fill,
+            mint_next_with_deadline,
+            process_batch,
+            repair_parcel_coordinate,
+            set_mint_window,
+            set_plane_treasury,
+            set_parcel_attributes,
+            set_plane_metadata,
+            setup_plane,
+            stamp_coordinate_uri,
+            transfer_land,
+            unlock_parcel,
+            verify_parcel_ownership,
+        },
+        metadata::{Collection, Data, UpdateMetadataAccountArgs},
+        state::{asset_address_at_index, find_land_asset_address, wallet_mint_record_acc_pubkey, FULL_ADDR_WIDTH, LAND_ASSET_V1_ACC_DATA_LEN, LAND_ASSET_V2_ACC_DATA_LEN, LAND_ASSET_V3_ACC_DATA_LEN, LAND_PLANE_V1_ACC_DATA_LEN, LAND_PLANE_V16_ACC_DATA_LEN, PRICE_FEED_ACC_DATA_LEN, PROGRAM_CONFIG_V1_ACC_DATA_LEN},
+        test_utils::{
+            fake_asset_account,
+            fake_initialized_asset,
+            fake_initialized_plane,
+            fake_land_asset_by_mint_account,
+            fake_plane_account,
+            fake_plane_stats_account,
+            fake_program_config_account,
+            initialized_asset,
+            initialized_land_asset_by_mint,
+            initialized_plane,
+            initialized_plane_stats,
+            initialized_program_config,
+        },
+    };
+    use solana_program::{
+        borsh::try_from_slice_unchecked,
+        hash::Hash,
+        slot_hashes::SlotHashes,
+        program_stubs,
+        system_program,
+        program_error::PrintProgramError,
+        program_option::COption,
+        instruction::{AccountMeta, Instruction},
+    };
+    use solana_sdk::{
+        account::{create_account_for_test, create_is_signer_account_infos, Account as SolanaAccount},
+        program_utils::limited_deserialize,
+        system_instruction::SystemInstruction,
+    };
+
+    ///
+    /// testing utils
+    /// 
+
+    fn return_land_error_as_program_error() -> ProgramError {
+        LandError::IncorrectDataSize.into()
+    }
+
+    fn rent_sysvar() -> SolanaAccount {
+        create_account_for_test(&Rent::default())
+    }
+
+    /// Syscall stubs emulating, when invoked, system program transfers
+    /// and the Metaplex Token Metadata program's UpdateMetadataAccount.
+    struct TestSyscallStubs;
+
+    impl program_stubs::SyscallStubs for TestSyscallStubs {
+        fn sol_invoke_signed(
+            &self,
+            instruction: &Instruction,
+            account_infos: &[AccountInfo],
+            _signers_seeds: &[&[&[u8]]],
+        ) -> ProgramResult {
+            let account_info = |i: usize| account_infos
+                .iter()
+                .find(|a| a.key == &instruction.accounts[i].pubkey)
+                .unwrap();
+            if instruction.program_id == system_program::id() {
+                if let Ok(SystemInstruction::Transfer { lamports }) = limited_deserialize(&instruction.data) {
+                    **account_info(0).lamports.borrow_mut() -= lamports;
+                    **account_info(1).lamports.borrow_mut() += lamports;
+                }
+            }
+            if instruction.program_id == token_metadata_program::id() {
+                let args = UpdateMetadataAccountArgs::try_from_slice(&instruction.data[1..]).unwrap();
+                let nft_metadata_acc_info = account_info(0);
+                let mut nft_metadata = Metadata::from_account_info(nft_metadata_acc_info)?;
+                nft_metadata.data = args.data.unwrap();
+                nft_metadata.serialize(&mut *nft_metadata_acc_info.data.borrow_mut())?;
+            }
+            Ok(())
+        }
+    }
+
+    fn do_process_instruction(
+        instruction: Instruction,
+        accounts: Vec<&mut SolanaAccount>,
+    ) -> ProgramResult {
+        program_stubs::set_syscall_stubs(Box::new(TestSyscallStubs));
+
+        let mut meta = instruction
+            .accounts
+            .iter()
+            .zip(accounts)
+            .map(|(account_meta, account)| (&account_meta.pubkey, account_meta.is_signer, account))
+            .collect::<Vec<_>>();
+
+        let account_infos = create_is_signer_account_infos(&mut meta);
+        process_instruction(&instruction.program_id, &account_infos, &instruction.data)
+    }  
+    
+    fn land_plane_minimum_balance() -> u64 {
+        Rent::default().minimum_balance(LAND_PLANE_ACC_DATA_LEN)
+    }    
+
+    fn land_plane_stats_account(program_id: &Pubkey, land_plane_acc_pubkey: &Pubkey) -> (Pubkey, SolanaAccount) {
+        let (land_plane_stats_acc_pubkey, _) = Pubkey::find_program_address(
+            &[
+                LAND_PLANE_STATS_ACC_PREFIX.as_bytes(),
+                land_plane_acc_pubkey.as_ref(),
+            ],
+            program_id,
+        );
+        (land_plane_stats_acc_pubkey, fake_plane_stats_account(&initialized_plane_stats()))
+    }
+
+    fn program_config_account(
+        program_id: &Pubkey,
+        protocol_fee: u64,
+        protocol_treasury: &Pubkey,
+    ) -> (Pubkey, SolanaAccount) {
+        let (program_config_acc_pubkey, _) = Pubkey::find_program_address(
+            &[PROGRAM_CONFIG_ACC_PREFIX.as_bytes()],
+            program_id,
+        );
+        let program_config = initialized_program_config(protocol_fee, protocol_treasury);
+        (program_config_acc_pubkey, fake_program_config_account(&program_config))
+    }
+
+    fn next_land_asset_account(
+        program_id: &Pubkey,
+        land_plane_acc_pubkey: &Pubkey,
+        land_plane_acc: &SolanaAccount,
+    ) -> (Pubkey, SolanaAccount) {
+        let land_plane = LandPlane::unpack_versioned(&land_plane_acc.data).unwrap();
+        let (land_asset_acc_pubkey, bump) = land_plane.land_asset_address(
+            program_id,
+            land_plane_acc_pubkey,
+            Coordinate { x: land_plane.next_x, z: land_plane.next_z },
+        );
+        (land_asset_acc_pubkey, fake_asset_account(&LandAsset{ bump, ..initialized_asset(&Pubkey::default()) }))
+    }
+
+    fn land_asset_by_mint_account() -> SolanaAccount {
+        SolanaAccount::new(
+            Rent::default().minimum_balance(LAND_ASSET_BY_MINT_ACC_DATA_LEN),
+            LAND_ASSET_BY_MINT_ACC_DATA_LEN,
+            &crate::id(),
+        )
+    }
+
+    fn nft_token_account(owner: &Pubkey, mint: &Pubkey, state: AccountState) -> SolanaAccount {
+        let mut account = SolanaAccount::new(1, Account::LEN, &spl_token::id());
+        Account{
+            mint: *mint,
+            owner: *owner,
+            amount: 1,
+            state,
+            ..Account::default()
+        }.pack_into_slice(&mut account.data);
+        account
+    }
+
+    ///
+    /// tests
+    /// 
+
+    #[test]
+    fn test_print_error() {
+        let error = return_land_error_as_program_error();
+        error.print::<LandError>();
+    }    
+
+    /// Name of the given instruction's variant. The match is deliberately
+    /// exhaustive so that adding a variant fails to compile until it is
+    /// covered by test_decode_instruction.
+    fn variant_name(instruction: &LandInstruction) -> &'static str {
+        match instruction {
+            LandInstruction::InitialiseLandPlane { .. } => "InitialiseLandPlane",
+            LandInstruction::InitialiseNextLandAsset => "InitialiseNextLandAsset",
+            LandInstruction::MintNextLandAsset => "MintNextLandAsset",
+            LandInstruction::InitialiseLandPlaneStats => "InitialiseLandPlaneStats",
+            LandInstruction::InitialiseLandPlaneIdempotent { .. } => "InitialiseLandPlaneIdempotent",
+            LandInstruction::StampCoordinateUri { .. } => "StampCoordinateUri",
+            LandInstruction::InitialiseProgramConfig { .. } => "InitialiseProgramConfig",
+            LandInstruction::TransferLand { .. } => "TransferLand",
+            LandInstruction::RepairParcelCoordinate => "RepairParcelCoordinate",
+            LandInstruction::ProcessBatch { .. } => "ProcessBatch",
+            LandInstruction::SetupPlane { .. } => "SetupPlane",
+            LandInstruction::MigrateAssetsBatch { .. } => "MigrateAssetsBatch",
+            LandInstruction::ForceSetCursor { .. } => "ForceSetCursor",
+            LandInstruction::SetParcelAttributes { .. } => "SetParcelAttributes",
+            LandInstruction::MergeParcels => "MergeParcels",
+            LandInstruction::SetPlaneMetadata { .. } => "SetPlaneMetadata",
+            LandInstruction::VerifyParcelOwnership => "VerifyParcelOwnership",
+            LandInstruction::AdminRelinkParcel { .. } => "AdminRelinkParcel",
+            LandInstruction::MintNextWithAttributes { .. } => "MintNextWithAttributes",
+            LandInstruction::LockParcel { .. } => "LockParcel",
+            LandInstruction::UnlockParcel => "UnlockParcel",
+            LandInstruction::MintNextWithDeadline { .. } => "MintNextWithDeadline",
+            LandInstruction::ClaimReservedParcel { .. } => "ClaimReservedParcel",
+            LandInstruction::CheckCoordinate { .. } => "CheckCoordinate",
+            LandInstruction::EmitParcelRange { .. } => "EmitParcelRange",
+            LandInstruction::SetMintWindow { .. } => "SetMintWindow",
+            LandInstruction::SetPlaneTreasury { .. } => "SetPlaneTreasury",
+        }
+    }
+
+    /// Returns an instruction of each
+    /// variant of LandInstruction, in declaration order, along with
+    /// the name of its variant.
+    fn instruction_of_each_variant(program_id: &Pubkey, pubkey: &Pubkey) -> Vec<(Instruction, &'static str)> {
+        let (program_id, pubkey) = (*program_id, *pubkey);
+        vec![
+            (
+                initialize_land_plane(&program_id, &pubkey, Some(9), true, 5, Some(pubkey), MintPattern::RandomFill, true, 250, true, Some(pubkey), Some(40), 10, None, None, None).unwrap(),
+                "InitialiseLandPlane",
+            ),
+            (
+                initialize_next_land_asset(&program_id, &pubkey, &pubkey, &pubkey, None).unwrap(),
+                "InitialiseNextLandAsset",
+            ),
+            (
+                mint_next_land_asset(&program_id, &pubkey, &pubkey, &pubkey, &pubkey, &pubkey, &pubkey, &pubkey, &pubkey, &pubkey, None, None).unwrap(),
+                "MintNextLandAsset",
+            ),
+            (
+                initialize_land_plane_stats(&program_id, &pubkey, &pubkey, &pubkey).unwrap(),
+                "InitialiseLandPlaneStats",
+            ),
+            (
+                initialize_land_plane_idempotent(&program_id, &pubkey, None, false, 0, None, MintPattern::Shell, false, 0, false, None, None, 0, None, None, None).unwrap(),
+                "InitialiseLandPlaneIdempotent",
+            ),
+            (
+                stamp_coordinate_uri(&program_id, &pubkey, &pubkey, &pubkey, &pubkey, 3, 1).unwrap(),
+                "StampCoordinateUri",
+            ),
+            (
+                initialize_program_config(&program_id, &pubkey, &pubkey, 100, &pubkey).unwrap(),
+                "InitialiseProgramConfig",
+            ),
+            (
+                transfer_land(&program_id, &pubkey, &pubkey, &pubkey, &pubkey, &pubkey, &pubkey, &pubkey, None, 3, 1, 1_000).unwrap(),
+                "TransferLand",
+            ),
+            (
+                repair_parcel_coordinate(&program_id, &pubkey, &pubkey, &pubkey).unwrap(),
+                "RepairParcelCoordinate",
+            ),
+            (
+                process_batch(&program_id, &[initialize_land_plane_stats(&program_id, &pubkey, &pubkey, &pubkey).unwrap()]).unwrap(),
+                "ProcessBatch",
+            ),
+            (
+                setup_plane(&program_id, &pubkey, &pubkey, &pubkey, None, false, 0, None, MintPattern::Shell, false, 0, false, None, None, 0, None, None, None).unwrap(),
+                "SetupPlane",
+            ),
+            (
+                migrate_assets_batch(&program_id, &pubkey, &pubkey, 7, &[pubkey]).unwrap(),
+                "MigrateAssetsBatch",
+            ),
+            (
+                force_set_cursor(&program_id, &pubkey, &pubkey, 0, 3, 3).unwrap(),
+                "ForceSetCursor",
+            ),
+            (
+                set_parcel_attributes(&program_id, &pubkey, &pubkey, &pubkey, 0b11, 0b01).unwrap(),
+                "SetParcelAttributes",
+            ),
+            (
+                merge_parcels(&program_id, &pubkey, &pubkey, &[pubkey; 4], &[pubkey; 4]).unwrap(),
+                "MergeParcels",
+            ),
+            (
+                set_plane_metadata(&program_id, &pubkey, &pubkey, "Plane", "PLN", "https://example.com/").unwrap(),
+                "SetPlaneMetadata",
+            ),
+            (
+                verify_parcel_ownership(&program_id, &pubkey, &pubkey, &pubkey, &pubkey).unwrap(),
+                "VerifyParcelOwnership",
+            ),
+            (
+                admin_relink_parcel(&program_id, &pubkey, &pubkey, &pubkey, &pubkey, &pubkey).unwrap(),
+                "AdminRelinkParcel",
+            ),
+            (
+                mint_next_with_attributes(&program_id, &pubkey, &pubkey, &pubkey, &pubkey, &pubkey, &pubkey, &pubkey, &pubkey, &pubkey, None, None, 0x8000_0001).unwrap(),
+                "MintNextWithAttributes",
+            ),
+            (
+                lock_parcel(&program_id, &pubkey, &pubkey, &pubkey, &pubkey, &pubkey).unwrap(),
+                "LockParcel",
+            ),
+            (
+                unlock_parcel(&program_id, &pubkey, &pubkey, &pubkey).unwrap(),
+                "UnlockParcel",
+            ),
+            (
+                mint_next_with_deadline(&program_id, &pubkey, &pubkey, &pubkey, &pubkey, &pubkey, &pubkey, &pubkey, &pubkey, &pubkey, None, None, 100).unwrap(),
+                "MintNextWithDeadline",
+            ),
+            (
+                claim_reserved_parcel(&program_id, &pubkey, &pubkey, &pubkey, &pubkey, &pubkey, 1, 2).unwrap(),
+                "ClaimReservedParcel",
+            ),
+            (
+                check_coordinate(&program_id, &pubkey, &pubkey, 1, 2).unwrap(),
+                "CheckCoordinate",
+            ),
+            (
+                emit_parcel_range(&program_id, &pubkey, 3, &[pubkey, pubkey]).unwrap(),
+                "EmitParcelRange",
+            ),
+            (
+                set_mint_window(&program_id, &pubkey, &pubkey, Some(1), None).unwrap(),
+                "SetMintWindow",
+            ),
+            (
+                set_plane_treasury(&program_id, &pubkey, &pubkey, Some(pubkey)).unwrap(),
+                "SetPlaneTreasury",
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_decode_instruction() {
+        let program_id = crate::id();
+        let pubkey = Pubkey::new_unique();
+
+        // the data of an instruction of each variant decodes back to it
+        for (instruction, expected) in instruction_of_each_variant(&program_id, &pubkey) {
+            let decoded = decode_instruction(&instruction.data).unwrap();
+            assert_eq!(expected, variant_name(&decoded));
+            assert_eq!(instruction.data, decoded.try_to_vec().unwrap(), "{}", expected);
+        }
+
+        // fields are decoded as given
+        match decode_instruction(&force_set_cursor(&program_id, &pubkey, &pubkey, 1, 2, 3).unwrap().data) {
+            Ok(LandInstruction::ForceSetCursor { next_x: 1, next_z: 2, depth: 3 }) => {}
+            _ => panic!("ForceSetCursor not decoded as given"),
+        }
+
+        //
+        // data that is not an instruction is rejected
+        //
+        let data = set_parcel_attributes(&program_id, &pubkey, &pubkey, &pubkey, 0b11, 0b01).unwrap().data;
+        assert!(decode_instruction(&[]).is_err());
+        assert!(decode_instruction(&[u8::MAX]).is_err());
+        assert!(decode_instruction(&data[..data.len() - 1]).is_err());
+        assert!(decode_instruction(&[&data[..], &[0]].concat()).is_err());
+    }
+
+    #[test]
+    fn test_instruction_discriminants_are_stable() {
+        // the leading byte of the data of each instruction is its variant,
+        // which deployed clients rely on never changing, so new variants
+        // must only ever be appended to LandInstruction
+        let pinned = vec![
+            ("InitialiseLandPlane", 0),
+            ("InitialiseNextLandAsset", 1),
+            ("MintNextLandAsset", 2),
+            ("InitialiseLandPlaneStats", 3),
+            ("InitialiseLandPlaneIdempotent", 4),
+            ("StampCoordinateUri", 5),
+            ("InitialiseProgramConfig", 6),
+            ("TransferLand", 7),
+            ("RepairParcelCoordinate", 8),
+            ("ProcessBatch", 9),
+            ("SetupPlane", 10),
+            ("MigrateAssetsBatch", 11),
+            ("ForceSetCursor", 12),
+            ("SetParcelAttributes", 13),
+            ("MergeParcels", 14),
+            ("SetPlaneMetadata", 15),
+            ("VerifyParcelOwnership", 16),
+            ("AdminRelinkParcel", 17),
+            ("MintNextWithAttributes", 18),
+            ("LockParcel", 19),
+            ("UnlockParcel", 20),
+            ("MintNextWithDeadline", 21),
+            ("ClaimReservedParcel", 22),
+            ("CheckCoordinate", 23),
+            ("EmitParcelRange", 24),
+            ("SetMintWindow", 25),
+            ("SetPlaneTreasury", 26),
+        ];
+        let instructions = instruction_of_each_variant(&crate::id(), &Pubkey::new_unique());
+        assert_eq!(pinned.len(), instructions.len(), "every variant must be pinned");
+        for ((instruction, name), (pinned_name, discriminant)) in instructions.iter().zip(pinned) {
+            assert_eq!(pinned_name, *name);
+            assert_eq!(discriminant, instruction.data[0], "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_program_id_as_data_account() {
+        let program_id = crate::id();
+        let mut land_plane_acc = SolanaAccount::new(land_plane_minimum_balance(), LAND_PLANE_ACC_DATA_LEN, &program_id);
+        let mut rent_sysvar = rent_sysvar();
+        let instruction = initialize_land_plane(&program_id, &program_id, None, false, 0, None, MintPattern::Shell, false, 0, false, None, None, 0, None, None, None).unwrap();
+
+        // account infos are built by hand, as do_process_instruction
+        // gives every account as read-only
+        let rent_sysvar_acc_pubkey = instruction.accounts[1].pubkey;
+        let account_infos = vec![
+            AccountInfo::new(
+                &program_id,
+                false,
+                true,
+                &mut land_plane_acc.lamports,
+                &mut land_plane_acc.data,
+                &program_id,
+                false,
+                0,
+            ),
+            AccountInfo::new(
+                &rent_sysvar_acc_pubkey,
+                false,
+                false,
+                &mut rent_sysvar.lamports,
+                &mut rent_sysvar.data,
+                &rent_sysvar.owner,
+                false,
+                0,
+            ),
+        ];
+
+        //
+        // program ID given as the land plane account
+        //
+        assert_eq!(
+            Err(LandError::InvalidAccountProgramId.into()),
+            process_instruction(&program_id, &account_infos, &instruction.data),
+        );
+        assert_eq!(
+            LandPlaneVersion::Uninitialised,
+            LandPlane::from_account_info(&account_infos[0]).unwrap().version,
+        );
+    }
+
+    #[test]
+    fn test_initialise_land_plane_account() {
+        let program_id = crate::id();
+        let (_, mut program_config_acc) = program_config_account(&program_id, 0, &Pubkey::new_unique());
+        let land_plane_acc_key = Pubkey::new_unique();
+        let mut land_plane_acc = SolanaAccount::new(42, LAND_PLANE_ACC_DATA_LEN, &program_id);
+        let mut rent_sysvar = rent_sysvar();
+
+        //
+        // given account to be initialised is not rent exempt 
+        //
+        #[cfg(not(feature = "skip-rent-check"))]
+        assert_eq!(
+            Err(LandError::NotRentExempt.into()),
+            do_process_instruction(
+                initialize_land_plane(&program_id, &land_plane_acc_key, None, false, 0, None, MintPattern::Shell, false, 0, false, None, None, 0, None, None, None).unwrap(),
+                vec![&mut land_plane_acc, &mut rent_sysvar, &mut program_config_acc]
+            )
+        );
+        // correct rent
+        land_plane_acc.lamports = land_plane_minimum_balance();
+
+        //
+        // land filled at random without a coordinate ceiling
+        //
+        assert_eq!(
+            Err(LandError::RandomFillRequiresCeiling.into()),
+            do_process_instruction(
+                initialize_land_plane(&program_id, &land_plane_acc_key, None, false, 0, None, MintPattern::RandomFill, false, 0, false, None, None, 0, None, None, None).unwrap(),
+                vec![&mut land_plane_acc, &mut rent_sysvar, &mut program_config_acc]
+            )
+        );
+
+        //
+        // program config account at the wrong key
+        //
+        let mut instruction = initialize_land_plane(&program_id, &land_plane_acc_key, None, false, 0, None, MintPattern::Shell, false, 0, false, None, None, 0, None, None, None).unwrap();
+        instruction.accounts[2].pubkey = Pubkey::new_unique();
+        assert_eq!(
+            Err(LandError::InvalidProgramConfigAccKey.into()),
+            do_process_instruction(
+                instruction,
+                vec![&mut land_plane_acc, &mut rent_sysvar, &mut program_config_acc]
+            )
+        );
+
+        //
+        // program config account not yet initialised
+        //
+        let mut uninitialised_program_config_acc = SolanaAccount::new(1, PROGRAM_CONFIG_ACC_DATA_LEN, &program_id);
+        assert_eq!(
+            Err(LandError::ProgramConfigAccUninitialised.into()),
+            do_process_instruction(
+                initialize_land_plane(&program_id, &land_plane_acc_key, None, false, 0, None, MintPattern::Shell, false, 0, false, None, None, 0, None, None, None).unwrap(),
+                vec![&mut land_plane_acc, &mut rent_sysvar, &mut uninitialised_program_config_acc]
+            )
+        );
+
+        // instruction completes successfully, counting the
+        // new land plane on the program config
+        do_process_instruction(
+            initialize_land_plane(&program_id, &land_plane_acc_key, None, false, 0, None, MintPattern::Shell, false, 0, false, None, None, 0, None, None, None).unwrap(),
+            vec![&mut land_plane_acc, &mut rent_sysvar, &mut program_config_acc]
+        )
+        .unwrap();
+        assert_eq!(1, ProgramConfig::try_from_slice(&program_config_acc.data).unwrap().total_planes);
+
+        // as is each land plane initialised after it, including
+        // against a program config account allocated at V1, which
+        // does not keep the count
+        let mut second_land_plane_acc = SolanaAccount::new(land_plane_minimum_balance(), LAND_PLANE_ACC_DATA_LEN, &program_id);
+        do_process_instruction(
+            initialize_land_plane(&program_id, &Pubkey::new_unique(), None, false, 0, None, MintPattern::Shell, false, 0, false, None, None, 0, None, None, None).unwrap(),
+            vec![&mut second_land_plane_acc, &mut rent_sysvar, &mut program_config_acc]
+        )
+        .unwrap();
+        assert_eq!(2, ProgramConfig::try_from_slice(&program_config_acc.data).unwrap().total_planes);
+        let mut v1_program_config_acc = program_config_acc.clone();
+        v1_program_config_acc.data.truncate(PROGRAM_CONFIG_V1_ACC_DATA_LEN);
+        let mut third_land_plane_acc = SolanaAccount::new(land_plane_minimum_balance(), LAND_PLANE_ACC_DATA_LEN, &program_id);
+        do_process_instruction(
+            initialize_land_plane(&program_id, &Pubkey::new_unique(), None, false, 0, None, MintPattern::Shell, false, 0, false, None, None, 0, None, None, None).unwrap(),
+            vec![&mut third_land_plane_acc, &mut rent_sysvar, &mut v1_program_config_acc]
+        )
+        .unwrap();
+        assert_eq!(program_config_acc.data[..PROGRAM_CONFIG_V1_ACC_DATA_LEN], v1_program_config_acc.data[..]);
+
+        //
+        // trying to call initialise again fails, without counting it
+        //
+        assert_eq!(
+            Err(LandError::LandPlaneAccAlreadyInitialised.into()),
+            do_process_instruction(
+                initialize_land_plane(&program_id, &land_plane_acc_key, None, false, 0, None, MintPattern::Shell, false, 0, false, None, None, 0, None, None, None).unwrap(),
+                vec![&mut land_plane_acc, &mut rent_sysvar, &mut program_config_acc]
+            )
+        );
+        assert_eq!(2, ProgramConfig::try_from_slice(&program_config_acc.data).unwrap().total_planes);
+    }
+
+    #[test]
+    fn test_initialise_land_plane_account_zero_rent() {
+        let program_id = crate::id();
+        let (_, mut program_config_acc) = program_config_account(&program_id, 0, &Pubkey::new_unique());
+        let land_plane_acc_key = Pubkey::new_unique();
+        let mut land_plane_acc = SolanaAccount::new(0, LAND_PLANE_ACC_DATA_LEN, &program_id);
+
+        // rent as on a local test validator charging none
+        let mut rent_sysvar = create_account_for_test(&Rent{
+            lamports_per_byte_year: 0,
+            exemption_threshold: 0.0,
+            burn_percent: 0,
+        });
+
+        //
+        // given account to be initialised is not funded
+        //
+        #[cfg(not(feature = "skip-rent-check"))]
+        assert_eq!(
+            Err(LandError::NotRentExempt.into()),
+            do_process_instruction(
+                initialize_land_plane(&program_id, &land_plane_acc_key, None, false, 0, None, MintPattern::Shell, false, 0, false, None, None, 0, None, None, None).unwrap(),
+                vec![&mut land_plane_acc, &mut rent_sysvar, &mut program_config_acc]
+            )
+        );
+
+        // instruction completes successfully once funded
+        land_plane_acc.lamports = 1;
+        do_process_instruction(
+            initialize_land_plane(&program_id, &land_plane_acc_key, None, false, 0, None, MintPattern::Shell, false, 0, false, None, None, 0, None, None, None).unwrap(),
+            vec![&mut land_plane_acc, &mut rent_sysvar, &mut program_config_acc]
+        )
+        .unwrap();
+        assert_eq!(
+            LandPlaneVersion::V18,
+            LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap().version,
+        );
+    }
+
+    #[test]
+    fn test_initialise_land_plane_account_skip_rent_check() {
+        let program_id = crate::id();
+        let (_, mut program_config_acc) = program_config_account(&program_id, 0, &Pubkey::new_unique());
+        let land_plane_acc_key = Pubkey::new_unique();
+        let mut land_plane_acc = SolanaAccount::new(42, LAND_PLANE_ACC_DATA_LEN, &program_id);
+        let mut rent_sysvar = rent_sysvar();
+
+        //
+        // account that is not rent exempt is only accepted
+        // when built with the skip-rent-check feature
+        //
+        let result = do_process_instruction(
+            initialize_land_plane(&program_id, &land_plane_acc_key, None, false, 0, None, MintPattern::Shell, false, 0, false, None, None, 0, None, None, None).unwrap(),
+            vec![&mut land_plane_acc, &mut rent_sysvar, &mut program_config_acc]
+        );
+        if cfg!(feature = "skip-rent-check") {
+            assert_eq!(Ok(()), result);
+            assert_eq!(
+                LandPlaneVersion::V18,
+                LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap().version,
+            );
+        } else {
+            assert_eq!(Err(LandError::NotRentExempt.into()), result);
+            assert_eq!(
+                LandPlaneVersion::Uninitialised,
+                LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap().version,
+            );
+        }
+    }
+
+    #[test]
+    fn test_initialise_land_plane_account_idempotent() {
+        let program_id = crate::id();
+        let (_, mut program_config_acc) = program_config_account(&program_id, 0, &Pubkey::new_unique());
+        let land_plane_acc_key = Pubkey::new_unique();
+        let land_plane_owner_acc_key = Pubkey::new_unique();
+        let mut land_plane_acc = SolanaAccount::new(
+            land_plane_minimum_balance(),
+            LAND_PLANE_ACC_DATA_LEN,
+            &program_id,
+        );
+        let mut rent_sysvar = rent_sysvar();
+
+        // instruction completes successfully
+        do_process_instruction(
+            initialize_land_plane_idempotent(&program_id, &land_plane_acc_key, Some(10), true, 0, Some(land_plane_owner_acc_key), MintPattern::Shell, false, 0, false, None, None, 0, None, None, None).unwrap(),
+            vec![&mut land_plane_acc, &mut rent_sysvar, &mut program_config_acc]
+        )
+        .unwrap();
+        let initialised_data = land_plane_acc.data.clone();
+
+        //
+        // calling it again with the same config is a no-op
+        //
+        do_process_instruction(
+            initialize_land_plane_idempotent(&program_id, &land_plane_acc_key, Some(10), true, 0, Some(land_plane_owner_acc_key), MintPattern::Shell, false, 0, false, None, None, 0, None, None, None).unwrap(),
+            vec![&mut land_plane_acc, &mut rent_sysvar, &mut program_config_acc]
+        )
+        .unwrap();
+        assert_eq!(initialised_data, land_plane_acc.data);
+        assert_eq!(1, ProgramConfig::try_from_slice(&program_config_acc.data).unwrap().total_planes);
+
+        //
+        // calling it again with a different config fails
+        //
+        assert_eq!(
+            Err(LandError::LandPlaneConfigMismatch.into()),
+            do_process_instruction(
+                initialize_land_plane_idempotent(&program_id, &land_plane_acc_key, None, true, 0, Some(land_plane_owner_acc_key), MintPattern::Shell, false, 0, false, None, None, 0, None, None, None).unwrap(),
+                vec![&mut land_plane_acc, &mut rent_sysvar, &mut program_config_acc]
+            )
+        );
+        assert_eq!(
+            Err(LandError::LandPlaneConfigMismatch.into()),
+            do_process_instruction(
+                initialize_land_plane_idempotent(&program_id, &land_plane_acc_key, Some(10), false, 0, Some(land_plane_owner_acc_key), MintPattern::Shell, false, 0, false, None, None, 0, None, None, None).unwrap(),
+                vec![&mut land_plane_acc, &mut rent_sysvar, &mut program_config_acc]
+            )
+        );
+        assert_eq!(
+            Err(LandError::LandPlaneConfigMismatch.into()),
+            do_process_instruction(
+                initialize_land_plane_idempotent(&program_id, &land_plane_acc_key, Some(10), true, 1, Some(land_plane_owner_acc_key), MintPattern::Shell, false, 0, false, None, None, 0, None, None, None).unwrap(),
+                vec![&mut land_plane_acc, &mut rent_sysvar, &mut program_config_acc]
+            )
+        );
+        assert_eq!(
+            Err(LandError::LandPlaneConfigMismatch.into()),
+            do_process_instruction(
+                initialize_land_plane_idempotent(&program_id, &land_plane_acc_key, Some(10), true, 0, None, MintPattern::Shell, false, 0, false, None, None, 0, None, None, None).unwrap(),
+                vec![&mut land_plane_acc, &mut rent_sysvar, &mut program_config_acc]
+            )
+        );
+        assert_eq!(
+            Err(LandError::LandPlaneConfigMismatch.into()),
+            do_process_instruction(
+                initialize_land_plane_idempotent(&program_id, &land_plane_acc_key, Some(10), true, 0, Some(land_plane_owner_acc_key), MintPattern::RandomFill, false, 0, false, None, None, 0, None, None, None).unwrap(),
+                vec![&mut land_plane_acc, &mut rent_sysvar, &mut program_config_acc]
+            )
+        );
+        assert_eq!(
+            Err(LandError::LandPlaneConfigMismatch.into()),
+            do_process_instruction(
+                initialize_land_plane_idempotent(&program_id, &land_plane_acc_key, Some(10), true, 0, Some(land_plane_owner_acc_key), MintPattern::Shell, false, 250, false, None, None, 0, None, None, None).unwrap(),
+                vec![&mut land_plane_acc, &mut rent_sysvar, &mut program_config_acc]
+            )
+        );
+        assert_eq!(
+            Err(LandError::LandPlaneConfigMismatch.into()),
+            do_process_instruction(
+                initialize_land_plane_idempotent(&program_id, &land_plane_acc_key, Some(10), true, 0, Some(land_plane_owner_acc_key), MintPattern::Shell, false, 0, true, None, None, 0, None, None, None).unwrap(),
+                vec![&mut land_plane_acc, &mut rent_sysvar, &mut program_config_acc]
+            )
+        );
+        assert_eq!(
+            Err(LandError::LandPlaneConfigMismatch.into()),
+            do_process_instruction(
+                initialize_land_plane_idempotent(&program_id, &land_plane_acc_key, Some(10), true, 0, Some(land_plane_owner_acc_key), MintPattern::Shell, false, 0, false, Some(Pubkey::new_unique()), None, 0, None, None, None).unwrap(),
+                vec![&mut land_plane_acc, &mut rent_sysvar, &mut program_config_acc]
+            )
+        );
+        assert_eq!(
+            Err(LandError::LandPlaneConfigMismatch.into()),
+            do_process_instruction(
+                initialize_land_plane_idempotent(&program_id, &land_plane_acc_key, Some(10), true, 0, Some(land_plane_owner_acc_key), MintPattern::Shell, false, 0, false, None, Some(100), 0, None, None, None).unwrap(),
+                vec![&mut land_plane_acc, &mut rent_sysvar, &mut program_config_acc]
+            )
+        );
+        assert_eq!(initialised_data, land_plane_acc.data);
+    }
+
+    #[test]
+    fn test_land_plane_compact_addresses() {
+        let program_id = crate::id();
+        let (_, mut program_config_acc) = program_config_account(&program_id, 0, &Pubkey::new_unique());
+        let land_plane_acc_key = Pubkey::new_unique();
+        let mut land_plane_acc = SolanaAccount::new(
+            land_plane_minimum_balance(),
+            LAND_PLANE_ACC_DATA_LEN,
+            &program_id,
+        );
+        let mut rent_sysvar = rent_sysvar();
+
+        //
+        // compact addresses without a coordinate ceiling
+        //
+        assert_eq!(
+            Err(LandError::CompactAddressesRequireCeiling.into()),
+            do_process_instruction(
+                initialize_land_plane(&program_id, &land_plane_acc_key, None, false, 0, None, MintPattern::Shell, false, 0, true, None, None, 0, None, None, None).unwrap(),
+                vec![&mut land_plane_acc, &mut rent_sysvar, &mut program_config_acc]
+            )
+        );
+
+        // instruction completes successfully, with co-ordinates
+        // up to the ceiling held in a single byte
+        do_process_instruction(
+            initialize_land_plane(&program_id, &land_plane_acc_key, Some(200), false, 0, None, MintPattern::Shell, false, 0, true, None, None, 0, None, None, None).unwrap(),
+            vec![&mut land_plane_acc, &mut rent_sysvar, &mut program_config_acc]
+        )
+        .unwrap();
+        let land_plane = LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap();
+        assert_eq!(1, land_plane.addr_width);
+
+        // the land asset account of the first piece of land
+        // is derived from 1-byte co-ordinates
+        let rent_payer_acc_pubkey = Pubkey::new_unique();
+        let mut rent_payer_acc = SolanaAccount::new(1_000_000, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+        let (land_asset_acc_pubkey, _) = Pubkey::find_program_address(
+            &[LAND_ASSET_ACC_PREFIX.as_bytes(), land_plane_acc_key.as_ref(), &[0], &[0]],
+            &program_id,
+        );
+        let mut land_asset_acc = SolanaAccount::new(
+            Rent::default().minimum_balance(LAND_ASSET_ACC_DATA_LEN),
+            LAND_ASSET_ACC_DATA_LEN,
+            &program_id,
+        );
+
+        //
+        // land asset account derived from 8-byte co-ordinates
+        //
+        assert_eq!(
+            Err(LandError::InvalidLandAssetAccKey.into()),
+            do_process_instruction(
+                initialize_next_land_asset(
+                    &program_id,
+                    &rent_payer_acc_pubkey,
+                    &find_land_asset_address(&program_id, &land_plane_acc_key, Coordinate { x: 0, z: 0 }, FULL_ADDR_WIDTH).0,
+                    &land_plane_acc_key,
+                    None,
+                ).unwrap(),
+                vec![
+                    &mut rent_payer_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+        );
+
+        // instruction completes successfully
+        do_process_instruction(
+            initialize_next_land_asset(
+                &program_id,
+                &rent_payer_acc_pubkey,
+                &land_asset_acc_pubkey,
+                &land_plane_acc_key,
+                None,
+            ).unwrap(),
+            vec![
+                &mut rent_payer_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut rent_sysvar,
+                &mut system_program_acc,
+                ]
+        )
+        .unwrap();
+        assert_eq!(
+            Coordinate { x: 0, z: 0 },
+            LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap().coordinate,
+        );
+    }
+
+    #[test]
+    fn test_initialise_land_asset_account() {
+        let program_id = crate::id();
+
+        let rent_payer_acc_pubkey = Pubkey::new_unique();
+        let mut rent_payer_acc = SolanaAccount::new(1_000_000, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+        let mut rent_sysvar = rent_sysvar();
+
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_initialized_plane(2, 1, 2);
+
+        // land asset account for the next piece of land, not yet initialised
+        let (land_asset_acc_pubkey, _) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+        let mut land_asset_acc = SolanaAccount::new(
+            Rent::default().minimum_balance(LAND_ASSET_ACC_DATA_LEN),
+            LAND_ASSET_ACC_DATA_LEN,
+            &program_id,
+        );
+
+        //
+        // system program is not genuine
+        //
+        let mut instruction = initialize_next_land_asset(
+            &program_id,
+            &rent_payer_acc_pubkey,
+            &land_asset_acc_pubkey,
+            &land_plane_acc_pubkey,
+            None,
+        ).unwrap();
+        instruction.accounts[4].pubkey = Pubkey::new_unique();
+        assert_eq!(
+            Err(LandError::InvalidSystemProgram.into()),
+            do_process_instruction(
+                instruction,
+                vec![
+                    &mut rent_payer_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+        );
+
+        //
+        // land asset account is not that of the next piece of land
+        //
+        assert_eq!(
+            Err(LandError::InvalidLandAssetAccKey.into()),
+            do_process_instruction(
+                initialize_next_land_asset(
+                    &program_id,
+                    &rent_payer_acc_pubkey,
+                    &Pubkey::new_unique(),
+                    &land_plane_acc_pubkey,
+                    None,
+                ).unwrap(),
+                vec![
+                    &mut rent_payer_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+        );
+
+        // instruction completes successfully
+        do_process_instruction(
+            initialize_next_land_asset(
+                &program_id,
+                &rent_payer_acc_pubkey,
+                &land_asset_acc_pubkey,
+                &land_plane_acc_pubkey,
+                None,
+            ).unwrap(),
+            vec![
+                &mut rent_payer_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut rent_sysvar,
+                &mut system_program_acc,
+                ]
+        )
+        .unwrap();
+        let (_, bump) = find_land_asset_address(&program_id, &land_plane_acc_pubkey, Coordinate { x: 2, z: 1 }, FULL_ADDR_WIDTH);
+        assert_eq!(
+            LandAsset{
+                version: LandAssetVersion::V8,
+                land_plane: land_plane_acc_pubkey,
+                coordinate: Coordinate { x: 2, z: 1 },
+                bump,
+                ..initialized_asset(&Pubkey::default())
+            },
+            LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap(),
+        );
+
+        //
+        // trying to call initialise again fails
+        //
+        assert_eq!(
+            Err(LandError::LandAssetAccAlreadyInitialised.into()),
+            do_process_instruction(
+                initialize_next_land_asset(
+                    &program_id,
+                    &rent_payer_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    None,
+                ).unwrap(),
+                vec![
+                    &mut rent_payer_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+        );
+    }
+
+    #[test]
+    fn test_initialise_land_asset_with_authority() {
+        let program_id = crate::id();
+
+        let rent_payer_acc_pubkey = Pubkey::new_unique();
+        let mut rent_payer_acc = SolanaAccount::new(1_000_000, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+        let mut rent_sysvar = rent_sysvar();
+
+        // land plane with an owner, who authorises while the payer funds rent
+        let owner_acc_pubkey = Pubkey::new_unique();
+        let mut owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let land_plane = LandPlane{ owner: Some(owner_acc_pubkey), ..initialized_plane(2, 1, 2) };
+        let mut land_plane_acc = fake_plane_account(&land_plane);
+
+        let (land_asset_acc_pubkey, _) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+        let new_land_asset_acc = || SolanaAccount::new(
+            Rent::default().minimum_balance(LAND_ASSET_ACC_DATA_LEN),
+            LAND_ASSET_ACC_DATA_LEN,
+            &program_id,
+        );
+        let mut land_asset_acc = new_land_asset_acc();
+
+        let instruction = |authority_acc_pubkey: &Pubkey| initialize_next_land_asset(
+            &program_id,
+            &rent_payer_acc_pubkey,
+            &land_asset_acc_pubkey,
+            &land_plane_acc_pubkey,
+            Some(authority_acc_pubkey),
+        ).unwrap();
+        assert_eq!(
+            AccountMeta::new_readonly(owner_acc_pubkey, true),
+            instruction(&owner_acc_pubkey).accounts[5],
+        );
+
+        //
+        // authority has not signed
+        //
+        let mut unsigned = instruction(&owner_acc_pubkey);
+        unsigned.accounts[5].is_signer = false;
+        assert_eq!(
+            Err(LandError::MissingSignature.into()),
+            do_process_instruction(
+                unsigned,
+                vec![
+                    &mut rent_payer_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    &mut owner_acc,
+                    ]
+            )
+        );
+
+        //
+        // authority is not the owner of the land plane
+        //
+        let other_acc_pubkey = Pubkey::new_unique();
+        let mut other_acc = SolanaAccount::new(1, 0, &system_program::id());
+        assert_eq!(
+            Err(LandError::LandPlaneOwnerMismatch.into()),
+            do_process_instruction(
+                instruction(&other_acc_pubkey),
+                vec![
+                    &mut rent_payer_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    &mut other_acc,
+                    ]
+            )
+        );
+
+        // instruction completes successfully with the payer and the
+        // owner as authority both signing
+        do_process_instruction(
+            instruction(&owner_acc_pubkey),
+            vec![
+                &mut rent_payer_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut rent_sysvar,
+                &mut system_program_acc,
+                &mut owner_acc,
+                ]
+        )
+        .unwrap();
+        assert_eq!(
+            land_plane_acc_pubkey,
+            LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap().land_plane,
+        );
+
+        // and with the payer alone signing, as before
+        let mut land_asset_acc = new_land_asset_acc();
+        do_process_instruction(
+            initialize_next_land_asset(
+                &program_id,
+                &rent_payer_acc_pubkey,
+                &land_asset_acc_pubkey,
+                &land_plane_acc_pubkey,
+                None,
+            ).unwrap(),
+            vec![
+                &mut rent_payer_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut rent_sysvar,
+                &mut system_program_acc,
+                ]
+        )
+        .unwrap();
+        assert_eq!(
+            land_plane_acc_pubkey,
+            LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap().land_plane,
+        );
+    }
+
+    #[test]
+    fn test_initialise_land_asset_max_supply() {
+        let program_id = crate::id();
+
+        let rent_payer_acc_pubkey = Pubkey::new_unique();
+        let mut rent_payer_acc = SolanaAccount::new(1_000_000, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+        let mut rent_sysvar = rent_sysvar();
+
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+
+        // land plane with a max supply of 5, or with a coordinate ceiling
+        // of 1, at the last piece of land that can be minted and then
+        // just past it
+        for (land_plane, expected) in vec![
+            (LandPlane{ max_supply: Some(5), ..initialized_plane(2, 0, 2) }, Ok(())),
+            (
+                LandPlane{ max_supply: Some(5), ..initialized_plane(2, 1, 2) },
+                Err(LandError::CoordinateOutOfBounds.into()),
+            ),
+            (LandPlane{ coordinate_ceiling: Some(1), ..initialized_plane(0, 1, 1) }, Ok(())),
+            (
+                LandPlane{ coordinate_ceiling: Some(1), ..initialized_plane(2, 0, 2) },
+                Err(LandError::CoordinateOutOfBounds.into()),
+            ),
+        ] {
+            let mut land_plane_acc = fake_plane_account(&land_plane);
+            let (land_asset_acc_pubkey, _) =
+                next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+            let mut land_asset_acc = SolanaAccount::new(
+                Rent::default().minimum_balance(LAND_ASSET_ACC_DATA_LEN),
+                LAND_ASSET_ACC_DATA_LEN,
+                &program_id,
+            );
+            assert_eq!(
+                expected,
+                do_process_instruction(
+                    initialize_next_land_asset(
+                        &program_id,
+                        &rent_payer_acc_pubkey,
+                        &land_asset_acc_pubkey,
+                        &land_plane_acc_pubkey,
+                        None,
+                    ).unwrap(),
+                    vec![
+                        &mut rent_payer_acc,
+                        &mut land_asset_acc,
+                        &mut land_plane_acc,
+                        &mut rent_sysvar,
+                        &mut system_program_acc,
+                        ]
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_mint_next() {
+        let program_id = crate::id();
+
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+
+        let protocol_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut protocol_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let (program_config_acc_pubkey, mut program_config_acc) =
+            program_config_account(&program_id, 0, &protocol_treasury_acc_pubkey);
+        let mut land_asset_by_mint_acc = land_asset_by_mint_account();
+        let mut rent_sysvar = rent_sysvar();
+
+        let nft_assoc_token_acc_owner_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc_owner_acc = SolanaAccount::new(1, 0, &system_program::id());        
+
+        let land_asset_acc_wrong_pubkey = Pubkey::new_unique();
+        let mut land_asset_acc = SolanaAccount::new(1, LAND_ASSET_ACC_DATA_LEN, &system_program::id());
+
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = SolanaAccount::new(42, LAND_PLANE_ACC_DATA_LEN, &program_id);
+
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = SolanaAccount::new(1, 0, &system_program::id());                
+
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut nft_mint_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let (land_plane_stats_acc_pubkey, mut land_plane_stats_acc) =
+            land_plane_stats_account(&program_id, &land_plane_acc_pubkey);
+
+        //
+        // land plane account not initialised
+        //
+        assert_eq!(
+            Err(LandError::LandPlaneAccUninitialised.into()),
+            do_process_instruction(
+                mint_next_land_asset(
+                    &program_id,
+                    &payer_acc_pubkey,
+                    &nft_assoc_token_acc_owner_acc_pubkey,
+                    &land_asset_acc_wrong_pubkey,
+                    &land_plane_acc_pubkey,
+                    &nft_assoc_token_acc_pubkey,
+                    &nft_mint_acc_pubkey,
+                    &land_plane_stats_acc_pubkey,
+                    &program_config_acc_pubkey,
+                    &protocol_treasury_acc_pubkey,
+                    None,
+                    None,
+                ).unwrap(),
+                vec![
+                    &mut payer_acc,
+                    &mut nft_assoc_token_acc_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    ]
+            )
+        );
+        
+        // initialise land plane account
+        let land_plane = initialized_plane(100, 21, 100);
+        land_plane_acc = fake_plane_account(&land_plane);
+
+        //
+        // invalid land asset acc key
+        //
+        assert_eq!(
+            Err(LandError::InvalidLandAssetAccKey.into()),
+            do_process_instruction(
+                mint_next_land_asset(
+                    &program_id,
+                    &payer_acc_pubkey,
+                    &nft_assoc_token_acc_owner_acc_pubkey,
+                    &land_asset_acc_wrong_pubkey,
+                    &land_plane_acc_pubkey,
+                    &nft_assoc_token_acc_pubkey,
+                    &nft_mint_acc_pubkey,
+                    &land_plane_stats_acc_pubkey,
+                    &program_config_acc_pubkey,
+                    &protocol_treasury_acc_pubkey,
+                    None,
+                    None,
+                ).unwrap(),
+                vec![
+                    &mut payer_acc,
+                    &mut nft_assoc_token_acc_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    ]
+            )
+        );
+
+        // generate correct land asset account for next piece of land
+        let (land_asset_acc_pubkey, _) = Pubkey::find_program_address(
+            &[
+                LAND_ASSET_ACC_PREFIX.as_bytes(),
+                land_plane_acc_pubkey.as_ref(),
+                &land_plane.next_x.to_le_bytes(),
+                &land_plane.next_z.to_le_bytes(),
+            ],
+            &program_id,
+        );
+
+        //
+        // land asset account not initialised
+        //
+        assert_eq!(
+            Err(LandError::LandAssetAccUninitialised.into()),
+            do_process_instruction(
+                mint_next_land_asset(
+                    &program_id,
+                    &payer_acc_pubkey,
+                    &nft_assoc_token_acc_owner_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    &nft_assoc_token_acc_pubkey,
+                    &nft_mint_acc_pubkey,
+                    &land_plane_stats_acc_pubkey,
+                    &program_config_acc_pubkey,
+                    &protocol_treasury_acc_pubkey,
+                    None,
+                    None,
+                ).unwrap(),
+                vec![
+                    &mut payer_acc,
+                    &mut nft_assoc_token_acc_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    ]
+            )
+        );
+
+        //
+        // land asset account initialised for another land plane
+        //
+        let (_, land_asset_acc_bump) = land_plane.land_asset_address(
+            &program_id,
+            &land_plane_acc_pubkey,
+            Coordinate { x: land_plane.next_x, z: land_plane.next_z },
+        );
+        let mut land_asset_acc = fake_asset_account(&LandAsset{
+            land_plane: Pubkey::new_unique(),
+            bump: land_asset_acc_bump,
+            ..initialized_asset(&Pubkey::default())
+        });
+        assert_eq!(
+            Err(LandError::PlaneMismatch.into()),
+            do_process_instruction(
+                mint_next_land_asset(
+                    &program_id,
+                    &payer_acc_pubkey,
+                    &nft_assoc_token_acc_owner_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    &nft_assoc_token_acc_pubkey,
+                    &nft_mint_acc_pubkey,
+                    &land_plane_stats_acc_pubkey,
+                    &program_config_acc_pubkey,
+                    &protocol_treasury_acc_pubkey,
+                    None,
+                    None,
+                ).unwrap(),
+                vec![
+                    &mut payer_acc,
+                    &mut nft_assoc_token_acc_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    ]
+            )
+        );
+    }
+
+    #[test]
+    fn test_mint_next_require_frozen_mint() {
+        let program_id = crate::id();
+
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+
+        let protocol_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut protocol_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let (program_config_acc_pubkey, mut program_config_acc) =
+            program_config_account(&program_id, 0, &protocol_treasury_acc_pubkey);
+        let mut land_asset_by_mint_acc = land_asset_by_mint_account();
+        let mut rent_sysvar = rent_sysvar();
+
+        let nft_assoc_token_acc_owner_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut nft_mint_acc = SolanaAccount::new(1, Mint::LEN, &spl_token::id());
+
+        let (land_plane_stats_acc_pubkey, mut land_plane_stats_acc) =
+            land_plane_stats_account(&program_id, &land_plane_acc_pubkey);
+
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = nft_token_account(
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+
+        // initialise land plane account requiring a frozen mint
+        let land_plane = LandPlane{
+            require_frozen_mint: true,
+            ..initialized_plane(3, 1, 3)
+        };
+        let mut land_plane_acc = fake_plane_account(&land_plane);
+
+        // initialise land asset account for next piece of land
+        let (land_asset_acc_pubkey, mut land_asset_acc) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+
+        //
+        // nft mint authority still active
+        //
+        Mint{
+            mint_authority: COption::Some(Pubkey::new_unique()),
+            supply: 1,
+            decimals: 0,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        }.pack_into_slice(&mut nft_mint_acc.data);
+        assert_eq!(
+            Err(LandError::MintAuthorityNotDisabled.into()),
+            do_process_instruction(
+                mint_next_land_asset(
+                    &program_id,
+                    &payer_acc_pubkey,
+                    &nft_assoc_token_acc_owner_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    &nft_assoc_token_acc_pubkey,
+                    &nft_mint_acc_pubkey,
+                    &land_plane_stats_acc_pubkey,
+                    &program_config_acc_pubkey,
+                    &protocol_treasury_acc_pubkey,
+                    None,
+                    None,
+                ).unwrap(),
+                vec![
+                    &mut payer_acc,
+                    &mut nft_assoc_token_acc_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    ]
+            )
+        );
+
+        //
+        // nft mint authority disabled
+        //
+        Mint{
+            mint_authority: COption::None,
+            supply: 1,
+            decimals: 0,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        }.pack_into_slice(&mut nft_mint_acc.data);
+        do_process_instruction(
+            mint_next_land_asset(
+                &program_id,
+                &payer_acc_pubkey,
+                &nft_assoc_token_acc_owner_acc_pubkey,
+                &land_asset_acc_pubkey,
+                &land_plane_acc_pubkey,
+                &nft_assoc_token_acc_pubkey,
+                &nft_mint_acc_pubkey,
+                &land_plane_stats_acc_pubkey,
+                &program_config_acc_pubkey,
+                &protocol_treasury_acc_pubkey,
+                None,
+                None,
+            ).unwrap(),
+            vec![
+                &mut payer_acc,
+                &mut nft_assoc_token_acc_owner_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut nft_assoc_token_acc,
+                &mut nft_mint_acc,
+                &mut land_plane_stats_acc,
+                &mut system_program_acc,
+                &mut program_config_acc,
+                &mut protocol_treasury_acc,
+                &mut land_asset_by_mint_acc,
+                &mut rent_sysvar,
+                ]
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_mint_next_token_account_frozen() {
+        let program_id = crate::id();
+
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+
+        let protocol_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut protocol_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let (program_config_acc_pubkey, mut program_config_acc) =
+            program_config_account(&program_id, 0, &protocol_treasury_acc_pubkey);
+        let mut land_asset_by_mint_acc = land_asset_by_mint_account();
+        let mut rent_sysvar = rent_sysvar();
+
+        let nft_assoc_token_acc_owner_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut nft_mint_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let (land_plane_stats_acc_pubkey, mut land_plane_stats_acc) =
+            land_plane_stats_account(&program_id, &land_plane_acc_pubkey);
+
+        // nft holding token account has been frozen
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = nft_token_account(
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Frozen,
+        );
+
+        // initialise land plane account
+        let land_plane = initialized_plane(0, 0, 0);
+        let mut land_plane_acc = fake_plane_account(&land_plane);
+
+        // initialise land asset account for next piece of land
+        let (land_asset_acc_pubkey, mut land_asset_acc) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+
+        assert_eq!(
+            Err(LandError::TokenAccountFrozen.into()),
+            do_process_instruction(
+                mint_next_land_asset(
+                    &program_id,
+                    &payer_acc_pubkey,
+                    &nft_assoc_token_acc_owner_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    &nft_assoc_token_acc_pubkey,
+                    &nft_mint_acc_pubkey,
+                    &land_plane_stats_acc_pubkey,
+                    &program_config_acc_pubkey,
+                    &protocol_treasury_acc_pubkey,
+                    None,
+                    None,
+                ).unwrap(),
+                vec![
+                    &mut payer_acc,
+                    &mut nft_assoc_token_acc_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    ]
+            )
+        );
+    }
+
+    #[test]
+    fn test_mint_next_failure_keeps_target() {
+        let program_id = crate::id();
+
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+
+        let protocol_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut protocol_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let (program_config_acc_pubkey, mut program_config_acc) =
+            program_config_account(&program_id, 0, &protocol_treasury_acc_pubkey);
+        let mut land_asset_by_mint_acc = land_asset_by_mint_account();
+        let mut rent_sysvar = rent_sysvar();
+
+        let nft_assoc_token_acc_owner_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut nft_mint_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let (land_plane_stats_acc_pubkey, mut land_plane_stats_acc) =
+            land_plane_stats_account(&program_id, &land_plane_acc_pubkey);
+
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = nft_token_account(
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Frozen,
+        );
+
+        // initialise land plane account
+        let land_plane = initialized_plane(3, 1, 3);
+        let mut land_plane_acc = fake_plane_account(&land_plane);
+
+        // initialise land asset account for next piece of land
+        let (land_asset_acc_pubkey, mut land_asset_acc) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+
+        //
+        // failed mint leaves the initialised asset as the plane's next target
+        //
+        assert_eq!(
+            Err(LandError::TokenAccountFrozen.into()),
+            do_process_instruction(
+                mint_next_land_asset(
+                    &program_id,
+                    &payer_acc_pubkey,
+                    &nft_assoc_token_acc_owner_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    &nft_assoc_token_acc_pubkey,
+                    &nft_mint_acc_pubkey,
+                    &land_plane_stats_acc_pubkey,
+                    &program_config_acc_pubkey,
+                    &protocol_treasury_acc_pubkey,
+                    None,
+                    None,
+                ).unwrap(),
+                vec![
+                    &mut payer_acc,
+                    &mut nft_assoc_token_acc_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    ]
+            )
+        );
+        let unchanged_plane = LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap();
+        assert_eq!(land_plane, unchanged_plane);
+        assert!(unchanged_plane.is_current_target(
+            &program_id,
+            &land_plane_acc_pubkey,
+            &land_asset_acc_pubkey,
+        ));
+        assert_eq!(
+            Pubkey::default(),
+            LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap().mint_pubkey,
+        );
+
+        //
+        // retried mint binds the same asset
+        //
+        nft_assoc_token_acc = nft_token_account(
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+        do_process_instruction(
+            mint_next_land_asset(
+                &program_id,
+                &payer_acc_pubkey,
+                &nft_assoc_token_acc_owner_acc_pubkey,
+                &land_asset_acc_pubkey,
+                &land_plane_acc_pubkey,
+                &nft_assoc_token_acc_pubkey,
+                &nft_mint_acc_pubkey,
+                &land_plane_stats_acc_pubkey,
+                &program_config_acc_pubkey,
+                &protocol_treasury_acc_pubkey,
+                None,
+                None,
+            ).unwrap(),
+            vec![
+                &mut payer_acc,
+                &mut nft_assoc_token_acc_owner_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut nft_assoc_token_acc,
+                &mut nft_mint_acc,
+                &mut land_plane_stats_acc,
+                &mut system_program_acc,
+                &mut program_config_acc,
+                &mut protocol_treasury_acc,
+                &mut land_asset_by_mint_acc,
+                &mut rent_sysvar,
+                ]
+        )
+        .unwrap();
+        assert_eq!(
+            nft_mint_acc_pubkey,
+            LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap().mint_pubkey,
+        );
+        assert!(!LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap().is_current_target(
+            &program_id,
+            &land_plane_acc_pubkey,
+            &land_asset_acc_pubkey,
+        ));
+    }
+
+    #[test]
+    fn test_mint_next_race_for_same_target() {
+        let program_id = crate::id();
+
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+
+        let protocol_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut protocol_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let (program_config_acc_pubkey, mut program_config_acc) =
+            program_config_account(&program_id, 0, &protocol_treasury_acc_pubkey);
+        let mut rent_sysvar = rent_sysvar();
+
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let (land_plane_stats_acc_pubkey, mut land_plane_stats_acc) =
+            land_plane_stats_account(&program_id, &land_plane_acc_pubkey);
+
+        // initialise land plane account
+        let land_plane = initialized_plane(2, 1, 2);
+        let mut land_plane_acc = fake_plane_account(&land_plane);
+
+        // initialise land asset account for next piece of land, which
+        // both users read as the land plane's current target
+        let (land_asset_acc_pubkey, mut land_asset_acc) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+
+        // each user holds their own nft
+        let mut users = vec![];
+        for _ in 0..2 {
+            let owner_acc_pubkey = Pubkey::new_unique();
+            let nft_mint_acc_pubkey = Pubkey::new_unique();
+            users.push((
+                owner_acc_pubkey,
+                SolanaAccount::new(1, 0, &system_program::id()),
+                nft_mint_acc_pubkey,
+                SolanaAccount::new(1, 0, &system_program::id()),
+                Pubkey::new_unique(),
+                nft_token_account(&owner_acc_pubkey, &nft_mint_acc_pubkey, AccountState::Initialized),
+                land_asset_by_mint_account(),
+            ));
+        }
+
+        let mut results = vec![];
+        for (
+            owner_acc_pubkey,
+            owner_acc,
+            nft_mint_acc_pubkey,
+            nft_mint_acc,
+            nft_assoc_token_acc_pubkey,
+            nft_assoc_token_acc,
+            land_asset_by_mint_acc,
+        ) in users.iter_mut() {
+            results.push(do_process_instruction(
+                mint_next_land_asset(
+                    &program_id,
+                    &payer_acc_pubkey,
+                    owner_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    nft_assoc_token_acc_pubkey,
+                    nft_mint_acc_pubkey,
+                    &land_plane_stats_acc_pubkey,
+                    &program_config_acc_pubkey,
+                    &protocol_treasury_acc_pubkey,
+                    None,
+                    None,
+                ).unwrap(),
+                vec![
+                    &mut payer_acc,
+                    owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    nft_assoc_token_acc,
+                    nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    ]
+            ));
+        }
+
+        //
+        // first mint to land wins the land
+        //
+        assert_eq!(Ok(()), results[0]);
+        assert_eq!(
+            users[0].2,
+            LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap().mint_pubkey,
+        );
+
+        //
+        // and the second is told that the land has been claimed
+        //
+        assert_eq!(Err(LandError::ParcelAlreadyClaimed.into()), results[1]);
+
+        //
+        // whereas an account that was never the land plane's target is
+        // still an invalid land asset account
+        //
+        let other_land_asset_acc_pubkey = Pubkey::new_unique();
+        let mut other_land_asset_acc = fake_asset_account(&LandAsset{
+            land_plane: land_plane_acc_pubkey,
+            coordinate: Coordinate { x: 0, z: 0 },
+            ..initialized_asset(&Pubkey::new_unique())
+        });
+        let (
+            owner_acc_pubkey,
+            owner_acc,
+            nft_mint_acc_pubkey,
+            nft_mint_acc,
+            nft_assoc_token_acc_pubkey,
+            nft_assoc_token_acc,
+            land_asset_by_mint_acc,
+        ) = &mut users[1];
+        assert_eq!(
+            Err(LandError::InvalidLandAssetAccKey.into()),
+            do_process_instruction(
+                mint_next_land_asset(
+                    &program_id,
+                    &payer_acc_pubkey,
+                    owner_acc_pubkey,
+                    &other_land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    nft_assoc_token_acc_pubkey,
+                    nft_mint_acc_pubkey,
+                    &land_plane_stats_acc_pubkey,
+                    &program_config_acc_pubkey,
+                    &protocol_treasury_acc_pubkey,
+                    None,
+                    None,
+                ).unwrap(),
+                vec![
+                    &mut payer_acc,
+                    owner_acc,
+                    &mut other_land_asset_acc,
+                    &mut land_plane_acc,
+                    nft_assoc_token_acc,
+                    nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    ]
+            )
+        );
+    }
+
+    #[test]
+    fn test_setup_plane() {
+        let program_id = crate::id();
+
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(1_000_000_000, 0, &system_program::id());
+
+        // new land plane account, as yet unfunded
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = SolanaAccount::new(0, LAND_PLANE_ACC_DATA_LEN, &program_id);
+
+        let (land_plane_stats_acc_pubkey, _) = Pubkey::find_program_address(
+            &[
+                LAND_PLANE_STATS_ACC_PREFIX.as_bytes(),
+                land_plane_acc_pubkey.as_ref(),
+            ],
+            &program_id,
+        );
+        let mut land_plane_stats_acc = SolanaAccount::new(0, LAND_PLANE_STATS_ACC_DATA_LEN, &program_id);
+
+        let mut rent_sysvar = rent_sysvar();
+        let mut system_program_acc = SolanaAccount::default();
+        let (_, mut program_config_acc) = program_config_account(&program_id, 0, &Pubkey::new_unique());
+
+        let instruction = setup_plane(
+            &program_id,
+            &payer_acc_pubkey,
+            &land_plane_acc_pubkey,
+            &land_plane_stats_acc_pubkey,
+            Some(10),
+            false,
+            5_000,
+            None,
+            MintPattern::Shell,
+            false,
+            0,
+            false,
+            None,
+            None,
+            0,
+            None,
+            None,
+            None,
+        ).unwrap();
+
+        //
+        // land plane account has not signed
+        //
+        let mut unsigned_instruction = instruction.clone();
+        unsigned_instruction.accounts[1].is_signer = false;
+        assert_eq!(
+            Err(LandError::MissingSignature.into()),
+            do_process_instruction(
+                unsigned_instruction,
+                vec![
+                    &mut payer_acc,
+                    &mut land_plane_acc,
+                    &mut land_plane_stats_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    ]
+            )
+        );
+
+        //
+        // payer cannot fund the land plane account
+        //
+        let mut underfunded_payer_acc =
+            SolanaAccount::new(land_plane_minimum_balance() - 1, 0, &system_program::id());
+        assert_eq!(
+            Err(LandError::InsufficientRentFunds.into()),
+            do_process_instruction(
+                instruction.clone(),
+                vec![
+                    &mut underfunded_payer_acc,
+                    &mut land_plane_acc,
+                    &mut land_plane_stats_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    ]
+            )
+        );
+
+        // instruction completes successfully
+        do_process_instruction(
+            instruction.clone(),
+            vec![
+                &mut payer_acc,
+                &mut land_plane_acc,
+                &mut land_plane_stats_acc,
+                &mut rent_sysvar,
+                &mut system_program_acc,
+                &mut program_config_acc,
+                ]
+        )
+        .unwrap();
+
+        // the land plane is funded and initialised with the given config
+        assert_eq!(land_plane_minimum_balance(), land_plane_acc.lamports);
+        assert_eq!(
+            LandPlane{
+                coordinate_ceiling: Some(10),
+                mint_fee: 5_000,
+                ..initialized_plane(0, 0, 0)
+            },
+            LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap(),
+        );
+
+        // as are its stats
+        assert_eq!(
+            Rent::default().minimum_balance(LAND_PLANE_STATS_ACC_DATA_LEN),
+            land_plane_stats_acc.lamports,
+        );
+        assert_eq!(
+            initialized_plane_stats(),
+            LandPlaneStats::try_from_slice(&land_plane_stats_acc.data).unwrap(),
+        );
+
+        // and the land plane is counted on the program config
+        assert_eq!(1, ProgramConfig::try_from_slice(&program_config_acc.data).unwrap().total_planes);
+
+        //
+        // trying to set up the land plane again fails
+        //
+        assert_eq!(
+            Err(LandError::LandPlaneAccAlreadyInitialised.into()),
+            do_process_instruction(
+                instruction,
+                vec![
+                    &mut payer_acc,
+                    &mut land_plane_acc,
+                    &mut land_plane_stats_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    ]
+            )
+        );
+    }
+
+    #[test]
+    fn test_initialise_land_plane_stats() {
+        let program_id = crate::id();
+
+        let rent_payer_acc_pubkey = Pubkey::new_unique();
+        let mut rent_payer_acc = SolanaAccount::new(1_000_000_000, 0, &system_program::id());
+
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = SolanaAccount::new(42, LAND_PLANE_ACC_DATA_LEN, &program_id);
+
+        let (land_plane_stats_acc_pubkey, _) = Pubkey::find_program_address(
+            &[
+                LAND_PLANE_STATS_ACC_PREFIX.as_bytes(),
+                land_plane_acc_pubkey.as_ref(),
+            ],
+            &program_id,
+        );
+        let mut land_plane_stats_acc = SolanaAccount::new(
+            Rent::default().minimum_balance(LAND_PLANE_STATS_ACC_DATA_LEN),
+            LAND_PLANE_STATS_ACC_DATA_LEN,
+            &program_id,
+        );
+
+        let mut rent_sysvar = rent_sysvar();
+        let mut system_program_acc = SolanaAccount::default();
+
+        //
+        // land plane account not initialised
+        //
+        assert_eq!(
+            Err(LandError::LandPlaneAccUninitialised.into()),
+            do_process_instruction(
+                initialize_land_plane_stats(
+                    &program_id,
+                    &rent_payer_acc_pubkey,
+                    &land_plane_stats_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                ).unwrap(),
+                vec![
+                    &mut rent_payer_acc,
+                    &mut land_plane_stats_acc,
+                    &mut land_plane_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+        );
+
+        // initialise land plane account
+        land_plane_acc = fake_initialized_plane(0, 0, 0);
+
+        //
+        // invalid land plane stats acc key
+        //
+        assert_eq!(
+            Err(LandError::InvalidLandPlaneStatsAccKey.into()),
+            do_process_instruction(
+                initialize_land_plane_stats(
+                    &program_id,
+                    &rent_payer_acc_pubkey,
+                    &Pubkey::new_unique(),
+                    &land_plane_acc_pubkey,
+                ).unwrap(),
+                vec![
+                    &mut rent_payer_acc,
+                    &mut land_plane_stats_acc,
+                    &mut land_plane_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+        );
+
+        // instruction completes successfully
+        do_process_instruction(
+            initialize_land_plane_stats(
+                &program_id,
+                &rent_payer_acc_pubkey,
+                &land_plane_stats_acc_pubkey,
+                &land_plane_acc_pubkey,
+            ).unwrap(),
+            vec![
+                &mut rent_payer_acc,
+                &mut land_plane_stats_acc,
+                &mut land_plane_acc,
+                &mut rent_sysvar,
+                &mut system_program_acc,
+                ]
+        )
+        .unwrap();
+        assert_eq!(
+            LandPlaneStatsVersion::V1,
+            LandPlaneStats::try_from_slice(&land_plane_stats_acc.data).unwrap().version,
+        );
+
+        //
+        // trying to call initialise again fails
+        //
+        assert_eq!(
+            Err(LandError::LandPlaneStatsAccAlreadyInitialised.into()),
+            do_process_instruction(
+                initialize_land_plane_stats(
+                    &program_id,
+                    &rent_payer_acc_pubkey,
+                    &land_plane_stats_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                ).unwrap(),
+                vec![
+                    &mut rent_payer_acc,
+                    &mut land_plane_stats_acc,
+                    &mut land_plane_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+        );
+    }
+
+    #[test]
+    fn test_mint_next_updates_land_plane_stats() {
+        let program_id = crate::id();
+
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+
+        let protocol_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut protocol_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let (program_config_acc_pubkey, mut program_config_acc) =
+            program_config_account(&program_id, 0, &protocol_treasury_acc_pubkey);
+        let mut land_asset_by_mint_acc = land_asset_by_mint_account();
+        let mut rent_sysvar = rent_sysvar();
+
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut nft_mint_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let (land_plane_stats_acc_pubkey, mut land_plane_stats_acc) =
+            land_plane_stats_account(&program_id, &land_plane_acc_pubkey);
+
+        // initialise land plane account
+        let mut land_plane_acc = fake_initialized_plane(0, 0, 0);
+
+        // mint twice from one wallet and once from another
+        let wallet_a = Pubkey::new_unique();
+        let wallet_b = Pubkey::new_unique();
+        for nft_assoc_token_acc_owner_acc_pubkey in vec![wallet_a, wallet_a, wallet_b] {
+            let (land_asset_acc_pubkey, mut land_asset_acc) =
+                next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+            let mut nft_assoc_token_acc_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+            let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+            let mut nft_assoc_token_acc = nft_token_account(
+                &nft_assoc_token_acc_owner_acc_pubkey,
+                &nft_mint_acc_pubkey,
+                AccountState::Initialized,
+            );
+            do_process_instruction(
+                mint_next_land_asset(
+                    &program_id,
+                    &payer_acc_pubkey,
+                    &nft_assoc_token_acc_owner_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    &nft_assoc_token_acc_pubkey,
+                    &nft_mint_acc_pubkey,
+                    &land_plane_stats_acc_pubkey,
+                    &program_config_acc_pubkey,
+                    &protocol_treasury_acc_pubkey,
+                    None,
+                    None,
+                ).unwrap(),
+                vec![
+                    &mut payer_acc,
+                    &mut nft_assoc_token_acc_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    ]
+            )
+            .unwrap();
+        }
+
+        // confirm counters incremented
+        let land_plane_stats = LandPlaneStats::try_from_slice(&land_plane_stats_acc.data).unwrap();
+        assert_eq!(3, land_plane_stats.mints);
+        assert_eq!(0, land_plane_stats.fees_collected);
+        assert_eq!(2, land_plane_stats.unique_wallets);
+    }
+
+    #[test]
+    fn test_mint_next_on_behalf_of_recipient() {
+        let program_id = crate::id();
+
+        // relayer signing for and funding the mint
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(1_000_000, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+
+        let protocol_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut protocol_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let (program_config_acc_pubkey, mut program_config_acc) =
+            program_config_account(&program_id, 0, &protocol_treasury_acc_pubkey);
+        let mut land_asset_by_mint_acc = land_asset_by_mint_account();
+        let mut rent_sysvar = rent_sysvar();
+
+        // owner of the nft and recipient of the land
+        let recipient_acc_pubkey = Pubkey::new_unique();
+        let mut recipient_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_initialized_plane(0, 0, 0);
+
+        let (land_asset_acc_pubkey, mut land_asset_acc) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut nft_mint_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = nft_token_account(
+            &recipient_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+
+        let (land_plane_stats_acc_pubkey, mut land_plane_stats_acc) =
+            land_plane_stats_account(&program_id, &land_plane_acc_pubkey);
+
+        //
+        // payer has not signed
+        //
+        let mut instruction = mint_next_land_asset(
+            &program_id,
+            &payer_acc_pubkey,
+            &recipient_acc_pubkey,
+            &land_asset_acc_pubkey,
+            &land_plane_acc_pubkey,
+            &nft_assoc_token_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            &land_plane_stats_acc_pubkey,
+            &program_config_acc_pubkey,
+            &protocol_treasury_acc_pubkey,
+            None,
+            None,
+        ).unwrap();
+        instruction.accounts[0].is_signer = false;
+        assert_eq!(
+            Err(LandError::MissingSignature.into()),
+            do_process_instruction(
+                instruction,
+                vec![
+                    &mut payer_acc,
+                    &mut recipient_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    ]
+            )
+        );
+
+        //
+        // payer given as recipient but nft held by recipient
+        //
+        assert_eq!(
+            Err(LandError::NftTokenAccOwnerMismatch.into()),
+            do_process_instruction(
+                mint_next_land_asset(
+                    &program_id,
+                    &payer_acc_pubkey,
+                    &payer_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    &nft_assoc_token_acc_pubkey,
+                    &nft_mint_acc_pubkey,
+                    &land_plane_stats_acc_pubkey,
+                    &program_config_acc_pubkey,
+                    &protocol_treasury_acc_pubkey,
+                    None,
+                    None,
+                ).unwrap(),
+                vec![
+                    &mut payer_acc,
+                    &mut recipient_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    ]
+            )
+        );
+
+        //
+        // token account does not hold the given nft mint
+        //
+        assert_eq!(
+            Err(LandError::NftTokenAccMintMismatch.into()),
+            do_process_instruction(
+                mint_next_land_asset(
+                    &program_id,
+                    &payer_acc_pubkey,
+                    &recipient_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    &nft_assoc_token_acc_pubkey,
+                    &Pubkey::new_unique(),
+                    &land_plane_stats_acc_pubkey,
+                    &program_config_acc_pubkey,
+                    &protocol_treasury_acc_pubkey,
+                    None,
+                    None,
+                ).unwrap(),
+                vec![
+                    &mut payer_acc,
+                    &mut recipient_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    ]
+            )
+        );
+
+        // instruction completes successfully
+        do_process_instruction(
+            mint_next_land_asset(
+                &program_id,
+                &payer_acc_pubkey,
+                &recipient_acc_pubkey,
+                &land_asset_acc_pubkey,
+                &land_plane_acc_pubkey,
+                &nft_assoc_token_acc_pubkey,
+                &nft_mint_acc_pubkey,
+                &land_plane_stats_acc_pubkey,
+                &program_config_acc_pubkey,
+                &protocol_treasury_acc_pubkey,
+                None,
+                None,
+            ).unwrap(),
+            vec![
+                &mut payer_acc,
+                &mut recipient_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut nft_assoc_token_acc,
+                &mut nft_mint_acc,
+                &mut land_plane_stats_acc,
+                &mut system_program_acc,
+                &mut program_config_acc,
+                &mut protocol_treasury_acc,
+                &mut land_asset_by_mint_acc,
+                &mut rent_sysvar,
+                ]
+        )
+        .unwrap();
+
+        // land is bound to the recipient's nft, with the recipient
+        // recorded as the minter rather than the payer, along with
+        // the land plane and co-ordinate of the land
+        let land_asset = LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap();
+        assert_eq!(nft_mint_acc_pubkey, land_asset.mint_pubkey);
+        assert_eq!(recipient_acc_pubkey, land_asset.minter);
+        assert_eq!(land_plane_acc_pubkey, land_asset.land_plane);
+        assert_eq!(Coordinate { x: 0, z: 0 }, land_asset.coordinate);
+        assert_eq!(LandAssetVersion::V8, land_asset.version);
+
+        // and the land plane has moved on to the next piece of land
+        let land_plane = LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap();
+        assert_eq!((1, 0, 1), (land_plane.next_x, land_plane.next_z, land_plane.depth));
+    }
+
+    fn nft_metadata_account(
+        update_authority: &Pubkey,
+        nft_mint_acc_pubkey: &Pubkey,
+        collection: Option<Collection>,
+    ) -> SolanaAccount {
+        let mut nft_metadata_acc = SolanaAccount::new(1, 679, &token_metadata_program::id());
+        Metadata{
+            key: 4,
+            update_authority: *update_authority,
+            mint: *nft_mint_acc_pubkey,
+            data: Data{
+                name: "Parcel".to_string(),
+                symbol: "LAND".to_string(),
+                uri: "https://example.com/nft.json".to_string(),
+                seller_fee_basis_points: 0,
+                creators: None,
+            },
+            primary_sale_happened: false,
+            is_mutable: true,
+            edition_nonce: None,
+            token_standard: None,
+            collection,
+        }.serialize(&mut &mut nft_metadata_acc.data[..]).unwrap();
+        nft_metadata_acc
+    }
+
+    #[test]
+    fn test_stamp_coordinate_uri() {
+        let program_id = crate::id();
+
+        let update_authority_acc_pubkey = Pubkey::new_unique();
+        let mut update_authority_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_initialized_plane(3, 1, 3);
+
+        // piece of land at (3, 1) bound to an nft
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let (land_asset_acc_pubkey, _) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+        let mut land_asset_acc = fake_initialized_asset(&nft_mint_acc_pubkey);
+
+        let nft_metadata_acc_pubkey = metadata_acc_pubkey(&nft_mint_acc_pubkey);
+        let mut nft_metadata_acc =
+            nft_metadata_account(&update_authority_acc_pubkey, &nft_mint_acc_pubkey, None);
+
+        let mut token_metadata_program_acc = SolanaAccount::default();
+
+        //
+        // update authority has not signed
+        //
+        let mut instruction = stamp_coordinate_uri(
+            &program_id,
+            &update_authority_acc_pubkey,
+            &land_asset_acc_pubkey,
+            &land_plane_acc_pubkey,
+            &nft_metadata_acc_pubkey,
+            3,
+            1,
+        ).unwrap();
+        instruction.accounts[0].is_signer = false;
+        assert_eq!(
+            Err(LandError::MissingSignature.into()),
+            do_process_instruction(
+                instruction,
+                vec![
+                    &mut update_authority_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_metadata_acc,
+                    &mut token_metadata_program_acc,
+                    ]
+            )
+        );
+
+        //
+        // signer is not the update authority
+        //
+        let wrong_update_authority_acc_pubkey = Pubkey::new_unique();
+        assert_eq!(
+            Err(LandError::UpdateAuthorityMismatch.into()),
+            do_process_instruction(
+                stamp_coordinate_uri(
+                    &program_id,
+                    &wrong_update_authority_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    &nft_metadata_acc_pubkey,
+                    3,
+                    1,
+                ).unwrap(),
+                vec![
+                    &mut update_authority_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_metadata_acc,
+                    &mut token_metadata_program_acc,
+                    ]
+            )
+        );
+
+        //
+        // co-ordinate does not match the given land asset account
+        //
+        assert_eq!(
+            Err(LandError::InvalidLandAssetAccKey.into()),
+            do_process_instruction(
+                stamp_coordinate_uri(
+                    &program_id,
+                    &update_authority_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    &nft_metadata_acc_pubkey,
+                    1,
+                    3,
+                ).unwrap(),
+                vec![
+                    &mut update_authority_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_metadata_acc,
+                    &mut token_metadata_program_acc,
+                    ]
+            )
+        );
+
+        // instruction completes successfully
+        do_process_instruction(
+            stamp_coordinate_uri(
+                &program_id,
+                &update_authority_acc_pubkey,
+                &land_asset_acc_pubkey,
+                &land_plane_acc_pubkey,
+                &nft_metadata_acc_pubkey,
+                3,
+                1,
+            ).unwrap(),
+            vec![
+                &mut update_authority_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut nft_metadata_acc,
+                &mut token_metadata_program_acc,
+                ]
+        )
+        .unwrap();
+
+        // and the metadata uri now includes the co-ordinate
+        let nft_metadata: Metadata = try_from_slice_unchecked(&nft_metadata_acc.data).unwrap();
+        assert_eq!("https://example.com/nft.json?x=3&z=1", nft_metadata.data.uri);
+    }
+
+    #[test]
+    fn test_mint_next_insufficient_fee_funds() {
+        let program_id = crate::id();
+
+        // payer one lamport short of the mint fee
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(4_999, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+
+        let protocol_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut protocol_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let (program_config_acc_pubkey, mut program_config_acc) =
+            program_config_account(&program_id, 0, &protocol_treasury_acc_pubkey);
+        let mut land_asset_by_mint_acc = land_asset_by_mint_account();
+        let mut rent_sysvar = rent_sysvar();
+        let mut slot_hashes_sysvar = slot_hashes_sysvar([0; 32]);
+        let mut nft_metadata_acc = SolanaAccount::default();
+        let mut clock_sysvar_acc = SolanaAccount::default();
+        let mut wallet_mint_record_acc = SolanaAccount::default();
+
+        let nft_assoc_token_acc_owner_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        // land plane with an owner but no treasury, whose owner is paid
+        let land_plane_owner_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_plane_account(&LandPlane{
+            mint_fee: 5_000,
+            owner: Some(land_plane_owner_acc_pubkey),
+            ..initialized_plane(0, 0, 0)
+        });
+
+        let (land_asset_acc_pubkey, mut land_asset_acc) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut nft_mint_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = nft_token_account(
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+
+        let (land_plane_stats_acc_pubkey, mut land_plane_stats_acc) =
+            land_plane_stats_account(&program_id, &land_plane_acc_pubkey);
+
+        let instruction = mint_next_land_asset(
+            &program_id,
+            &payer_acc_pubkey,
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            &land_asset_acc_pubkey,
+            &land_plane_acc_pubkey,
+            &nft_assoc_token_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            &land_plane_stats_acc_pubkey,
+            &program_config_acc_pubkey,
+            &protocol_treasury_acc_pubkey,
+            None,
+            Some(&land_plane_owner_acc_pubkey),
+        ).unwrap();
+
+        //
+        // payer cannot cover the mint fee
+        //
+        assert_eq!(
+            Err(LandError::InsufficientFeeFunds.into()),
+            do_process_instruction(
+                instruction.clone(),
+                vec![
+                    &mut payer_acc,
+                    &mut nft_assoc_token_acc_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    ]
+            )
+        );
+
+        // fund payer
+        payer_acc.lamports = 5_000;
+
+        //
+        // land plane with neither a treasury nor an owner
+        //
+        let mut ownerless_land_plane_acc = fake_plane_account(&LandPlane{
+            mint_fee: 5_000,
+            ..initialized_plane(0, 0, 0)
+        });
+        assert_eq!(
+            Err(LandError::FeeRecipientUnset.into()),
+            do_process_instruction(
+                instruction.clone(),
+                vec![
+                    &mut payer_acc,
+                    &mut nft_assoc_token_acc_owner_acc,
+                    &mut land_asset_acc,
+                    &mut ownerless_land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    ]
+            )
+        );
+
+        // instruction completes successfully
+        do_process_instruction(
+            instruction,
+            vec![
+                &mut payer_acc,
+                &mut nft_assoc_token_acc_owner_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut nft_assoc_token_acc,
+                &mut nft_mint_acc,
+                &mut land_plane_stats_acc,
+                &mut system_program_acc,
+                &mut program_config_acc,
+                &mut protocol_treasury_acc,
+                &mut land_asset_by_mint_acc,
+                &mut rent_sysvar,
+                &mut slot_hashes_sysvar,
+                &mut nft_metadata_acc,
+                &mut clock_sysvar_acc,
+                &mut wallet_mint_record_acc,
+                &mut land_plane_owner_acc,
+                ]
+        )
+        .unwrap();
+
+        // and the fee is paid to the land plane's owner, not held by the
+        // program owned land plane account
+        assert_eq!(0, payer_acc.lamports);
+        assert_eq!(5_001, land_plane_owner_acc.lamports);
+        assert_eq!(land_plane_minimum_balance(), land_plane_acc.lamports);
+
+        // and the fee is recorded
+        let land_plane_stats = LandPlaneStats::try_from_slice(&land_plane_stats_acc.data).unwrap();
+        assert_eq!(5_000, land_plane_stats.fees_collected);
+    }
+
+    #[test]
+    fn test_initialise_program_config() {
+        let program_id = crate::id();
+
+        let upgrade_authority_acc_pubkey = Pubkey::new_unique();
+        let mut upgrade_authority_acc = SolanaAccount::new(1_000_000_000, 0, &system_program::id());
+
+        let (program_config_acc_pubkey, _) = Pubkey::find_program_address(
+            &[PROGRAM_CONFIG_ACC_PREFIX.as_bytes()],
+            &program_id,
+        );
+        let mut program_config_acc = SolanaAccount::new(
+            Rent::default().minimum_balance(PROGRAM_CONFIG_ACC_DATA_LEN),
+            PROGRAM_CONFIG_ACC_DATA_LEN,
+            &program_id,
+        );
+
+        let mut program_data_acc = SolanaAccount::new_data(
+            1,
+            &UpgradeableLoaderState::ProgramData {
+                slot: 0,
+                upgrade_authority_address: Some(upgrade_authority_acc_pubkey),
+            },
+            &bpf_loader_upgradeable::id(),
+        ).unwrap();
+
+        let protocol_treasury = Pubkey::new_unique();
+        let mut rent_sysvar = rent_sysvar();
+        let mut system_program_acc = SolanaAccount::default();
+
+        //
+        // upgrade authority has not signed
+        //
+        let mut instruction = initialize_program_config(
+            &program_id,
+            &upgrade_authority_acc_pubkey,
+            &program_config_acc_pubkey,
+            1_000,
+            &protocol_treasury,
+        ).unwrap();
+        instruction.accounts[0].is_signer = false;
+        assert_eq!(
+            Err(LandError::MissingSignature.into()),
+            do_process_instruction(
+                instruction,
+                vec![
+                    &mut upgrade_authority_acc,
+                    &mut program_config_acc,
+                    &mut program_data_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+        );
+
+        //
+        // signer is not the upgrade authority
+        //
+        assert_eq!(
+            Err(LandError::SignatureError.into()),
+            do_process_instruction(
+                initialize_program_config(
+                    &program_id,
+                    &Pubkey::new_unique(),
+                    &program_config_acc_pubkey,
+                    1_000,
+                    &protocol_treasury,
+                ).unwrap(),
+                vec![
+                    &mut upgrade_authority_acc,
+                    &mut program_config_acc,
+                    &mut program_data_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+        );
+
+        //
+        // program data account not held by the upgradeable loader
+        //
+        program_data_acc.owner = Pubkey::new_unique();
+        assert_eq!(
+            Err(LandError::InvalidProgramDataAcc.into()),
+            do_process_instruction(
+                initialize_program_config(
+                    &program_id,
+                    &upgrade_authority_acc_pubkey,
+                    &program_config_acc_pubkey,
+                    1_000,
+                    &protocol_treasury,
+                ).unwrap(),
+                vec![
+                    &mut upgrade_authority_acc,
+                    &mut program_config_acc,
+                    &mut program_data_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+        );
+        program_data_acc.owner = bpf_loader_upgradeable::id();
+
+        // instruction completes successfully
+        do_process_instruction(
+            initialize_program_config(
+                &program_id,
+                &upgrade_authority_acc_pubkey,
+                &program_config_acc_pubkey,
+                1_000,
+                &protocol_treasury,
+            ).unwrap(),
+            vec![
+                &mut upgrade_authority_acc,
+                &mut program_config_acc,
+                &mut program_data_acc,
+                &mut rent_sysvar,
+                &mut system_program_acc,
+                ]
+        )
+        .unwrap();
+        assert_eq!(
+            ProgramConfig{
+                version: ProgramConfigVersion::V2,
+                protocol_fee: 1_000,
+                protocol_treasury,
+                total_planes: 0,
+                total_mints: 0,
+            },
+            ProgramConfig::try_from_slice(&program_config_acc.data).unwrap(),
+        );
+
+        //
+        // trying to call initialise again fails
+        //
+        assert_eq!(
+            Err(LandError::ProgramConfigAccAlreadyInitialised.into()),
+            do_process_instruction(
+                initialize_program_config(
+                    &program_id,
+                    &upgrade_authority_acc_pubkey,
+                    &program_config_acc_pubkey,
+                    0,
+                    &upgrade_authority_acc_pubkey,
+                ).unwrap(),
+                vec![
+                    &mut upgrade_authority_acc,
+                    &mut program_config_acc,
+                    &mut program_data_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+        );
+    }
+
+    #[test]
+    fn test_force_set_cursor() {
+        let program_id = crate::id();
+
+        let upgrade_authority_acc_pubkey = Pubkey::new_unique();
+        let mut upgrade_authority_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let mut program_data_acc = SolanaAccount::new_data(
+            1,
+            &UpgradeableLoaderState::ProgramData {
+                slot: 0,
+                upgrade_authority_address: Some(upgrade_authority_acc_pubkey),
+            },
+            &bpf_loader_upgradeable::id(),
+        ).unwrap();
+
+        // bounded land plane left part way through shell 2
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let land_plane = LandPlane{
+            coordinate_ceiling: Some(10),
+            ..initialized_plane(2, 1, 2)
+        };
+        let mut land_plane_acc = fake_plane_account(&land_plane);
+
+        //
+        // signer is not the upgrade authority
+        //
+        assert_eq!(
+            Err(LandError::SignatureError.into()),
+            do_process_instruction(
+                force_set_cursor(&program_id, &Pubkey::new_unique(), &land_plane_acc_pubkey, 0, 3, 3).unwrap(),
+                vec![
+                    &mut upgrade_authority_acc,
+                    &mut land_plane_acc,
+                    &mut program_data_acc,
+                    ]
+            )
+        );
+
+        //
+        // co-ordinate is not reachable by the minting pattern, or lies
+        // beyond the coordinate ceiling other than where the cursor is
+        // left once the land plane is complete
+        //
+        for (next_x, next_z, depth) in vec![(1, 1, 3), (4, 0, 3), (11, 1, 11), (0, 11, 11), (12, 0, 12)] {
+            assert_eq!(
+                Err(LandError::InconsistentPlaneState.into()),
+                do_process_instruction(
+                    force_set_cursor(&program_id, &upgrade_authority_acc_pubkey, &land_plane_acc_pubkey, next_x, next_z, depth).unwrap(),
+                    vec![
+                        &mut upgrade_authority_acc,
+                        &mut land_plane_acc,
+                        &mut program_data_acc,
+                        ]
+                )
+            );
+        }
+        assert_eq!(land_plane, LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap());
+
+        //
+        // land plane without a ceiling is bounded by MAX_PLANE_DEPTH
+        //
+        let unbounded_plane = initialized_plane(2, 1, 2);
+        let mut unbounded_plane_acc = fake_plane_account(&unbounded_plane);
+        assert_eq!(
+            Err(LandError::InconsistentPlaneState.into()),
+            do_process_instruction(
+                force_set_cursor(
+                    &program_id,
+                    &upgrade_authority_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    MAX_PLANE_DEPTH + 1,
+                    1,
+                    MAX_PLANE_DEPTH + 1,
+                ).unwrap(),
+                vec![
+                    &mut upgrade_authority_acc,
+                    &mut unbounded_plane_acc,
+                    &mut program_data_acc,
+                    ]
+            )
+        );
+        assert_eq!(unbounded_plane, LandPlane::deserialize(&mut &unbounded_plane_acc.data[..]).unwrap());
+
+        // instruction completes successfully
+        do_process_instruction(
+            force_set_cursor(&program_id, &upgrade_authority_acc_pubkey, &land_plane_acc_pubkey, 0, 3, 3).unwrap(),
+            vec![
+                &mut upgrade_authority_acc,
+                &mut land_plane_acc,
+                &mut program_data_acc,
+                ]
+        )
+        .unwrap();
+        let forced = LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap();
+        assert_eq!(LandPlane{ next_x: 0, next_z: 3, depth: 3, ..land_plane.clone() }, forced);
+        assert_eq!(15, forced.minted_count());
+
+        // as it does when the land plane is left complete, with
+        // the cursor where the last mint within the ceiling leaves it
+        do_process_instruction(
+            force_set_cursor(&program_id, &upgrade_authority_acc_pubkey, &land_plane_acc_pubkey, 11, 0, 11).unwrap(),
+            vec![
+                &mut upgrade_authority_acc,
+                &mut land_plane_acc,
+                &mut program_data_acc,
+                ]
+        )
+        .unwrap();
+        let forced = LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap();
+        assert_eq!(LandPlane{ next_x: 11, next_z: 0, depth: 11, ..land_plane }, forced);
+        assert!(forced.is_complete());
+        assert_eq!(121, forced.minted_count());
+    }
+
+    #[test]
+    fn test_mint_next_pays_plane_and_protocol_fees() {
+        let program_id = crate::id();
+
+        // payer one lamport short of the combined fees
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(5_999, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+
+        let protocol_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut protocol_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let (program_config_acc_pubkey, mut program_config_acc) =
+            program_config_account(&program_id, 1_000, &protocol_treasury_acc_pubkey);
+        let mut land_asset_by_mint_acc = land_asset_by_mint_account();
+        let mut rent_sysvar = rent_sysvar();
+        let mut slot_hashes_sysvar = slot_hashes_sysvar([0; 32]);
+        let mut nft_metadata_acc = SolanaAccount::default();
+        let mut clock_sysvar_acc = SolanaAccount::default();
+        let mut wallet_mint_record_acc = SolanaAccount::default();
+
+        let nft_assoc_token_acc_owner_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let plane_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut plane_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_plane_account(&LandPlane{
+            mint_fee: 5_000,
+            owner: Some(Pubkey::new_unique()),
+            plane_treasury: Some(plane_treasury_acc_pubkey),
+            ..initialized_plane(0, 0, 0)
+        });
+
+        let (land_asset_acc_pubkey, mut land_asset_acc) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut nft_mint_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = nft_token_account(
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+
+        let (land_plane_stats_acc_pubkey, mut land_plane_stats_acc) =
+            land_plane_stats_account(&program_id, &land_plane_acc_pubkey);
+
+        //
+        // protocol fee paid to an account other than the treasury
+        //
+        assert_eq!(
+            Err(LandError::InvalidProtocolTreasuryAcc.into()),
+            do_process_instruction(
+                mint_next_land_asset(
+                    &program_id,
+                    &payer_acc_pubkey,
+                    &nft_assoc_token_acc_owner_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    &nft_assoc_token_acc_pubkey,
+                    &nft_mint_acc_pubkey,
+                    &land_plane_stats_acc_pubkey,
+                    &program_config_acc_pubkey,
+                    &payer_acc_pubkey,
+                    None,
+                    None,
+                ).unwrap(),
+                vec![
+                    &mut payer_acc,
+                    &mut nft_assoc_token_acc_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    ]
+            )
+        );
+
+        let instruction = mint_next_land_asset(
+            &program_id,
+            &payer_acc_pubkey,
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            &land_asset_acc_pubkey,
+            &land_plane_acc_pubkey,
+            &nft_assoc_token_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            &land_plane_stats_acc_pubkey,
+            &program_config_acc_pubkey,
+            &protocol_treasury_acc_pubkey,
+            None,
+            Some(&plane_treasury_acc_pubkey),
+        ).unwrap();
+
+        //
+        // payer cannot cover both fees
+        //
+        assert_eq!(
+            Err(LandError::InsufficientFeeFunds.into()),
+            do_process_instruction(
+                instruction.clone(),
+                vec![
+                    &mut payer_acc,
+                    &mut nft_assoc_token_acc_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    ]
+            )
+        );
+
+        // fund payer
+        payer_acc.lamports = 10_000;
+
+        // instruction completes successfully
+        do_process_instruction(
+            instruction,
+            vec![
+                &mut payer_acc,
+                &mut nft_assoc_token_acc_owner_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut nft_assoc_token_acc,
+                &mut nft_mint_acc,
+                &mut land_plane_stats_acc,
+                &mut system_program_acc,
+                &mut program_config_acc,
+                &mut protocol_treasury_acc,
+                &mut land_asset_by_mint_acc,
+                &mut rent_sysvar,
+                &mut slot_hashes_sysvar,
+                &mut nft_metadata_acc,
+                &mut clock_sysvar_acc,
+                &mut wallet_mint_record_acc,
+                &mut plane_treasury_acc,
+                ]
+        )
+        .unwrap();
+
+        // and both fees are paid, the mint fee to the land plane's
+        // treasury rather than to its owner
+        assert_eq!(4_000, payer_acc.lamports);
+        assert_eq!(5_001, plane_treasury_acc.lamports);
+        assert_eq!(land_plane_minimum_balance(), land_plane_acc.lamports);
+        assert_eq!(1_001, protocol_treasury_acc.lamports);
+
+        // with only the land plane's mint fee recorded against the land plane
+        let land_plane_stats = LandPlaneStats::try_from_slice(&land_plane_stats_acc.data).unwrap();
+        assert_eq!(5_000, land_plane_stats.fees_collected);
+
+        // and the mint counted on the program config, once
+        // only, since the mint that failed is not counted
+        assert_eq!(1, ProgramConfig::try_from_slice(&program_config_acc.data).unwrap().total_mints);
+    }
+
+    #[test]
+    fn test_mint_next_price_oracle() {
+        let program_id = crate::id();
+
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(10_000, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+
+        let protocol_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut protocol_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let (program_config_acc_pubkey, mut program_config_acc) =
+            program_config_account(&program_id, 0, &protocol_treasury_acc_pubkey);
+        let mut land_asset_by_mint_acc = land_asset_by_mint_account();
+        let mut rent_sysvar = rent_sysvar();
+        let mut slot_hashes_sysvar = slot_hashes_sysvar([0; 32]);
+        let mut nft_metadata_acc = SolanaAccount::default();
+        let mut clock_sysvar_acc = SolanaAccount::default();
+        let mut wallet_mint_record_acc = SolanaAccount::default();
+
+        // price feed, kept by some other program, not yet initialised
+        let price_oracle_acc_pubkey = Pubkey::new_unique();
+        let mut price_oracle_acc = SolanaAccount::new(
+            Rent::default().minimum_balance(PRICE_FEED_ACC_DATA_LEN),
+            PRICE_FEED_ACC_DATA_LEN,
+            &Pubkey::new_unique(),
+        );
+
+        let nft_assoc_token_acc_owner_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let plane_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut plane_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        // land plane whose own mint fee is overridden by its price oracle
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_plane_account(&LandPlane{
+            mint_fee: 5_000,
+            price_oracle: Some(price_oracle_acc_pubkey),
+            plane_treasury: Some(plane_treasury_acc_pubkey),
+            ..initialized_plane(0, 0, 0)
+        });
+
+        let (land_asset_acc_pubkey, mut land_asset_acc) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut nft_mint_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = nft_token_account(
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+
+        let (land_plane_stats_acc_pubkey, mut land_plane_stats_acc) =
+            land_plane_stats_account(&program_id, &land_plane_acc_pubkey);
+
+        let instruction = |price_oracle_acc_pubkey: Option<&Pubkey>| mint_next_land_asset(
+            &program_id,
+            &payer_acc_pubkey,
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            &land_asset_acc_pubkey,
+            &land_plane_acc_pubkey,
+            &nft_assoc_token_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            &land_plane_stats_acc_pubkey,
+            &program_config_acc_pubkey,
+            &protocol_treasury_acc_pubkey,
+            price_oracle_acc_pubkey,
+            Some(&plane_treasury_acc_pubkey),
+        ).unwrap();
+
+        //
+        // price oracle not given
+        //
+        assert_eq!(
+            Err(ProgramError::NotEnoughAccountKeys),
+            do_process_instruction(
+                instruction(None),
+                vec![
+                    &mut payer_acc,
+                    &mut nft_assoc_token_acc_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    &mut slot_hashes_sysvar,
+                    &mut nft_metadata_acc,
+                    &mut clock_sysvar_acc,
+                    &mut wallet_mint_record_acc,
+                    ]
+            )
+        );
+
+        //
+        // price feed other than the land plane's price oracle
+        //
+        assert_eq!(
+            Err(LandError::InvalidPriceOracleAccKey.into()),
+            do_process_instruction(
+                instruction(Some(&Pubkey::new_unique())),
+                vec![
+                    &mut payer_acc,
+                    &mut nft_assoc_token_acc_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    &mut slot_hashes_sysvar,
+                    &mut nft_metadata_acc,
+                    &mut clock_sysvar_acc,
+                    &mut wallet_mint_record_acc,
+                    &mut price_oracle_acc,
+                    ]
+            )
+        );
+
+        //
+        // price oracle not yet initialised
+        //
+        assert_eq!(
+            Err(LandError::PriceFeedAccUninitialised.into()),
+            do_process_instruction(
+                instruction(Some(&price_oracle_acc_pubkey)),
+                vec![
+                    &mut payer_acc,
+                    &mut nft_assoc_token_acc_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    &mut slot_hashes_sysvar,
+                    &mut nft_metadata_acc,
+                    &mut clock_sysvar_acc,
+                    &mut wallet_mint_record_acc,
+                    &mut price_oracle_acc,
+                    ]
+            )
+        );
+
+        // the price feed sets a price above the land plane's mint fee
+        PriceFeed{ version: PriceFeedVersion::V1, price: 7_500 }
+            .serialize(&mut &mut price_oracle_acc.data[..])
+            .unwrap();
+
+        // instruction completes successfully
+        do_process_instruction(
+            instruction(Some(&price_oracle_acc_pubkey)),
+            vec![
+                &mut payer_acc,
+                &mut nft_assoc_token_acc_owner_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut nft_assoc_token_acc,
+                &mut nft_mint_acc,
+                &mut land_plane_stats_acc,
+                &mut system_program_acc,
+                &mut program_config_acc,
+                &mut protocol_treasury_acc,
+                &mut land_asset_by_mint_acc,
+                &mut rent_sysvar,
+                &mut slot_hashes_sysvar,
+                &mut nft_metadata_acc,
+                &mut clock_sysvar_acc,
+                &mut wallet_mint_record_acc,
+                &mut price_oracle_acc,
+                &mut plane_treasury_acc,
+                ]
+        )
+        .unwrap();
+
+        // and the price of the price feed is paid in place of the mint fee
+        assert_eq!(2_500, payer_acc.lamports);
+        assert_eq!(7_501, plane_treasury_acc.lamports);
+        assert_eq!(land_plane_minimum_balance(), land_plane_acc.lamports);
+        let land_plane_stats = LandPlaneStats::try_from_slice(&land_plane_stats_acc.data).unwrap();
+        assert_eq!(7_500, land_plane_stats.fees_collected);
+    }
+
+    #[test]
+    fn test_mint_next_indexes_land_asset_by_mint() {
+        let program_id = crate::id();
+
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(1_000_000, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+
+        let protocol_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut protocol_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let (program_config_acc_pubkey, mut program_config_acc) =
+            program_config_account(&program_id, 0, &protocol_treasury_acc_pubkey);
+        let mut land_asset_by_mint_acc = land_asset_by_mint_account();
+        let mut rent_sysvar = rent_sysvar();
+
+        let nft_assoc_token_acc_owner_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_initialized_plane(2, 1, 2);
+
+        let (land_asset_acc_pubkey, mut land_asset_acc) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut nft_mint_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = nft_token_account(
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+
+        let (land_plane_stats_acc_pubkey, mut land_plane_stats_acc) =
+            land_plane_stats_account(&program_id, &land_plane_acc_pubkey);
+
+        //
+        // land asset by mint acc is not the PDA of the nft mint
+        //
+        let mut instruction = mint_next_land_asset(
+            &program_id,
+            &payer_acc_pubkey,
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            &land_asset_acc_pubkey,
+            &land_plane_acc_pubkey,
+            &nft_assoc_token_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            &land_plane_stats_acc_pubkey,
+            &program_config_acc_pubkey,
+            &protocol_treasury_acc_pubkey,
+            None,
+            None,
+        ).unwrap();
+        instruction.accounts[10].pubkey = Pubkey::new_unique();
+        assert_eq!(
+            Err(LandError::InvalidLandAssetByMintAccKey.into()),
+            do_process_instruction(
+                instruction,
+                vec![
+                    &mut payer_acc,
+                    &mut nft_assoc_token_acc_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    ]
+            )
+        );
+
+        // instruction completes successfully
+        do_process_instruction(
+            mint_next_land_asset(
+                &program_id,
+                &payer_acc_pubkey,
+                &nft_assoc_token_acc_owner_acc_pubkey,
+                &land_asset_acc_pubkey,
+                &land_plane_acc_pubkey,
+                &nft_assoc_token_acc_pubkey,
+                &nft_mint_acc_pubkey,
+                &land_plane_stats_acc_pubkey,
+                &program_config_acc_pubkey,
+                &protocol_treasury_acc_pubkey,
+                None,
+                None,
+            ).unwrap(),
+            vec![
+                &mut payer_acc,
+                &mut nft_assoc_token_acc_owner_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut nft_assoc_token_acc,
+                &mut nft_mint_acc,
+                &mut land_plane_stats_acc,
+                &mut system_program_acc,
+                &mut program_config_acc,
+                &mut protocol_treasury_acc,
+                &mut land_asset_by_mint_acc,
+                &mut rent_sysvar,
+                ]
+        )
+        .unwrap();
+
+        // the nft mint is indexed to the minted piece of land
+        assert_eq!(
+            LandAssetByMint{
+                version: LandAssetByMintVersion::V1,
+                land_plane: land_plane_acc_pubkey,
+                land_asset: land_asset_acc_pubkey,
+                coordinate: Coordinate { x: 2, z: 1 },
+            },
+            LandAssetByMint::try_from_slice(&land_asset_by_mint_acc.data).unwrap(),
+        );
+    }
+
+    fn new_nft_mint_account() -> SolanaAccount {
+        let mut account = SolanaAccount::new(1, Mint::LEN, &spl_token::id());
+        Mint{
+            mint_authority: COption::None,
+            supply: 1,
+            decimals: 0,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        }.pack_into_slice(&mut account.data);
+        account
+    }
+
+    #[test]
+    fn test_transfer_land() {
+        let program_id = crate::id();
+
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(1_000_000, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+        let mut rent_sysvar = rent_sysvar();
+
+        // holder of the nft to which the land is bound
+        let holder_acc_pubkey = Pubkey::new_unique();
+        let mut holder_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        // piece of land at (3, 1) bound to an nft and indexed by its mint
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_initialized_plane(3, 1, 3);
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let (land_asset_acc_pubkey, _) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+        let mut land_asset_acc = fake_initialized_asset(&nft_mint_acc_pubkey);
+        let land_asset_by_mint = initialized_land_asset_by_mint(
+            &land_plane_acc_pubkey,
+            &land_asset_acc_pubkey,
+            Coordinate { x: 3, z: 1 },
+        );
+        let mut land_asset_by_mint_acc = fake_land_asset_by_mint_account(&land_asset_by_mint);
+
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = nft_token_account(
+            &holder_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+
+        // nft to which the land is to be bound
+        let new_nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut new_nft_mint_acc = new_nft_mint_account();
+        let mut new_land_asset_by_mint_acc = land_asset_by_mint_account();
+
+        //
+        // holder of the nft has not signed
+        //
+        let mut instruction = transfer_land(
+            &program_id,
+            &payer_acc_pubkey,
+            &holder_acc_pubkey,
+            &land_asset_acc_pubkey,
+            &land_plane_acc_pubkey,
+            &nft_assoc_token_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            &new_nft_mint_acc_pubkey,
+            None,
+            3,
+            1,
+            0,
+        ).unwrap();
+        instruction.accounts[1].is_signer = false;
+        assert_eq!(
+            Err(LandError::MissingSignature.into()),
+            do_process_instruction(
+                instruction,
+                vec![
+                    &mut payer_acc,
+                    &mut holder_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut new_nft_mint_acc,
+                    &mut new_land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+        );
+
+        //
+        // land asset minted from another land plane
+        //
+        let mut other_plane_land_asset_acc = fake_asset_account(&LandAsset{
+            land_plane: Pubkey::new_unique(),
+            ..initialized_asset(&nft_mint_acc_pubkey)
+        });
+        assert_eq!(
+            Err(LandError::PlaneMismatch.into()),
+            do_process_instruction(
+                transfer_land(
+                    &program_id,
+                    &payer_acc_pubkey,
+                    &holder_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    &nft_assoc_token_acc_pubkey,
+                    &nft_mint_acc_pubkey,
+                    &new_nft_mint_acc_pubkey,
+                    None,
+                    3,
+                    1,
+                    0,
+                ).unwrap(),
+                vec![
+                    &mut payer_acc,
+                    &mut holder_acc,
+                    &mut other_plane_land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut new_nft_mint_acc,
+                    &mut new_land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+        );
+
+        //
+        // land merged into an estate
+        //
+        let mut merged_land_asset_acc = fake_asset_account(&LandAsset{
+            merged_into: Pubkey::new_unique(),
+            ..initialized_asset(&nft_mint_acc_pubkey)
+        });
+        assert_eq!(
+            Err(LandError::ParcelAlreadyMerged.into()),
+            do_process_instruction(
+                transfer_land(
+                    &program_id,
+                    &payer_acc_pubkey,
+                    &holder_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    &nft_assoc_token_acc_pubkey,
+                    &nft_mint_acc_pubkey,
+                    &new_nft_mint_acc_pubkey,
+                    None,
+                    3,
+                    1,
+                    0,
+                ).unwrap(),
+                vec![
+                    &mut payer_acc,
+                    &mut holder_acc,
+                    &mut merged_land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut new_nft_mint_acc,
+                    &mut new_land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+        );
+
+        //
+        // signer does not hold the nft
+        //
+        assert_eq!(
+            Err(LandError::NftTokenAccOwnerMismatch.into()),
+            do_process_instruction(
+                transfer_land(
+                    &program_id,
+                    &payer_acc_pubkey,
+                    &payer_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    &nft_assoc_token_acc_pubkey,
+                    &nft_mint_acc_pubkey,
+                    &new_nft_mint_acc_pubkey,
+                    None,
+                    3,
+                    1,
+                    0,
+                ).unwrap(),
+                vec![
+                    &mut payer_acc,
+                    &mut holder_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut new_nft_mint_acc,
+                    &mut new_land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+        );
+
+        //
+        // land asset by mint acc is not that of the nft to which the land is bound
+        //
+        assert_eq!(
+            Err(LandError::InvalidLandAssetByMintAccKey.into()),
+            do_process_instruction(
+                transfer_land(
+                    &program_id,
+                    &payer_acc_pubkey,
+                    &holder_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    &nft_assoc_token_acc_pubkey,
+                    &new_nft_mint_acc_pubkey,
+                    &new_nft_mint_acc_pubkey,
+                    None,
+                    3,
+                    1,
+                    0,
+                ).unwrap(),
+                vec![
+                    &mut payer_acc,
+                    &mut holder_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut new_nft_mint_acc,
+                    &mut new_land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+        );
+
+        //
+        // new mint is not that of an nft, as it has a supply of many tokens
+        //
+        let mut fungible_mint_acc = new_nft_mint_account();
+        let mut fungible_mint = Mint::unpack_from_slice(&fungible_mint_acc.data).unwrap();
+        fungible_mint.supply = 1_000;
+        fungible_mint.pack_into_slice(&mut fungible_mint_acc.data);
+        assert_eq!(
+            Err(LandError::NotAnNft.into()),
+            do_process_instruction(
+                transfer_land(
+                    &program_id,
+                    &payer_acc_pubkey,
+                    &holder_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    &nft_assoc_token_acc_pubkey,
+                    &nft_mint_acc_pubkey,
+                    &new_nft_mint_acc_pubkey,
+                    None,
+                    3,
+                    1,
+                    0,
+                ).unwrap(),
+                vec![
+                    &mut payer_acc,
+                    &mut holder_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut fungible_mint_acc,
+                    &mut new_land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+        );
+
+        // instruction completes successfully
+        let land_asset_by_mint_acc_lamports = land_asset_by_mint_acc.lamports;
+        do_process_instruction(
+            transfer_land(
+                &program_id,
+                &payer_acc_pubkey,
+                &holder_acc_pubkey,
+                &land_asset_acc_pubkey,
+                &land_plane_acc_pubkey,
+                &nft_assoc_token_acc_pubkey,
+                &nft_mint_acc_pubkey,
+                &new_nft_mint_acc_pubkey,
+                None,
+                3,
+                1,
+                0,
+            ).unwrap(),
+            vec![
+                &mut payer_acc,
+                &mut holder_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut nft_assoc_token_acc,
+                &mut land_asset_by_mint_acc,
+                &mut new_nft_mint_acc,
+                &mut new_land_asset_by_mint_acc,
+                &mut rent_sysvar,
+                &mut system_program_acc,
+                ]
+        )
+        .unwrap();
+
+        // land is bound to the new nft
+        let land_asset = LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap();
+        assert_eq!(new_nft_mint_acc_pubkey, land_asset.mint_pubkey);
+
+        // the index has moved to the new nft mint
+        assert_eq!(
+            land_asset_by_mint,
+            LandAssetByMint::try_from_slice(&new_land_asset_by_mint_acc.data).unwrap(),
+        );
+
+        // and the index of the old nft mint is closed,
+        // with its rent returned to the payer
+        assert_eq!(
+            LandAssetByMintVersion::Uninitialised,
+            LandAssetByMint::try_from_slice(&land_asset_by_mint_acc.data).unwrap().version,
+        );
+        assert_eq!(0, land_asset_by_mint_acc.lamports);
+        assert_eq!(1_000_000 + land_asset_by_mint_acc_lamports, payer_acc.lamports);
+    }
+
+    #[test]
+    fn test_transfer_land_counts_transfers() {
+        let program_id = crate::id();
+
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(1_000_000, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+        let mut rent_sysvar = rent_sysvar();
+
+        let holder_acc_pubkey = Pubkey::new_unique();
+        let mut holder_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        // piece of land at (3, 1) bound to an nft and indexed by its mint
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_initialized_plane(3, 1, 3);
+        let (land_asset_acc_pubkey, _) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+        let mut nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut land_asset_acc = fake_initialized_asset(&nft_mint_acc_pubkey);
+        assert_eq!(0, LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap().transfer_count);
+        let mut land_asset_by_mint_acc = fake_land_asset_by_mint_account(&initialized_land_asset_by_mint(
+            &land_plane_acc_pubkey,
+            &land_asset_acc_pubkey,
+            Coordinate { x: 3, z: 1 },
+        ));
+
+        // transfer the land from nft to nft, twice
+        for expected_transfer_count in 1..=2 {
+            let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+            let mut nft_assoc_token_acc = nft_token_account(
+                &holder_acc_pubkey,
+                &nft_mint_acc_pubkey,
+                AccountState::Initialized,
+            );
+            let new_nft_mint_acc_pubkey = Pubkey::new_unique();
+            let mut new_nft_mint_acc = new_nft_mint_account();
+            let mut new_land_asset_by_mint_acc = land_asset_by_mint_account();
+
+            do_process_instruction(
+                transfer_land(
+                    &program_id,
+                    &payer_acc_pubkey,
+                    &holder_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    &nft_assoc_token_acc_pubkey,
+                    &nft_mint_acc_pubkey,
+                    &new_nft_mint_acc_pubkey,
+                    None,
+                    3,
+                    1,
+                    0,
+                ).unwrap(),
+                vec![
+                    &mut payer_acc,
+                    &mut holder_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut new_nft_mint_acc,
+                    &mut new_land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+            .unwrap();
+
+            // each transfer is counted on the land
+            let land_asset = LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap();
+            assert_eq!(new_nft_mint_acc_pubkey, land_asset.mint_pubkey);
+            assert_eq!(expected_transfer_count, land_asset.transfer_count);
+
+            nft_mint_acc_pubkey = new_nft_mint_acc_pubkey;
+            land_asset_by_mint_acc = new_land_asset_by_mint_acc;
+        }
+    }
+
+    #[test]
+    fn test_admin_relink_parcel() {
+        let program_id = crate::id();
+
+        let mut system_program_acc = SolanaAccount::default();
+        let mut rent_sysvar = rent_sysvar();
+
+        let land_plane_owner_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_owner_acc = SolanaAccount::new(1_000_000, 0, &system_program::id());
+
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let land_plane = LandPlane{
+            owner: Some(land_plane_owner_acc_pubkey),
+            ..initialized_plane(2, 1, 2)
+        };
+        let mut land_plane_acc = fake_plane_account(&land_plane);
+
+        // piece of land minted at (1, 1), bound to an nft that has since
+        // been lost, and indexed by its mint
+        let coordinate = Coordinate { x: 1, z: 1 };
+        let (land_asset_acc_pubkey, _) = land_plane.land_asset_address(
+            &program_id,
+            &land_plane_acc_pubkey,
+            coordinate,
+        );
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut land_asset_acc = fake_asset_account(&LandAsset{
+            land_plane: land_plane_acc_pubkey,
+            coordinate,
+            ..initialized_asset(&nft_mint_acc_pubkey)
+        });
+        let land_asset_by_mint = initialized_land_asset_by_mint(
+            &land_plane_acc_pubkey,
+            &land_asset_acc_pubkey,
+            coordinate,
+        );
+        let mut land_asset_by_mint_acc = fake_land_asset_by_mint_account(&land_asset_by_mint);
+
+        // reissued nft to which the land is to be bound
+        let new_nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut new_nft_mint_acc = new_nft_mint_account();
+        let mut new_land_asset_by_mint_acc = land_asset_by_mint_account();
+
+        let instruction = admin_relink_parcel(
+            &program_id,
+            &land_plane_owner_acc_pubkey,
+            &land_asset_acc_pubkey,
+            &land_plane_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            &new_nft_mint_acc_pubkey,
+        ).unwrap();
+
+        //
+        // signer is not the owner of the land plane
+        //
+        let mut not_owner_acc = SolanaAccount::new(1_000_000, 0, &system_program::id());
+        assert_eq!(
+            Err(LandError::LandPlaneOwnerMismatch.into()),
+            do_process_instruction(
+                admin_relink_parcel(
+                    &program_id,
+                    &Pubkey::new_unique(),
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    &nft_mint_acc_pubkey,
+                    &new_nft_mint_acc_pubkey,
+                ).unwrap(),
+                vec![
+                    &mut not_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut new_nft_mint_acc,
+                    &mut new_land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+        );
+
+        //
+        // land has been locked
+        //
+        let mut locked_land_asset_acc = fake_asset_account(&LandAsset{
+            land_plane: land_plane_acc_pubkey,
+            coordinate,
+            locked_by: Pubkey::new_unique(),
+            ..initialized_asset(&nft_mint_acc_pubkey)
+        });
+        assert_eq!(
+            Err(LandError::ParcelLocked.into()),
+            do_process_instruction(
+                instruction.clone(),
+                vec![
+                    &mut land_plane_owner_acc,
+                    &mut locked_land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut new_nft_mint_acc,
+                    &mut new_land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+        );
+
+        //
+        // land is the primary parcel of an estate
+        //
+        let mut merged_land_asset_acc = fake_asset_account(&LandAsset{
+            land_plane: land_plane_acc_pubkey,
+            coordinate,
+            estate_footprint: 2,
+            ..initialized_asset(&nft_mint_acc_pubkey)
+        });
+        assert_eq!(
+            Err(LandError::ParcelAlreadyMerged.into()),
+            do_process_instruction(
+                instruction.clone(),
+                vec![
+                    &mut land_plane_owner_acc,
+                    &mut merged_land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut new_nft_mint_acc,
+                    &mut new_land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+        );
+
+        //
+        // new mint is not that of an nft
+        //
+        let mut fungible_mint_acc = SolanaAccount::new(1, Mint::LEN, &spl_token::id());
+        Mint{
+            mint_authority: COption::None,
+            supply: 1_000_000,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        }.pack_into_slice(&mut fungible_mint_acc.data);
+        assert_eq!(
+            Err(LandError::NotAnNft.into()),
+            do_process_instruction(
+                instruction.clone(),
+                vec![
+                    &mut land_plane_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut fungible_mint_acc,
+                    &mut new_land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+        );
+
+        // instruction completes successfully without the signature
+        // of the holder of the lost nft
+        let land_asset_by_mint_acc_lamports = land_asset_by_mint_acc.lamports;
+        do_process_instruction(
+            instruction,
+            vec![
+                &mut land_plane_owner_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut land_asset_by_mint_acc,
+                &mut new_nft_mint_acc,
+                &mut new_land_asset_by_mint_acc,
+                &mut rent_sysvar,
+                &mut system_program_acc,
+                ]
+        )
+        .unwrap();
+
+        // land is bound to the new nft
+        let land_asset = LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap();
+        assert_eq!(new_nft_mint_acc_pubkey, land_asset.mint_pubkey);
+        assert_eq!(coordinate, land_asset.coordinate);
+
+        // the index has moved to the new nft mint
+        assert_eq!(
+            land_asset_by_mint,
+            LandAssetByMint::try_from_slice(&new_land_asset_by_mint_acc.data).unwrap(),
+        );
+
+        // and the index of the lost nft mint is closed,
+        // with its rent returned to the land plane owner
+        assert_eq!(0, land_asset_by_mint_acc.lamports);
+        assert_eq!(1_000_000 + land_asset_by_mint_acc_lamports, land_plane_owner_acc.lamports);
+    }
+
+    #[test]
+    fn test_transfer_land_keeps_index_of_other_land() {
+        let program_id = crate::id();
+
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(1_000_000, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+        let mut rent_sysvar = rent_sysvar();
+
+        let holder_acc_pubkey = Pubkey::new_unique();
+        let mut holder_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        // piece of land at (3, 1) bound to an nft which was later
+        // bound to another piece of land at (4, 0) as well
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_initialized_plane(3, 1, 3);
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let (land_asset_acc_pubkey, _) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+        let mut land_asset_acc = fake_initialized_asset(&nft_mint_acc_pubkey);
+        let other_land_asset_by_mint = initialized_land_asset_by_mint(
+            &land_plane_acc_pubkey,
+            &Pubkey::new_unique(),
+            Coordinate { x: 4, z: 0 },
+        );
+        let mut land_asset_by_mint_acc = fake_land_asset_by_mint_account(&other_land_asset_by_mint);
+
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = nft_token_account(
+            &holder_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+
+        let new_nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut new_nft_mint_acc = new_nft_mint_account();
+        let mut new_land_asset_by_mint_acc = land_asset_by_mint_account();
+
+        // instruction completes successfully
+        do_process_instruction(
+            transfer_land(
+                &program_id,
+                &payer_acc_pubkey,
+                &holder_acc_pubkey,
+                &land_asset_acc_pubkey,
+                &land_plane_acc_pubkey,
+                &nft_assoc_token_acc_pubkey,
+                &nft_mint_acc_pubkey,
+                &new_nft_mint_acc_pubkey,
+                None,
+                3,
+                1,
+                0,
+            ).unwrap(),
+            vec![
+                &mut payer_acc,
+                &mut holder_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut nft_assoc_token_acc,
+                &mut land_asset_by_mint_acc,
+                &mut new_nft_mint_acc,
+                &mut new_land_asset_by_mint_acc,
+                &mut rent_sysvar,
+                &mut system_program_acc,
+                ]
+        )
+        .unwrap();
+
+        // the new nft mint is indexed to the transferred land
+        assert_eq!(
+            land_asset_acc_pubkey,
+            LandAssetByMint::try_from_slice(&new_land_asset_by_mint_acc.data).unwrap().land_asset,
+        );
+
+        // while the old nft mint is still indexed to its other land
+        assert_eq!(
+            other_land_asset_by_mint,
+            LandAssetByMint::try_from_slice(&land_asset_by_mint_acc.data).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_mint_next_one_parcel_per_nft() {
+        let program_id = crate::id();
+
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(1_000_000, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+
+        let protocol_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut protocol_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let (program_config_acc_pubkey, mut program_config_acc) =
+            program_config_account(&program_id, 0, &protocol_treasury_acc_pubkey);
+        let mut rent_sysvar = rent_sysvar();
+
+        let nft_assoc_token_acc_owner_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        // land plane allowing only one parcel per nft
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_plane_account(&LandPlane{
+            one_parcel_per_nft: true,
+            ..initialized_plane(2, 1, 2)
+        });
+
+        let (land_asset_acc_pubkey, mut land_asset_acc) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+
+        // nft already bound to other land on the land plane
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut nft_mint_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let mut land_asset_by_mint_acc = fake_land_asset_by_mint_account(&initialized_land_asset_by_mint(
+            &land_plane_acc_pubkey,
+            &Pubkey::new_unique(),
+            Coordinate { x: 1, z: 0 },
+        ));
+
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = nft_token_account(
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+
+        let (land_plane_stats_acc_pubkey, mut land_plane_stats_acc) =
+            land_plane_stats_account(&program_id, &land_plane_acc_pubkey);
+
+        let instruction = mint_next_land_asset(
+            &program_id,
+            &payer_acc_pubkey,
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            &land_asset_acc_pubkey,
+            &land_plane_acc_pubkey,
+            &nft_assoc_token_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            &land_plane_stats_acc_pubkey,
+            &program_config_acc_pubkey,
+            &protocol_treasury_acc_pubkey,
+            None,
+            None,
+        ).unwrap();
+
+        //
+        // nft already owns land
+        //
+        assert_eq!(
+            Err(LandError::NftAlreadyOwnsLand.into()),
+            do_process_instruction(
+                instruction.clone(),
+                vec![
+                    &mut payer_acc,
+                    &mut nft_assoc_token_acc_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    ]
+            )
+        );
+
+        // instruction completes successfully once the
+        // land plane allows more than one parcel per nft
+        let mut land_plane_acc = fake_initialized_plane(2, 1, 2);
+        do_process_instruction(
+            instruction,
+            vec![
+                &mut payer_acc,
+                &mut nft_assoc_token_acc_owner_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut nft_assoc_token_acc,
+                &mut nft_mint_acc,
+                &mut land_plane_stats_acc,
+                &mut system_program_acc,
+                &mut program_config_acc,
+                &mut protocol_treasury_acc,
+                &mut land_asset_by_mint_acc,
+                &mut rent_sysvar,
+                ]
+        )
+        .unwrap();
+
+        // the nft mint is indexed to the minted piece of land
+        assert_eq!(
+            land_asset_acc_pubkey,
+            LandAssetByMint::try_from_slice(&land_asset_by_mint_acc.data).unwrap().land_asset,
+        );
+    }
+
+    #[test]
+    fn test_mint_next_required_collection() {
+        let program_id = crate::id();
+
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(1_000_000, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+
+        let protocol_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut protocol_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let (program_config_acc_pubkey, mut program_config_acc) =
+            program_config_account(&program_id, 0, &protocol_treasury_acc_pubkey);
+        let mut land_asset_by_mint_acc = land_asset_by_mint_account();
+        let mut rent_sysvar = rent_sysvar();
+        let mut slot_hashes_sysvar = slot_hashes_sysvar([0; 32]);
+
+        let nft_assoc_token_acc_owner_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        // land plane requiring nfts in a collection
+        let collection_pubkey = Pubkey::new_unique();
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_plane_account(&LandPlane{
+            required_collection: Some(collection_pubkey),
+            ..initialized_plane(2, 1, 2)
+        });
+
+        let (land_asset_acc_pubkey, mut land_asset_acc) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut nft_mint_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = nft_token_account(
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+
+        let (land_plane_stats_acc_pubkey, mut land_plane_stats_acc) =
+            land_plane_stats_account(&program_id, &land_plane_acc_pubkey);
+
+        let instruction = mint_next_land_asset(
+            &program_id,
+            &payer_acc_pubkey,
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            &land_asset_acc_pubkey,
+            &land_plane_acc_pubkey,
+            &nft_assoc_token_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            &land_plane_stats_acc_pubkey,
+            &program_config_acc_pubkey,
+            &protocol_treasury_acc_pubkey,
+            None,
+            None,
+        ).unwrap();
+        assert_eq!(metadata_acc_pubkey(&nft_mint_acc_pubkey), instruction.accounts[13].pubkey);
+
+        //
+        // nft metadata account not given
+        //
+        assert_eq!(
+            Err(ProgramError::NotEnoughAccountKeys),
+            do_process_instruction(
+                instruction.clone(),
+                vec![
+                    &mut payer_acc,
+                    &mut nft_assoc_token_acc_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    &mut slot_hashes_sysvar,
+                    ]
+            )
+        );
+
+        //
+        // nft with no metadata account
+        //
+        let mut nft_metadata_acc = SolanaAccount::default();
+        assert_eq!(
+            Err(LandError::MissingMetadataAccount.into()),
+            do_process_instruction(
+                instruction.clone(),
+                vec![
+                    &mut payer_acc,
+                    &mut nft_assoc_token_acc_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    &mut slot_hashes_sysvar,
+                    &mut nft_metadata_acc,
+                    ]
+            )
+        );
+
+        //
+        // nft in another collection, or not verified in the collection
+        //
+        for collection in vec![
+            None,
+            Some(Collection { verified: true, key: Pubkey::new_unique() }),
+            Some(Collection { verified: false, key: collection_pubkey }),
+        ] {
+            let mut nft_metadata_acc =
+                nft_metadata_account(&Pubkey::new_unique(), &nft_mint_acc_pubkey, collection);
+            assert_eq!(
+                Err(LandError::WrongCollection.into()),
+                do_process_instruction(
+                    instruction.clone(),
+                    vec![
+                        &mut payer_acc,
+                        &mut nft_assoc_token_acc_owner_acc,
+                        &mut land_asset_acc,
+                        &mut land_plane_acc,
+                        &mut nft_assoc_token_acc,
+                        &mut nft_mint_acc,
+                        &mut land_plane_stats_acc,
+                        &mut system_program_acc,
+                        &mut program_config_acc,
+                        &mut protocol_treasury_acc,
+                        &mut land_asset_by_mint_acc,
+                        &mut rent_sysvar,
+                        &mut slot_hashes_sysvar,
+                        &mut nft_metadata_acc,
+                        ]
+                )
+            );
+        }
+
+        // instruction completes successfully for an nft in the collection
+        let mut nft_metadata_acc = nft_metadata_account(
+            &Pubkey::new_unique(),
+            &nft_mint_acc_pubkey,
+            Some(Collection { verified: true, key: collection_pubkey }),
+        );
+        do_process_instruction(
+            instruction,
+            vec![
+                &mut payer_acc,
+                &mut nft_assoc_token_acc_owner_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut nft_assoc_token_acc,
+                &mut nft_mint_acc,
+                &mut land_plane_stats_acc,
+                &mut system_program_acc,
+                &mut program_config_acc,
+                &mut protocol_treasury_acc,
+                &mut land_asset_by_mint_acc,
+                &mut rent_sysvar,
+                &mut slot_hashes_sysvar,
+                &mut nft_metadata_acc,
+                ]
+        )
+        .unwrap();
+        assert_eq!(
+            nft_mint_acc_pubkey,
+            LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap().mint_pubkey,
+        );
+    }
+
+    #[test]
+    fn test_mint_next_max_supply() {
+        let program_id = crate::id();
+
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+
+        let protocol_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut protocol_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let (program_config_acc_pubkey, mut program_config_acc) =
+            program_config_account(&program_id, 0, &protocol_treasury_acc_pubkey);
+        let mut land_asset_by_mint_acc = land_asset_by_mint_account();
+        let mut rent_sysvar = rent_sysvar();
+
+        let nft_assoc_token_acc_owner_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        // unbounded land plane with a max supply of 5 pieces of land
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_plane_account(&LandPlane{
+            max_supply: Some(5),
+            ..initialized_plane(0, 0, 0)
+        });
+
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut nft_mint_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = nft_token_account(
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+
+        let (land_plane_stats_acc_pubkey, mut land_plane_stats_acc) =
+            land_plane_stats_account(&program_id, &land_plane_acc_pubkey);
+
+        //
+        // land can be minted up to the max supply
+        //
+        for _ in 0..5 {
+            let (land_asset_acc_pubkey, mut land_asset_acc) =
+                next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+            do_process_instruction(
+                mint_next_land_asset(
+                    &program_id,
+                    &payer_acc_pubkey,
+                    &nft_assoc_token_acc_owner_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    &nft_assoc_token_acc_pubkey,
+                    &nft_mint_acc_pubkey,
+                    &land_plane_stats_acc_pubkey,
+                    &program_config_acc_pubkey,
+                    &protocol_treasury_acc_pubkey,
+                    None,
+                    None,
+                ).unwrap(),
+                vec![
+                    &mut payer_acc,
+                    &mut nft_assoc_token_acc_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    ]
+            )
+            .unwrap();
+        }
+        let land_plane = LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap();
+        assert_eq!(5, land_plane.minted_count());
+
+        //
+        // but no further, though the shells could grow
+        //
+        let (land_asset_acc_pubkey, mut land_asset_acc) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+        assert_eq!(
+            Err(LandError::MaxSupplyReached.into()),
+            do_process_instruction(
+                mint_next_land_asset(
+                    &program_id,
+                    &payer_acc_pubkey,
+                    &nft_assoc_token_acc_owner_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    &nft_assoc_token_acc_pubkey,
+                    &nft_mint_acc_pubkey,
+                    &land_plane_stats_acc_pubkey,
+                    &program_config_acc_pubkey,
+                    &protocol_treasury_acc_pubkey,
+                    None,
+                    None,
+                ).unwrap(),
+                vec![
+                    &mut payer_acc,
+                    &mut nft_assoc_token_acc_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    ]
+            )
+        );
+        assert_eq!(land_plane, LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap());
+    }
+
+    #[test]
+    fn test_mint_next_mint_cooldown() {
+        let program_id = crate::id();
+
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+
+        let protocol_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut protocol_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let (program_config_acc_pubkey, mut program_config_acc) =
+            program_config_account(&program_id, 0, &protocol_treasury_acc_pubkey);
+        let mut land_asset_by_mint_acc = land_asset_by_mint_account();
+        let mut rent_sysvar = rent_sysvar();
+        let mut slot_hashes_sysvar = slot_hashes_sysvar([0; 32]);
+        let mut nft_metadata_acc = SolanaAccount::default();
+
+        let nft_assoc_token_acc_owner_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        // land plane with a mint cooldown of 10 slots
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_plane_account(&LandPlane{
+            mint_cooldown_slots: 10,
+            ..initialized_plane(0, 0, 0)
+        });
+
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut nft_mint_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = nft_token_account(
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+
+        let (land_plane_stats_acc_pubkey, mut land_plane_stats_acc) =
+            land_plane_stats_account(&program_id, &land_plane_acc_pubkey);
+
+        // the recipient has not yet been minted land from the land plane
+        let mut wallet_mint_record_acc = SolanaAccount::new(
+            Rent::default().minimum_balance(WALLET_MINT_RECORD_ACC_DATA_LEN),
+            WALLET_MINT_RECORD_ACC_DATA_LEN,
+            &program_id,
+        );
+
+        let instruction = |land_asset_acc_pubkey: &Pubkey| mint_next_land_asset(
+            &program_id,
+            &payer_acc_pubkey,
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            land_asset_acc_pubkey,
+            &land_plane_acc_pubkey,
+            &nft_assoc_token_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            &land_plane_stats_acc_pubkey,
+            &program_config_acc_pubkey,
+            &protocol_treasury_acc_pubkey,
+            None,
+            None,
+        ).unwrap();
+
+        //
+        // clock sysvar and wallet mint record not given
+        //
+        let (land_asset_acc_pubkey, mut land_asset_acc) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+        assert_eq!(
+            Err(ProgramError::NotEnoughAccountKeys),
+            do_process_instruction(
+                instruction(&land_asset_acc_pubkey),
+                vec![
+                    &mut payer_acc,
+                    &mut nft_assoc_token_acc_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    &mut slot_hashes_sysvar,
+                    &mut nft_metadata_acc,
+                    ]
+            )
+        );
+
+        //
+        // wallet mint record is not that of the recipient
+        //
+        let mut wrong_record_instruction = instruction(&land_asset_acc_pubkey);
+        wrong_record_instruction.accounts[15].pubkey = wallet_mint_record_acc_pubkey(
+            &program_id,
+            &land_plane_acc_pubkey,
+            &payer_acc_pubkey,
+        );
+        let mut clock_sysvar_acc = clock_sysvar(100);
+        assert_eq!(
+            Err(LandError::InvalidWalletMintRecordAccKey.into()),
+            do_process_instruction(
+                wrong_record_instruction,
+                vec![
+                    &mut payer_acc,
+                    &mut nft_assoc_token_acc_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    &mut slot_hashes_sysvar,
+                    &mut nft_metadata_acc,
+                    &mut clock_sysvar_acc,
+                    &mut wallet_mint_record_acc,
+                    ]
+            )
+        );
+
+        //
+        // first mint at slot 100, again within the cooldown at slot 109,
+        // and once the cooldown has passed at slot 110
+        //
+        for (slot, expected) in vec![
+            (100, Ok(())),
+            (109, Err(LandError::MintCooldownActive.into())),
+            (110, Ok(())),
+        ] {
+            let (land_asset_acc_pubkey, mut land_asset_acc) =
+                next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+            let land_plane = LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap();
+            let mut clock_sysvar_acc = clock_sysvar(slot);
+            assert_eq!(
+                expected,
+                do_process_instruction(
+                    instruction(&land_asset_acc_pubkey),
+                    vec![
+                        &mut payer_acc,
+                        &mut nft_assoc_token_acc_owner_acc,
+                        &mut land_asset_acc,
+                        &mut land_plane_acc,
+                        &mut nft_assoc_token_acc,
+                        &mut nft_mint_acc,
+                        &mut land_plane_stats_acc,
+                        &mut system_program_acc,
+                        &mut program_config_acc,
+                        &mut protocol_treasury_acc,
+                        &mut land_asset_by_mint_acc,
+                        &mut rent_sysvar,
+                        &mut slot_hashes_sysvar,
+                        &mut nft_metadata_acc,
+                        &mut clock_sysvar_acc,
+                        &mut wallet_mint_record_acc,
+                        ]
+                ),
+                "slot {}", slot,
+            );
+            if expected.is_err() {
+                assert_eq!(land_plane, LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap());
+            }
+        }
+        assert_eq!(2, LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap().minted_count());
+        assert_eq!(
+            WalletMintRecord {
+                version: WalletMintRecordVersion::V1,
+                last_mint_slot: 110,
+            },
+            WalletMintRecord::deserialize(&mut &wallet_mint_record_acc.data[..]).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_transfer_land_one_parcel_per_nft() {
+        let program_id = crate::id();
+
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(1_000_000, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+        let mut rent_sysvar = rent_sysvar();
+
+        let holder_acc_pubkey = Pubkey::new_unique();
+        let mut holder_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        // piece of land at (3, 1) on a land plane allowing only one parcel per nft
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_plane_account(&LandPlane{
+            one_parcel_per_nft: true,
+            ..initialized_plane(3, 1, 3)
+        });
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let (land_asset_acc_pubkey, _) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+        let mut land_asset_acc = fake_initialized_asset(&nft_mint_acc_pubkey);
+        let mut land_asset_by_mint_acc = fake_land_asset_by_mint_account(&initialized_land_asset_by_mint(
+            &land_plane_acc_pubkey,
+            &land_asset_acc_pubkey,
+            Coordinate { x: 3, z: 1 },
+        ));
+
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = nft_token_account(
+            &holder_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+
+        // nft to which the land is to be bound, already bound to other land
+        let new_nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut new_nft_mint_acc = new_nft_mint_account();
+        let mut new_land_asset_by_mint_acc = fake_land_asset_by_mint_account(&initialized_land_asset_by_mint(
+            &land_plane_acc_pubkey,
+            &Pubkey::new_unique(),
+            Coordinate { x: 1, z: 0 },
+        ));
+
+        let instruction = transfer_land(
+            &program_id,
+            &payer_acc_pubkey,
+            &holder_acc_pubkey,
+            &land_asset_acc_pubkey,
+            &land_plane_acc_pubkey,
+            &nft_assoc_token_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            &new_nft_mint_acc_pubkey,
+            None,
+            3,
+            1,
+            0,
+        ).unwrap();
+
+        //
+        // new nft already owns land
+        //
+        assert_eq!(
+            Err(LandError::NftAlreadyOwnsLand.into()),
+            do_process_instruction(
+                instruction.clone(),
+                vec![
+                    &mut payer_acc,
+                    &mut holder_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut new_nft_mint_acc,
+                    &mut new_land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+        );
+
+        // instruction completes successfully once the
+        // land plane allows more than one parcel per nft
+        let mut land_plane_acc = fake_initialized_plane(3, 1, 3);
+        do_process_instruction(
+            instruction,
+            vec![
+                &mut payer_acc,
+                &mut holder_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut nft_assoc_token_acc,
+                &mut land_asset_by_mint_acc,
+                &mut new_nft_mint_acc,
+                &mut new_land_asset_by_mint_acc,
+                &mut rent_sysvar,
+                &mut system_program_acc,
+                ]
+        )
+        .unwrap();
+
+        // land is bound to the new nft
+        let land_asset = LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap();
+        assert_eq!(new_nft_mint_acc_pubkey, land_asset.mint_pubkey);
+        assert_eq!(
+            land_asset_acc_pubkey,
+            LandAssetByMint::try_from_slice(&new_land_asset_by_mint_acc.data).unwrap().land_asset,
+        );
+    }
+
+    #[test]
+    fn test_transfer_land_royalty() {
+        let program_id = crate::id();
+
+        // payer short of the royalty of 2.5% on a sale for 1_000_000
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(24_999, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+        let mut rent_sysvar = rent_sysvar();
+
+        let holder_acc_pubkey = Pubkey::new_unique();
+        let mut holder_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let plane_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut plane_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        // piece of land at (3, 1) on a land plane charging a royalty of 250 bps
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_plane_account(&LandPlane{
+            royalty_bps: 250,
+            plane_treasury: Some(plane_treasury_acc_pubkey),
+            ..initialized_plane(3, 1, 3)
+        });
+        let land_plane_acc_lamports = land_plane_acc.lamports;
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let (land_asset_acc_pubkey, _) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+        let mut land_asset_acc = fake_initialized_asset(&nft_mint_acc_pubkey);
+        let mut land_asset_by_mint_acc = land_asset_by_mint_account();
+
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = nft_token_account(
+            &holder_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+
+        let new_nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut new_nft_mint_acc = new_nft_mint_account();
+        let mut new_land_asset_by_mint_acc = land_asset_by_mint_account();
+
+        let instruction = transfer_land(
+            &program_id,
+            &payer_acc_pubkey,
+            &holder_acc_pubkey,
+            &land_asset_acc_pubkey,
+            &land_plane_acc_pubkey,
+            &nft_assoc_token_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            &new_nft_mint_acc_pubkey,
+            Some(&plane_treasury_acc_pubkey),
+            3,
+            1,
+            1_000_000,
+        ).unwrap();
+
+        //
+        // payer cannot cover the royalty
+        //
+        assert_eq!(
+            Err(LandError::InsufficientRoyaltyFunds.into()),
+            do_process_instruction(
+                instruction.clone(),
+                vec![
+                    &mut payer_acc,
+                    &mut holder_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut new_nft_mint_acc,
+                    &mut new_land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+        );
+
+        // instruction completes successfully once the payer is funded
+        payer_acc.lamports = 25_000;
+        do_process_instruction(
+            instruction,
+            vec![
+                &mut payer_acc,
+                &mut holder_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut nft_assoc_token_acc,
+                &mut land_asset_by_mint_acc,
+                &mut new_nft_mint_acc,
+                &mut new_land_asset_by_mint_acc,
+                &mut rent_sysvar,
+                &mut system_program_acc,
+                &mut plane_treasury_acc,
+                ]
+        )
+        .unwrap();
+
+        // and the royalty is paid to the land plane's treasury, not held
+        // by the land plane account
+        assert_eq!(0, payer_acc.lamports);
+        assert_eq!(25_001, plane_treasury_acc.lamports);
+        assert_eq!(land_plane_acc_lamports, land_plane_acc.lamports);
+        let land_asset = LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap();
+        assert_eq!(new_nft_mint_acc_pubkey, land_asset.mint_pubkey);
+    }
+
+    #[test]
+    fn test_transfer_land_zero_royalty() {
+        let program_id = crate::id();
+
+        // payer holding no lamports
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(0, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+        let mut rent_sysvar = rent_sysvar();
+
+        let holder_acc_pubkey = Pubkey::new_unique();
+        let mut holder_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        // piece of land at (3, 1) on a land plane charging no royalty
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_initialized_plane(3, 1, 3);
+        let land_plane_acc_lamports = land_plane_acc.lamports;
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let (land_asset_acc_pubkey, _) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+        let mut land_asset_acc = fake_initialized_asset(&nft_mint_acc_pubkey);
+        let mut land_asset_by_mint_acc = land_asset_by_mint_account();
+
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = nft_token_account(
+            &holder_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+
+        let new_nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut new_nft_mint_acc = new_nft_mint_account();
+        let mut new_land_asset_by_mint_acc = land_asset_by_mint_account();
+
+        // instruction completes successfully whatever the sale price
+        do_process_instruction(
+            transfer_land(
+                &program_id,
+                &payer_acc_pubkey,
+                &holder_acc_pubkey,
+                &land_asset_acc_pubkey,
+                &land_plane_acc_pubkey,
+                &nft_assoc_token_acc_pubkey,
+                &nft_mint_acc_pubkey,
+                &new_nft_mint_acc_pubkey,
+                None,
+                3,
+                1,
+                1_000_000,
+            ).unwrap(),
+            vec![
+                &mut payer_acc,
+                &mut holder_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut nft_assoc_token_acc,
+                &mut land_asset_by_mint_acc,
+                &mut new_nft_mint_acc,
+                &mut new_land_asset_by_mint_acc,
+                &mut rent_sysvar,
+                &mut system_program_acc,
+                ]
+        )
+        .unwrap();
+
+        // without any royalty being paid
+        assert_eq!(0, payer_acc.lamports);
+        assert_eq!(land_plane_acc_lamports, land_plane_acc.lamports);
+        let land_asset = LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap();
+        assert_eq!(new_nft_mint_acc_pubkey, land_asset.mint_pubkey);
+    }
+
+    #[test]
+    fn test_transfer_land_closed_index_not_reused() {
+        let program_id = crate::id();
+
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(1_000_000, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+        let mut rent_sysvar = rent_sysvar();
+
+        let protocol_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut protocol_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let (program_config_acc_pubkey, mut program_config_acc) =
+            program_config_account(&program_id, 0, &protocol_treasury_acc_pubkey);
+
+        let holder_acc_pubkey = Pubkey::new_unique();
+        let mut holder_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        // piece of land at (3, 1) on a land plane allowing only one
+        // parcel per nft, bound to an nft and indexed by its mint
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_plane_account(&LandPlane{
+            one_parcel_per_nft: true,
+            ..initialized_plane(3, 1, 3)
+        });
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut nft_mint_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let (land_asset_acc_pubkey, _) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+        let mut land_asset_acc = fake_initialized_asset(&nft_mint_acc_pubkey);
+        let mut land_asset_by_mint_acc = fake_land_asset_by_mint_account(&initialized_land_asset_by_mint(
+            &land_plane_acc_pubkey,
+            &land_asset_acc_pubkey,
+            Coordinate { x: 3, z: 1 },
+        ));
+        let land_asset_by_mint_acc_lamports = land_asset_by_mint_acc.lamports;
+
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = nft_token_account(
+            &holder_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+
+        let new_nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut new_nft_mint_acc = new_nft_mint_account();
+        let mut new_land_asset_by_mint_acc = land_asset_by_mint_account();
+
+        // the land is transferred to the new nft, closing the index of the old
+        do_process_instruction(
+            transfer_land(
+                &program_id,
+                &payer_acc_pubkey,
+                &holder_acc_pubkey,
+                &land_asset_acc_pubkey,
+                &land_plane_acc_pubkey,
+                &nft_assoc_token_acc_pubkey,
+                &nft_mint_acc_pubkey,
+                &new_nft_mint_acc_pubkey,
+                None,
+                3,
+                1,
+                0,
+            ).unwrap(),
+            vec![
+                &mut payer_acc,
+                &mut holder_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut nft_assoc_token_acc,
+                &mut land_asset_by_mint_acc,
+                &mut new_nft_mint_acc,
+                &mut new_land_asset_by_mint_acc,
+                &mut rent_sysvar,
+                &mut system_program_acc,
+                ]
+        )
+        .unwrap();
+        assert_eq!(0, land_asset_by_mint_acc.lamports);
+        assert!(land_asset_by_mint_acc.data.iter().all(|b| *b == 0));
+
+        // a later instruction in the same transaction refunds the closed
+        // index so that it is not removed at the end of the transaction
+        land_asset_by_mint_acc.lamports = land_asset_by_mint_acc_lamports;
+
+        // the old nft is then used to mint the next piece of land at (3, 2)
+        let mut land_plane_acc = fake_plane_account(&LandPlane{
+            one_parcel_per_nft: true,
+            ..initialized_plane(3, 2, 3)
+        });
+        let (next_land_asset_acc_pubkey, mut next_land_asset_acc) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+        let (land_plane_stats_acc_pubkey, mut land_plane_stats_acc) =
+            land_plane_stats_account(&program_id, &land_plane_acc_pubkey);
+
+        // which succeeds, as the refunded index is not
+        // treated as still binding the nft to land
+        do_process_instruction(
+            mint_next_land_asset(
+                &program_id,
+                &payer_acc_pubkey,
+                &holder_acc_pubkey,
+                &next_land_asset_acc_pubkey,
+                &land_plane_acc_pubkey,
+                &nft_assoc_token_acc_pubkey,
+                &nft_mint_acc_pubkey,
+                &land_plane_stats_acc_pubkey,
+                &program_config_acc_pubkey,
+                &protocol_treasury_acc_pubkey,
+                None,
+                None,
+            ).unwrap(),
+            vec![
+                &mut payer_acc,
+                &mut holder_acc,
+                &mut next_land_asset_acc,
+                &mut land_plane_acc,
+                &mut nft_assoc_token_acc,
+                &mut nft_mint_acc,
+                &mut land_plane_stats_acc,
+                &mut system_program_acc,
+                &mut program_config_acc,
+                &mut protocol_treasury_acc,
+                &mut land_asset_by_mint_acc,
+                &mut rent_sysvar,
+                ]
+        )
+        .unwrap();
+
+        // and re-initialises it afresh, indexed to the newly minted land
+        assert_eq!(
+            LandAssetByMint{
+                version: LandAssetByMintVersion::V1,
+                land_plane: land_plane_acc_pubkey,
+                land_asset: next_land_asset_acc_pubkey,
+                coordinate: Coordinate { x: 3, z: 2 },
+            },
+            LandAssetByMint::try_from_slice(&land_asset_by_mint_acc.data).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_repair_parcel_coordinate() {
+        let program_id = crate::id();
+
+        let land_plane_owner_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        // land plane owned by the owner, minted up to but not including (2, 1)
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_plane_account(&LandPlane{
+            owner: Some(land_plane_owner_acc_pubkey),
+            ..initialized_plane(2, 1, 2)
+        });
+
+        // piece of land at (1, 1), minted before the co-ordinate was
+        // recorded on the land asset, so that it reads as (0, 0)
+        let land_asset_acc_pubkey = Pubkey::find_program_address(
+            &[
+                LAND_ASSET_ACC_PREFIX.as_bytes(),
+                land_plane_acc_pubkey.as_ref(),
+                &1u64.to_le_bytes(),
+                &1u64.to_le_bytes(),
+            ],
+            &program_id,
+        ).0;
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let minter_acc_pubkey = Pubkey::new_unique();
+        let mut land_asset_acc = fake_asset_account(&LandAsset{
+            version: LandAssetVersion::V2,
+            minter: minter_acc_pubkey,
+            ..initialized_asset(&nft_mint_acc_pubkey)
+        });
+        let zeroed_data = land_asset_acc.data.clone();
+
+        //
+        // land plane owner has not signed
+        //
+        let mut instruction = repair_parcel_coordinate(
+            &program_id,
+            &land_plane_owner_acc_pubkey,
+            &land_asset_acc_pubkey,
+            &land_plane_acc_pubkey,
+        ).unwrap();
+        instruction.accounts[0].is_signer = false;
+        assert_eq!(
+            Err(LandError::MissingSignature.into()),
+            do_process_instruction(
+                instruction,
+                vec![&mut land_plane_owner_acc, &mut land_asset_acc, &mut land_plane_acc],
+            )
+        );
+
+        //
+        // signer is not the owner of the land plane
+        //
+        let other_acc_pubkey = Pubkey::new_unique();
+        assert_eq!(
+            Err(LandError::LandPlaneOwnerMismatch.into()),
+            do_process_instruction(
+                repair_parcel_coordinate(
+                    &program_id,
+                    &other_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                ).unwrap(),
+                vec![&mut land_plane_owner_acc, &mut land_asset_acc, &mut land_plane_acc],
+            )
+        );
+
+        //
+        // land plane without an owner cannot be repaired by anyone
+        //
+        let mut unowned_land_plane_acc = fake_initialized_plane(2, 1, 2);
+        assert_eq!(
+            Err(LandError::LandPlaneOwnerMismatch.into()),
+            do_process_instruction(
+                repair_parcel_coordinate(
+                    &program_id,
+                    &land_plane_owner_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                ).unwrap(),
+                vec![&mut land_plane_owner_acc, &mut land_asset_acc, &mut unowned_land_plane_acc],
+            )
+        );
+
+        //
+        // land asset account of land not minted from the land plane
+        //
+        let unminted_land_asset_acc_pubkey = Pubkey::find_program_address(
+            &[
+                LAND_ASSET_ACC_PREFIX.as_bytes(),
+                land_plane_acc_pubkey.as_ref(),
+                &2u64.to_le_bytes(),
+                &1u64.to_le_bytes(),
+            ],
+            &program_id,
+        ).0;
+        assert_eq!(
+            Err(LandError::InvalidLandAssetAccKey.into()),
+            do_process_instruction(
+                repair_parcel_coordinate(
+                    &program_id,
+                    &land_plane_owner_acc_pubkey,
+                    &unminted_land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                ).unwrap(),
+                vec![&mut land_plane_owner_acc, &mut land_asset_acc, &mut land_plane_acc],
+            )
+        );
+        assert_eq!(zeroed_data, land_asset_acc.data);
+
+        //
+        // land asset account too short to hold the co-ordinate
+        //
+        let mut short_land_asset_acc = land_asset_acc.clone();
+        short_land_asset_acc.data.truncate(LAND_ASSET_V2_ACC_DATA_LEN);
+        assert_eq!(
+            Err(LandError::IncorrectDataSize.into()),
+            do_process_instruction(
+                repair_parcel_coordinate(
+                    &program_id,
+                    &land_plane_owner_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                ).unwrap(),
+                vec![&mut land_plane_owner_acc, &mut short_land_asset_acc, &mut land_plane_acc],
+            )
+        );
+
+        //
+        // zeroed co-ordinate is repaired to that of the land
+        //
+        do_process_instruction(
+            repair_parcel_coordinate(
+                &program_id,
+                &land_plane_owner_acc_pubkey,
+                &land_asset_acc_pubkey,
+                &land_plane_acc_pubkey,
+            ).unwrap(),
+            vec![&mut land_plane_owner_acc, &mut land_asset_acc, &mut land_plane_acc],
+        )
+        .unwrap();
+        assert_eq!(
+            LandAsset{
+                version: LandAssetVersion::V8,
+                mint_pubkey: nft_mint_acc_pubkey,
+                minter: minter_acc_pubkey,
+                land_plane: land_plane_acc_pubkey,
+                coordinate: Coordinate { x: 1, z: 1 },
+                attributes: 0,
+                merged_into: Pubkey::default(),
+                estate_footprint: 0,
+                bump: 0,
+                transfer_count: 0,
+                locked_by: Pubkey::default(),
+            },
+            LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap(),
+        );
+
+        //
+        // land recorded as minted from another land plane
+        //
+        let other_land_plane_acc_pubkey = Pubkey::new_unique();
+        assert_eq!(
+            Err(LandError::InvalidLandAssetAccKey.into()),
+            do_process_instruction(
+                repair_parcel_coordinate(
+                    &program_id,
+                    &land_plane_owner_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &other_land_plane_acc_pubkey,
+                ).unwrap(),
+                vec![&mut land_plane_owner_acc, &mut land_asset_acc, &mut land_plane_acc],
+            )
+        );
+    }
+
+    #[test]
+    fn test_set_parcel_attributes() {
+        let program_id = crate::id();
+
+        let land_plane_owner_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let land_plane = LandPlane{
+            owner: Some(land_plane_owner_acc_pubkey),
+            ..initialized_plane(2, 1, 2)
+        };
+        let mut land_plane_acc = fake_plane_account(&land_plane);
+
+        // piece of land minted at (1, 1)
+        let coordinate = Coordinate { x: 1, z: 1 };
+        let (land_asset_acc_pubkey, _) = land_plane.land_asset_address(
+            &program_id,
+            &land_plane_acc_pubkey,
+            coordinate,
+        );
+        let land_asset = LandAsset{
+            land_plane: land_plane_acc_pubkey,
+            coordinate,
+            ..initialized_asset(&Pubkey::new_unique())
+        };
+        let mut land_asset_acc = fake_asset_account(&land_asset);
+
+        //
+        // land plane owner has not signed
+        //
+        let mut instruction = set_parcel_attributes(
+            &program_id,
+            &land_plane_owner_acc_pubkey,
+            &land_plane_acc_pubkey,
+            &land_asset_acc_pubkey,
+            u32::MAX,
+            0b101,
+        ).unwrap();
+        instruction.accounts[0].is_signer = false;
+        assert_eq!(
+            Err(LandError::MissingSignature.into()),
+            do_process_instruction(
+                instruction,
+                vec![&mut land_plane_owner_acc, &mut land_asset_acc, &mut land_plane_acc],
+            )
+        );
+
+        //
+        // signer is not the owner of the land plane
+        //
+        assert_eq!(
+            Err(LandError::LandPlaneOwnerMismatch.into()),
+            do_process_instruction(
+                set_parcel_attributes(
+                    &program_id,
+                    &Pubkey::new_unique(),
+                    &land_plane_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    u32::MAX,
+                    0b101,
+                ).unwrap(),
+                vec![&mut land_plane_owner_acc, &mut land_asset_acc, &mut land_plane_acc],
+            )
+        );
+
+        //
+        // land asset account is not that of the land on the land plane
+        //
+        assert_eq!(
+            Err(LandError::InvalidLandAssetAccKey.into()),
+            do_process_instruction(
+                set_parcel_attributes(
+                    &program_id,
+                    &land_plane_owner_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    &Pubkey::new_unique(),
+                    u32::MAX,
+                    0b101,
+                ).unwrap(),
+                vec![&mut land_plane_owner_acc, &mut land_asset_acc, &mut land_plane_acc],
+            )
+        );
+
+        //
+        // land asset account allocated at the V3 length is too short
+        // to hold the attributes
+        //
+        let mut short_land_asset_acc = fake_asset_account(&LandAsset{
+            version: LandAssetVersion::V3,
+            ..land_asset.clone()
+        });
+        short_land_asset_acc.data.truncate(LAND_ASSET_V3_ACC_DATA_LEN);
+        assert_eq!(
+            Err(LandError::IncorrectDataSize.into()),
+            do_process_instruction(
+                set_parcel_attributes(
+                    &program_id,
+                    &land_plane_owner_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    u32::MAX,
+                    0b101,
+                ).unwrap(),
+                vec![&mut land_plane_owner_acc, &mut short_land_asset_acc, &mut land_plane_acc],
+            )
+        );
+        assert_eq!(land_asset, LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap());
+
+        //
+        // attributes are set
+        //
+        do_process_instruction(
+            set_parcel_attributes(
+                &program_id,
+                &land_plane_owner_acc_pubkey,
+                &land_plane_acc_pubkey,
+                &land_asset_acc_pubkey,
+                u32::MAX,
+                0b101,
+            ).unwrap(),
+            vec![&mut land_plane_owner_acc, &mut land_asset_acc, &mut land_plane_acc],
+        )
+        .unwrap();
+        let updated = LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap();
+        assert_eq!(LandAsset{ attributes: 0b101, ..land_asset.clone() }, updated);
+        assert!(updated.has_attribute(0));
+        assert!(updated.has_attribute(2));
+
+        //
+        // and only the masked bits are cleared
+        //
+        do_process_instruction(
+            set_parcel_attributes(
+                &program_id,
+                &land_plane_owner_acc_pubkey,
+                &land_plane_acc_pubkey,
+                &land_asset_acc_pubkey,
+                0b1,
+                0,
+            ).unwrap(),
+            vec![&mut land_plane_owner_acc, &mut land_asset_acc, &mut land_plane_acc],
+        )
+        .unwrap();
+        let updated = LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap();
+        assert_eq!(LandAsset{ attributes: 0b100, ..land_asset }, updated);
+        assert!(!updated.has_attribute(0));
+        assert!(updated.has_attribute(2));
+    }
+
+    #[test]
+    fn test_mint_next_with_attributes() {
+        let program_id = crate::id();
+
+        let land_plane_owner_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let holder_acc_pubkey = Pubkey::new_unique();
+        let mut holder_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let mut nft_assoc_token_acc_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+
+        let protocol_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut protocol_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let (program_config_acc_pubkey, mut program_config_acc) =
+            program_config_account(&program_id, 0, &protocol_treasury_acc_pubkey);
+        let mut rent_sysvar = rent_sysvar();
+
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_plane_account(&LandPlane{
+            owner: Some(land_plane_owner_acc_pubkey),
+            ..initialized_plane(0, 0, 0)
+        });
+
+        let (land_plane_stats_acc_pubkey, mut land_plane_stats_acc) =
+            land_plane_stats_account(&program_id, &land_plane_acc_pubkey);
+
+        // the holder holds two NFTs, each to be bound to land
+        let nft_mint_acc_pubkeys = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let mut nft_mint_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_accs = [
+            nft_token_account(&holder_acc_pubkey, &nft_mint_acc_pubkeys[0], AccountState::Initialized),
+            nft_token_account(&holder_acc_pubkey, &nft_mint_acc_pubkeys[1], AccountState::Initialized),
+        ];
+
+        let instruction = |payer_acc_pubkey: &Pubkey, land_asset_acc_pubkey: &Pubkey, nft: usize, attributes: u32| {
+            mint_next_with_attributes(
+                &program_id,
+                payer_acc_pubkey,
+                &holder_acc_pubkey,
+                land_asset_acc_pubkey,
+                &land_plane_acc_pubkey,
+                &nft_assoc_token_acc_pubkey,
+                &nft_mint_acc_pubkeys[nft],
+                &land_plane_stats_acc_pubkey,
+                &program_config_acc_pubkey,
+                &protocol_treasury_acc_pubkey,
+                None,
+                None,
+                attributes,
+            ).unwrap()
+        };
+
+        //
+        // holder other than the land plane owner tags their own land
+        //
+        let (land_asset_acc_pubkey, mut land_asset_acc) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+        let mut land_asset_by_mint_acc = land_asset_by_mint_account();
+        assert_eq!(
+            Err(LandError::LandPlaneOwnerMismatch.into()),
+            do_process_instruction(
+                instruction(&holder_acc_pubkey, &land_asset_acc_pubkey, 0, 0b101),
+                vec![
+                    &mut holder_acc,
+                    &mut nft_assoc_token_acc_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_accs[0],
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    ]
+            )
+        );
+
+        //
+        // land plane owner tags the land it mints for the holder
+        //
+        do_process_instruction(
+            instruction(&land_plane_owner_acc_pubkey, &land_asset_acc_pubkey, 0, 0b101),
+            vec![
+                &mut land_plane_owner_acc,
+                &mut nft_assoc_token_acc_owner_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut nft_assoc_token_accs[0],
+                &mut nft_mint_acc,
+                &mut land_plane_stats_acc,
+                &mut system_program_acc,
+                &mut program_config_acc,
+                &mut protocol_treasury_acc,
+                &mut land_asset_by_mint_acc,
+                &mut rent_sysvar,
+                ]
+        )
+        .unwrap();
+        let land_asset = LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap();
+        assert_eq!(nft_mint_acc_pubkeys[0], land_asset.mint_pubkey);
+        assert_eq!(holder_acc_pubkey, land_asset.minter);
+        assert_eq!(0b101, land_asset.attributes);
+
+        //
+        // holder mints land without attributes as usual
+        //
+        let (land_asset_acc_pubkey, mut land_asset_acc) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+        let mut land_asset_by_mint_acc = land_asset_by_mint_account();
+        do_process_instruction(
+            instruction(&holder_acc_pubkey, &land_asset_acc_pubkey, 1, 0),
+            vec![
+                &mut holder_acc,
+                &mut nft_assoc_token_acc_owner_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut nft_assoc_token_accs[1],
+                &mut nft_mint_acc,
+                &mut land_plane_stats_acc,
+                &mut system_program_acc,
+                &mut program_config_acc,
+                &mut protocol_treasury_acc,
+                &mut land_asset_by_mint_acc,
+                &mut rent_sysvar,
+                ]
+        )
+        .unwrap();
+        let land_asset = LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap();
+        assert_eq!(nft_mint_acc_pubkeys[1], land_asset.mint_pubkey);
+        assert_eq!(0, land_asset.attributes);
+        assert_eq!(2, LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap().minted_count());
+    }
+
+    #[test]
+    fn test_mint_next_with_deadline() {
+        let program_id = crate::id();
+
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+
+        let protocol_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut protocol_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let (program_config_acc_pubkey, mut program_config_acc) =
+            program_config_account(&program_id, 0, &protocol_treasury_acc_pubkey);
+        let mut land_asset_by_mint_acc = land_asset_by_mint_account();
+        let mut rent_sysvar = rent_sysvar();
+        let mut slot_hashes_sysvar = slot_hashes_sysvar([0; 32]);
+        let mut nft_metadata_acc = SolanaAccount::default();
+
+        let nft_assoc_token_acc_owner_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_initialized_plane(0, 0, 0);
+        let (land_asset_acc_pubkey, mut land_asset_acc) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut nft_mint_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = nft_token_account(
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+
+        let (land_plane_stats_acc_pubkey, mut land_plane_stats_acc) =
+            land_plane_stats_account(&program_id, &land_plane_acc_pubkey);
+
+        // valid until slot 100
+        let instruction = mint_next_with_deadline(
+            &program_id,
+            &payer_acc_pubkey,
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            &land_asset_acc_pubkey,
+            &land_plane_acc_pubkey,
+            &nft_assoc_token_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            &land_plane_stats_acc_pubkey,
+            &program_config_acc_pubkey,
+            &protocol_treasury_acc_pubkey,
+            None,
+            None,
+            100,
+        ).unwrap();
+
+        //
+        // clock sysvar not given
+        //
+        assert_eq!(
+            Err(ProgramError::NotEnoughAccountKeys),
+            do_process_instruction(
+                instruction.clone(),
+                vec![
+                    &mut payer_acc,
+                    &mut nft_assoc_token_acc_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    &mut slot_hashes_sysvar,
+                    &mut nft_metadata_acc,
+                    ]
+            )
+        );
+
+        //
+        // deadline has passed
+        //
+        let mut clock_sysvar_acc = clock_sysvar(101);
+        assert_eq!(
+            Err(LandError::TransactionExpired.into()),
+            do_process_instruction(
+                instruction.clone(),
+                vec![
+                    &mut payer_acc,
+                    &mut nft_assoc_token_acc_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    &mut slot_hashes_sysvar,
+                    &mut nft_metadata_acc,
+                    &mut clock_sysvar_acc,
+                    ]
+            )
+        );
+        assert_eq!(0, LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap().minted_count());
+
+        //
+        // deadline has passed, with optional accounts ahead of the
+        // clock sysvar omitted
+        //
+        let mut without_optional_accounts = instruction.clone();
+        without_optional_accounts.accounts.retain(|account_meta| {
+            account_meta.pubkey != sysvar::slot_hashes::id()
+                && account_meta.pubkey != metadata_acc_pubkey(&nft_mint_acc_pubkey)
+        });
+        assert_eq!(
+            Err(LandError::TransactionExpired.into()),
+            do_process_instruction(
+                without_optional_accounts,
+                vec![
+                    &mut payer_acc,
+                    &mut nft_assoc_token_acc_owner_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    &mut clock_sysvar_acc,
+                    ]
+            )
+        );
+        assert_eq!(0, LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap().minted_count());
+
+        // instruction completes successfully up to and including the
+        // deadline slot
+        let mut clock_sysvar_acc = clock_sysvar(100);
+        do_process_instruction(
+            instruction,
+            vec![
+                &mut payer_acc,
+                &mut nft_assoc_token_acc_owner_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut nft_assoc_token_acc,
+                &mut nft_mint_acc,
+                &mut land_plane_stats_acc,
+                &mut system_program_acc,
+                &mut program_config_acc,
+                &mut protocol_treasury_acc,
+                &mut land_asset_by_mint_acc,
+                &mut rent_sysvar,
+                &mut slot_hashes_sysvar,
+                &mut nft_metadata_acc,
+                &mut clock_sysvar_acc,
+                ]
+        )
+        .unwrap();
+        assert_eq!(nft_mint_acc_pubkey, LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap().mint_pubkey);
+    }
+
+    #[test]
+    fn test_mint_next_from_v1_land_plane() {
+        let program_id = crate::id();
+
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+
+        let protocol_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut protocol_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let (program_config_acc_pubkey, mut program_config_acc) =
+            program_config_account(&program_id, 0, &protocol_treasury_acc_pubkey);
+        let mut land_asset_by_mint_acc = land_asset_by_mint_account();
+        let mut rent_sysvar = rent_sysvar();
+
+        let nft_assoc_token_acc_owner_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        // land plane account allocated at the V1 length, before any of
+        // the later fields were added
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let land_plane = LandPlane{
+            version: LandPlaneVersion::V1,
+            ..initialized_plane(2, 1, 2)
+        };
+        let mut land_plane_acc = fake_plane_account(&land_plane);
+        land_plane_acc.data.truncate(LAND_PLANE_V1_ACC_DATA_LEN);
+        let (land_asset_acc_pubkey, mut land_asset_acc) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut nft_mint_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = nft_token_account(
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+
+        let (land_plane_stats_acc_pubkey, mut land_plane_stats_acc) =
+            land_plane_stats_account(&program_id, &land_plane_acc_pubkey);
+
+        // instruction completes successfully
+        do_process_instruction(
+            mint_next_land_asset(
+                &program_id,
+                &payer_acc_pubkey,
+                &nft_assoc_token_acc_owner_acc_pubkey,
+                &land_asset_acc_pubkey,
+                &land_plane_acc_pubkey,
+                &nft_assoc_token_acc_pubkey,
+                &nft_mint_acc_pubkey,
+                &land_plane_stats_acc_pubkey,
+                &program_config_acc_pubkey,
+                &protocol_treasury_acc_pubkey,
+                None,
+                None,
+            ).unwrap(),
+            vec![
+                &mut payer_acc,
+                &mut nft_assoc_token_acc_owner_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut nft_assoc_token_acc,
+                &mut nft_mint_acc,
+                &mut land_plane_stats_acc,
+                &mut system_program_acc,
+                &mut program_config_acc,
+                &mut protocol_treasury_acc,
+                &mut land_asset_by_mint_acc,
+                &mut rent_sysvar,
+                ]
+        )
+        .unwrap();
+
+        // the land plane account keeps its length, with the cursor moved on
+        assert_eq!(LAND_PLANE_V1_ACC_DATA_LEN, land_plane_acc.data.len());
+        assert_eq!(
+            Ok(LandPlane{ next_x: 2, next_z: 2, ..land_plane }),
+            LandPlane::unpack_versioned(&land_plane_acc.data),
+        );
+        assert_eq!(
+            Coordinate { x: 2, z: 1 },
+            LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap().coordinate,
+        );
+    }
+
+    #[test]
+    fn test_mint_next_within_mint_window() {
+        let program_id = crate::id();
+
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+
+        let protocol_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut protocol_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let (program_config_acc_pubkey, mut program_config_acc) =
+            program_config_account(&program_id, 0, &protocol_treasury_acc_pubkey);
+        let mut land_asset_by_mint_acc = land_asset_by_mint_account();
+        let mut rent_sysvar = rent_sysvar();
+        let mut slot_hashes_sysvar = slot_hashes_sysvar([0; 32]);
+        let mut nft_metadata_acc = SolanaAccount::default();
+
+        let nft_assoc_token_acc_owner_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        // land plane open to mints from slot 50 to slot 100
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_plane_account(&LandPlane{
+            mint_start_slot: Some(50),
+            mint_end_slot: Some(100),
+            ..initialized_plane(0, 0, 0)
+        });
+        let (land_asset_acc_pubkey, mut land_asset_acc) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut nft_mint_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = nft_token_account(
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+
+        let (land_plane_stats_acc_pubkey, mut land_plane_stats_acc) =
+            land_plane_stats_account(&program_id, &land_plane_acc_pubkey);
+
+        let instruction = mint_next_land_asset(
+            &program_id,
+            &payer_acc_pubkey,
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            &land_asset_acc_pubkey,
+            &land_plane_acc_pubkey,
+            &nft_assoc_token_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            &land_plane_stats_acc_pubkey,
+            &program_config_acc_pubkey,
+            &protocol_treasury_acc_pubkey,
+            None,
+            None,
+        ).unwrap();
+
+        for (slot, expected) in vec![
+            // before the window opens
+            (49, Err(LandError::MintNotStarted.into())),
+            // after the window closes
+            (101, Err(LandError::MintEnded.into())),
+            // within the window, whose ends are inclusive
+            (50, Ok(())),
+        ] {
+            let mut clock_sysvar_acc = clock_sysvar(slot);
+            assert_eq!(
+                expected,
+                do_process_instruction(
+                    instruction.clone(),
+                    vec![
+                        &mut payer_acc,
+                        &mut nft_assoc_token_acc_owner_acc,
+                        &mut land_asset_acc,
+                        &mut land_plane_acc,
+                        &mut nft_assoc_token_acc,
+                        &mut nft_mint_acc,
+                        &mut land_plane_stats_acc,
+                        &mut system_program_acc,
+                        &mut program_config_acc,
+                        &mut protocol_treasury_acc,
+                        &mut land_asset_by_mint_acc,
+                        &mut rent_sysvar,
+                        &mut slot_hashes_sysvar,
+                        &mut nft_metadata_acc,
+                        &mut clock_sysvar_acc,
+                        ]
+                ),
+                "slot {}",
+                slot,
+            );
+        }
+        assert_eq!(1, LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap().minted_count());
+    }
+
+    #[test]
+    fn test_set_mint_window() {
+        let program_id = crate::id();
+
+        let land_plane_owner_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_plane_account(&LandPlane{
+            owner: Some(land_plane_owner_acc_pubkey),
+            ..initialized_plane(2, 1, 2)
+        });
+
+        let instruction = |mint_start_slot, mint_end_slot| set_mint_window(
+            &program_id,
+            &land_plane_owner_acc_pubkey,
+            &land_plane_acc_pubkey,
+            mint_start_slot,
+            mint_end_slot,
+        ).unwrap();
+
+        //
+        // owner has not signed
+        //
+        let mut unsigned = instruction(Some(50), Some(100));
+        unsigned.accounts[0].is_signer = false;
+        assert_eq!(
+            Err(LandError::MissingSignature.into()),
+            do_process_instruction(unsigned, vec![&mut land_plane_owner_acc, &mut land_plane_acc])
+        );
+
+        //
+        // signer is not the owner of the land plane
+        //
+        let other_acc_pubkey = Pubkey::new_unique();
+        let mut other_acc = SolanaAccount::new(1, 0, &system_program::id());
+        assert_eq!(
+            Err(LandError::LandPlaneOwnerMismatch.into()),
+            do_process_instruction(
+                set_mint_window(&program_id, &other_acc_pubkey, &land_plane_acc_pubkey, Some(50), Some(100)).unwrap(),
+                vec![&mut other_acc, &mut land_plane_acc],
+            )
+        );
+
+        //
+        // window ends before it starts
+        //
+        assert_eq!(
+            Err(LandError::InvalidMintWindow.into()),
+            do_process_instruction(
+                instruction(Some(100), Some(50)),
+                vec![&mut land_plane_owner_acc, &mut land_plane_acc],
+            )
+        );
+        let land_plane = LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap();
+        assert_eq!((None, None), (land_plane.mint_start_slot, land_plane.mint_end_slot));
+
+        // instruction completes successfully, changing only the window
+        let before = land_plane;
+        do_process_instruction(
+            instruction(Some(50), Some(100)),
+            vec![&mut land_plane_owner_acc, &mut land_plane_acc],
+        )
+        .unwrap();
+        assert_eq!(
+            LandPlane{ mint_start_slot: Some(50), mint_end_slot: Some(100), ..before.clone() },
+            LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap(),
+        );
+
+        // and may reopen the land plane to mints at any time
+        do_process_instruction(
+            instruction(None, None),
+            vec![&mut land_plane_owner_acc, &mut land_plane_acc],
+        )
+        .unwrap();
+        assert_eq!(before, LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap());
+    }
+
+    #[test]
+    fn test_set_plane_treasury() {
+        let program_id = crate::id();
+
+        let land_plane_owner_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_plane_account(&LandPlane{
+            owner: Some(land_plane_owner_acc_pubkey),
+            ..initialized_plane(2, 1, 2)
+        });
+        let plane_treasury = Pubkey::new_unique();
+
+        let instruction = |plane_treasury| set_plane_treasury(
+            &program_id,
+            &land_plane_owner_acc_pubkey,
+            &land_plane_acc_pubkey,
+            plane_treasury,
+        ).unwrap();
+
+        //
+        // owner has not signed
+        //
+        let mut unsigned = instruction(Some(plane_treasury));
+        unsigned.accounts[0].is_signer = false;
+        assert_eq!(
+            Err(LandError::MissingSignature.into()),
+            do_process_instruction(unsigned, vec![&mut land_plane_owner_acc, &mut land_plane_acc])
+        );
+
+        //
+        // signer is not the owner of the land plane
+        //
+        let other_acc_pubkey = Pubkey::new_unique();
+        let mut other_acc = SolanaAccount::new(1, 0, &system_program::id());
+        assert_eq!(
+            Err(LandError::LandPlaneOwnerMismatch.into()),
+            do_process_instruction(
+                set_plane_treasury(&program_id, &other_acc_pubkey, &land_plane_acc_pubkey, Some(other_acc_pubkey)).unwrap(),
+                vec![&mut other_acc, &mut land_plane_acc],
+            )
+        );
+
+        // instruction completes successfully, changing only the treasury
+        let before = LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap();
+        do_process_instruction(
+            instruction(Some(plane_treasury)),
+            vec![&mut land_plane_owner_acc, &mut land_plane_acc],
+        )
+        .unwrap();
+        let land_plane = LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap();
+        assert_eq!(LandPlane{ plane_treasury: Some(plane_treasury), ..before.clone() }, land_plane);
+        assert_eq!(Some(plane_treasury), land_plane.fee_recipient());
+
+        // and may be unset, so that fees are once again paid to the owner
+        do_process_instruction(
+            instruction(None),
+            vec![&mut land_plane_owner_acc, &mut land_plane_acc],
+        )
+        .unwrap();
+        let land_plane = LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap();
+        assert_eq!(before, land_plane);
+        assert_eq!(Some(land_plane_owner_acc_pubkey), land_plane.fee_recipient());
+    }
+
+    #[test]
+    fn test_claim_reserved_parcel() {
+        let program_id = crate::id();
+
+        let reserve_claimant_acc_pubkey = Pubkey::new_unique();
+        let mut reserve_claimant_acc = SolanaAccount::new(1_000_000, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+        let mut rent_sysvar = rent_sysvar();
+
+        // land plane whose sale window closes after slot 100
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_plane_account(&LandPlane{
+            reserve_claimant: Some(reserve_claimant_acc_pubkey),
+            closes_at_slot: Some(100),
+            ..initialized_plane(0, 0, 0)
+        });
+        let (land_plane_stats_acc_pubkey, mut land_plane_stats_acc) =
+            land_plane_stats_account(&program_id, &land_plane_acc_pubkey);
+        let (land_asset_acc_pubkey, mut land_asset_acc) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+
+        // nft, held by no one in particular, to which the land is bound
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut nft_mint_acc = new_nft_mint_account();
+        let mut land_asset_by_mint_acc = land_asset_by_mint_account();
+
+        let instruction = |reserve_claimant_acc_pubkey: &Pubkey| claim_reserved_parcel(
+            &program_id,
+            reserve_claimant_acc_pubkey,
+            &land_asset_acc_pubkey,
+            &land_plane_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            &land_plane_stats_acc_pubkey,
+            0,
+            0,
+        ).unwrap();
+
+        //
+        // sale window has not yet closed
+        //
+        let mut clock_sysvar_acc = clock_sysvar(100);
+        assert_eq!(
+            Err(LandError::SaleWindowOpen.into()),
+            do_process_instruction(
+                instruction(&reserve_claimant_acc_pubkey),
+                vec![
+                    &mut reserve_claimant_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut clock_sysvar_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+        );
+
+        //
+        // signer is not the reserve claimant
+        //
+        let mut clock_sysvar_acc = clock_sysvar(101);
+        assert_eq!(
+            Err(LandError::InvalidReserveClaimant.into()),
+            do_process_instruction(
+                instruction(&Pubkey::new_unique()),
+                vec![
+                    &mut reserve_claimant_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut clock_sysvar_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+        );
+        assert_eq!(0, LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap().minted_count());
+
+        // reserve claimant claims the land once the sale window has closed
+        do_process_instruction(
+            instruction(&reserve_claimant_acc_pubkey),
+            vec![
+                &mut reserve_claimant_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut nft_mint_acc,
+                &mut land_plane_stats_acc,
+                &mut land_asset_by_mint_acc,
+                &mut clock_sysvar_acc,
+                &mut rent_sysvar,
+                &mut system_program_acc,
+                ]
+        )
+        .unwrap();
+        let land_asset = LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap();
+        assert_eq!(nft_mint_acc_pubkey, land_asset.mint_pubkey);
+        assert_eq!(reserve_claimant_acc_pubkey, land_asset.minter);
+        assert_eq!(land_plane_acc_pubkey, land_asset.land_plane);
+        assert_eq!(Coordinate { x: 0, z: 0 }, land_asset.coordinate);
+        assert_eq!(1, LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap().minted_count());
+        assert_eq!(1, LandPlaneStats::try_from_slice(&land_plane_stats_acc.data).unwrap().mints);
+        let land_asset_by_mint = LandAssetByMint::try_from_slice(&land_asset_by_mint_acc.data).unwrap();
+        assert_eq!(land_asset_acc_pubkey, land_asset_by_mint.land_asset);
+
+        //
+        // land the land plane has since passed has already been claimed
+        //
+        assert_eq!(
+            Err(LandError::ParcelAlreadyClaimed.into()),
+            do_process_instruction(
+                instruction(&reserve_claimant_acc_pubkey),
+                vec![
+                    &mut reserve_claimant_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut clock_sysvar_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+        );
+    }
+
+    #[test]
+    fn test_check_coordinate() {
+        let program_id = crate::id();
+
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let land_plane = initialized_plane(2, 1, 2);
+        let mut land_plane_acc = fake_plane_account(&land_plane);
+        let land_plane_acc_data = land_plane_acc.data.clone();
+
+        let coordinate = Coordinate { x: 1, z: 1 };
+        let (land_asset_acc_pubkey, _) = land_plane.land_asset_address(
+            &program_id,
+            &land_plane_acc_pubkey,
+            coordinate,
+        );
+        let instruction = check_coordinate(
+            &program_id,
+            &land_asset_acc_pubkey,
+            &land_plane_acc_pubkey,
+            coordinate.x,
+            coordinate.z,
+        ).unwrap();
+
+        // instruction completes successfully for land whose
+        // account does not yet exist
+        let mut land_asset_acc = SolanaAccount::default();
+        do_process_instruction(
+            instruction.clone(),
+            vec![&mut land_asset_acc, &mut land_plane_acc],
+        )
+        .unwrap();
+
+        // and for land whose account has been initialised but not minted
+        let mut land_asset_acc = fake_asset_account(&LandAsset{
+            land_plane: land_plane_acc_pubkey,
+            coordinate,
+            ..initialized_asset(&Pubkey::default())
+        });
+        do_process_instruction(
+            instruction.clone(),
+            vec![&mut land_asset_acc, &mut land_plane_acc],
+        )
+        .unwrap();
+
+        // and for minted land, changing nothing
+        let mut land_asset_acc = fake_asset_account(&LandAsset{
+            land_plane: land_plane_acc_pubkey,
+            coordinate,
+            ..initialized_asset(&Pubkey::new_unique())
+        });
+        let land_asset_acc_data = land_asset_acc.data.clone();
+        do_process_instruction(
+            instruction.clone(),
+            vec![&mut land_asset_acc, &mut land_plane_acc],
+        )
+        .unwrap();
+        assert_eq!(land_asset_acc_data, land_asset_acc.data);
+        assert_eq!(land_plane_acc_data, land_plane_acc.data);
+
+        //
+        // land asset account is that of another co-ordinate
+        //
+        let (other_land_asset_acc_pubkey, _) = land_plane.land_asset_address(
+            &program_id,
+            &land_plane_acc_pubkey,
+            Coordinate { x: 0, z: 1 },
+        );
+        assert_eq!(
+            Err(LandError::InvalidLandAssetAccKey.into()),
+            do_process_instruction(
+                check_coordinate(
+                    &program_id,
+                    &other_land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    coordinate.x,
+                    coordinate.z,
+                ).unwrap(),
+                vec![&mut land_asset_acc, &mut land_plane_acc],
+            )
+        );
+
+        //
+        // land plane account has not been initialised
+        //
+        let mut uninitialised_land_plane_acc = SolanaAccount::new(42, LAND_PLANE_ACC_DATA_LEN, &program_id);
+        assert_eq!(
+            Err(LandError::LandPlaneAccUninitialised.into()),
+            do_process_instruction(
+                instruction,
+                vec![&mut land_asset_acc, &mut uninitialised_land_plane_acc],
+            )
+        );
+    }
+
+    #[test]
+    fn test_set_plane_metadata() {
+        let program_id = crate::id();
+        let mut rent_sysvar = rent_sysvar();
+        let mut system_program_acc = SolanaAccount::default();
+
+        let land_plane_owner_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_owner_acc = SolanaAccount::new(1_000_000_000, 0, &system_program::id());
+
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_plane_account(&LandPlane{
+            owner: Some(land_plane_owner_acc_pubkey),
+            ..initialized_plane(0, 0, 0)
+        });
+
+        // plane metadata account, allocated as on the first call
+        let mut plane_metadata_acc = SolanaAccount::new(
+            Rent::default().minimum_balance(PLANE_METADATA_ACC_DATA_LEN),
+            PLANE_METADATA_ACC_DATA_LEN,
+            &program_id,
+        );
+
+        //
+        // signer is not the owner of the land plane
+        //
+        let mut instruction = set_plane_metadata(
+            &program_id,
+            &land_plane_owner_acc_pubkey,
+            &land_plane_acc_pubkey,
+            "Plane",
+            "PLN",
+            "https://example.com/plane/",
+        ).unwrap();
+        instruction.accounts[0].pubkey = Pubkey::new_unique();
+        assert_eq!(
+            Err(LandError::LandPlaneOwnerMismatch.into()),
+            do_process_instruction(
+                instruction,
+                vec![
+                    &mut land_plane_owner_acc,
+                    &mut plane_metadata_acc,
+                    &mut land_plane_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                ],
+            )
+        );
+
+        //
+        // plane metadata account is not the PDA of the land plane
+        //
+        let mut instruction = set_plane_metadata(
+            &program_id,
+            &land_plane_owner_acc_pubkey,
+            &land_plane_acc_pubkey,
+            "Plane",
+            "PLN",
+            "https://example.com/plane/",
+        ).unwrap();
+        instruction.accounts[1].pubkey = Pubkey::new_unique();
+        assert_eq!(
+            Err(LandError::InvalidPlaneMetadataAccKey.into()),
+            do_process_instruction(
+                instruction,
+                vec![
+                    &mut land_plane_owner_acc,
+                    &mut plane_metadata_acc,
+                    &mut land_plane_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                ],
+            )
+        );
+
+        //
+        // metadata is set
+        //
+        do_process_instruction(
+            set_plane_metadata(
+                &program_id,
+                &land_plane_owner_acc_pubkey,
+                &land_plane_acc_pubkey,
+                "Plane",
+                "PLN",
+                "https://example.com/plane/",
+            ).unwrap(),
+            vec![
+                &mut land_plane_owner_acc,
+                &mut plane_metadata_acc,
+                &mut land_plane_acc,
+                &mut rent_sysvar,
+                &mut system_program_acc,
+            ],
+        ).unwrap();
+        let plane_metadata = PlaneMetadata::deserialize(&mut &plane_metadata_acc.data[..]).unwrap();
+        assert_eq!(PlaneMetadataVersion::V1, plane_metadata.version);
+        assert_eq!("Plane", plane_metadata.name.as_str());
+        assert_eq!("PLN", plane_metadata.symbol.as_str());
+        assert_eq!("https://example.com/plane/", plane_metadata.base_uri.as_str());
+
+        //
+        // and may be overwritten with shorter strings, leaving
+        // none of the earlier ones behind
+        //
+        do_process_instruction(
+            set_plane_metadata(
+                &program_id,
+                &land_plane_owner_acc_pubkey,
+                &land_plane_acc_pubkey,
+                "P",
+                "",
+                "ipfs://x/",
+            ).unwrap(),
+            vec![
+                &mut land_plane_owner_acc,
+                &mut plane_metadata_acc,
+                &mut land_plane_acc,
+                &mut rent_sysvar,
+                &mut system_program_acc,
+            ],
+        ).unwrap();
+        let plane_metadata = PlaneMetadata::deserialize(&mut &plane_metadata_acc.data[..]).unwrap();
+        assert_eq!("P", plane_metadata.name.as_str());
+        assert_eq!("", plane_metadata.symbol.as_str());
+        assert_eq!("ipfs://x/", plane_metadata.base_uri.as_str());
+        let written = plane_metadata.try_to_vec().unwrap().len();
+        assert!(plane_metadata_acc.data[written..].iter().all(|b| *b == 0));
+
+        //
+        // oversized strings are rejected when building the instruction
+        //
+        for (name, symbol, base_uri) in vec![
+            ("a".repeat(MAX_PLANE_NAME_LEN + 1), String::new(), String::new()),
+            (String::new(), "a".repeat(MAX_PLANE_SYMBOL_LEN + 1), String::new()),
+            (String::new(), String::new(), "a".repeat(MAX_PLANE_BASE_URI_LEN + 1)),
+        ] {
+            assert_eq!(
+                Err(LandError::FieldTooLong.into()),
+                set_plane_metadata(&program_id, &land_plane_owner_acc_pubkey, &land_plane_acc_pubkey, &name, &symbol, &base_uri),
+            );
+        }
+
+        //
+        // and when decoding instruction data built by hand
+        //
+        let mut data = set_plane_metadata(
+            &program_id,
+            &land_plane_owner_acc_pubkey,
+            &land_plane_acc_pubkey,
+            "",
+            "",
+            "",
+        ).unwrap().data;
+        data.truncate(1);
+        data.extend("a".repeat(MAX_PLANE_NAME_LEN + 1).try_to_vec().unwrap());
+        data.extend(String::new().try_to_vec().unwrap());
+        data.extend(String::new().try_to_vec().unwrap());
+        assert!(decode_instruction(&data).is_err());
+    }
+
+    #[test]
+    fn test_merge_parcels() {
+        let program_id = crate::id();
+
+        let owner_acc_pubkey = Pubkey::new_unique();
+        let mut owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let land_plane = initialized_plane(3, 1, 2);
+        let mut land_plane_acc = fake_plane_account(&land_plane);
+
+        // pieces of land minted at (1, 1), (2, 1), (1, 2), (2, 2) and
+        // (3, 1), each bound to an nft held by the owner
+        let mut land_asset_acc_pubkeys = vec![];
+        let mut land_assets = vec![];
+        let mut land_asset_accs = vec![];
+        let mut nft_assoc_token_acc_pubkeys = vec![];
+        let mut nft_assoc_token_accs = vec![];
+        for &(x, z) in [(1, 1), (2, 1), (1, 2), (2, 2), (3, 1)].iter() {
+            let coordinate = Coordinate { x, z };
+            let (land_asset_acc_pubkey, _) = land_plane.land_asset_address(
+                &program_id,
+                &land_plane_acc_pubkey,
+                coordinate,
+            );
+            let land_asset = LandAsset{
+                land_plane: land_plane_acc_pubkey,
+                coordinate,
+                ..initialized_asset(&Pubkey::new_unique())
+            };
+            land_asset_acc_pubkeys.push(land_asset_acc_pubkey);
+            land_asset_accs.push(fake_asset_account(&land_asset));
+            nft_assoc_token_acc_pubkeys.push(Pubkey::new_unique());
+            nft_assoc_token_accs.push(nft_token_account(
+                &owner_acc_pubkey,
+                &land_asset.mint_pubkey,
+                AccountState::Initialized,
+            ));
+            land_assets.push(land_asset);
+        }
+        let block = [0, 1, 2, 3];
+
+        // instruction merging the pieces of land at the given indices
+        let instruction = |signer: &Pubkey, indices: [usize; 4]| merge_parcels(
+            &program_id,
+            signer,
+            &land_plane_acc_pubkey,
+            &[
+                land_asset_acc_pubkeys[indices[0]],
+                land_asset_acc_pubkeys[indices[1]],
+                land_asset_acc_pubkeys[indices[2]],
+                land_asset_acc_pubkeys[indices[3]],
+            ],
+            &[
+                nft_assoc_token_acc_pubkeys[indices[0]],
+                nft_assoc_token_acc_pubkeys[indices[1]],
+                nft_assoc_token_acc_pubkeys[indices[2]],
+                nft_assoc_token_acc_pubkeys[indices[3]],
+            ],
+        ).unwrap();
+
+        // and the accounts to pass along with it
+        fn accounts<'a>(
+            owner_acc: &'a mut SolanaAccount,
+            land_plane_acc: &'a mut SolanaAccount,
+            land_asset_accs: &'a mut [SolanaAccount],
+            nft_assoc_token_accs: &'a mut [SolanaAccount],
+            indices: [usize; 4],
+        ) -> Vec<&'a mut SolanaAccount> {
+            let mut land_asset_accs: Vec<_> = land_asset_accs.iter_mut().map(Some).collect();
+            let mut nft_assoc_token_accs: Vec<_> = nft_assoc_token_accs.iter_mut().map(Some).collect();
+            let mut accounts = vec![owner_acc];
+            accounts.extend(indices.iter().map(|i| land_asset_accs[*i].take().unwrap()));
+            accounts.push(land_plane_acc);
+            accounts.extend(indices.iter().map(|i| nft_assoc_token_accs[*i].take().unwrap()));
+            accounts
+        }
+
+        //
+        // owner has not signed
+        //
+        let mut unsigned = instruction(&owner_acc_pubkey, block);
+        unsigned.accounts[0].is_signer = false;
+        assert_eq!(
+            Err(LandError::MissingSignature.into()),
+            do_process_instruction(
+                unsigned,
+                accounts(&mut owner_acc, &mut land_plane_acc, &mut land_asset_accs, &mut nft_assoc_token_accs, block),
+            )
+        );
+
+        //
+        // land is not contiguous
+        //
+        let scattered = [0, 4, 2, 3];
+        assert_eq!(
+            Err(LandError::ParcelsNotContiguous.into()),
+            do_process_instruction(
+                instruction(&owner_acc_pubkey, scattered),
+                accounts(&mut owner_acc, &mut land_plane_acc, &mut land_asset_accs, &mut nft_assoc_token_accs, scattered),
+            )
+        );
+
+        //
+        // land is contiguous but given out of order
+        //
+        let reordered = [1, 0, 3, 2];
+        assert_eq!(
+            Err(LandError::ParcelsNotContiguous.into()),
+            do_process_instruction(
+                instruction(&owner_acc_pubkey, reordered),
+                accounts(&mut owner_acc, &mut land_plane_acc, &mut land_asset_accs, &mut nft_assoc_token_accs, reordered),
+            )
+        );
+
+        //
+        // signer does not hold one of the nfts
+        //
+        let other_owner_acc_pubkey = Pubkey::new_unique();
+        nft_assoc_token_accs[3] = nft_token_account(
+            &other_owner_acc_pubkey,
+            &land_assets[3].mint_pubkey,
+            AccountState::Initialized,
+        );
+        assert_eq!(
+            Err(LandError::NftTokenAccOwnerMismatch.into()),
+            do_process_instruction(
+                instruction(&owner_acc_pubkey, block),
+                accounts(&mut owner_acc, &mut land_plane_acc, &mut land_asset_accs, &mut nft_assoc_token_accs, block),
+            )
+        );
+        nft_assoc_token_accs[3] = nft_token_account(
+            &owner_acc_pubkey,
+            &land_assets[3].mint_pubkey,
+            AccountState::Initialized,
+        );
+        for (land_asset, land_asset_acc) in land_assets.iter().zip(land_asset_accs.iter()) {
+            assert_eq!(*land_asset, LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap());
+        }
+
+        //
+        // 2x2 block of land is merged into an estate
+        //
+        do_process_instruction(
+            instruction(&owner_acc_pubkey, block),
+            accounts(&mut owner_acc, &mut land_plane_acc, &mut land_asset_accs, &mut nft_assoc_token_accs, block),
+        ).unwrap();
+        assert_eq!(
+            LandAsset{ estate_footprint: 2, ..land_assets[0].clone() },
+            LandAsset::unpack_from_slice(&land_asset_accs[0].data).unwrap(),
+        );
+        for index in 1..4 {
+            assert_eq!(
+                LandAsset{ merged_into: land_asset_acc_pubkeys[0], ..land_assets[index].clone() },
+                LandAsset::unpack_from_slice(&land_asset_accs[index].data).unwrap(),
+            );
+        }
+        assert_eq!(land_assets[4], LandAsset::unpack_from_slice(&land_asset_accs[4].data).unwrap());
+
+        //
+        // merged land cannot be merged again
+        //
+        assert_eq!(
+            Err(LandError::ParcelAlreadyMerged.into()),
+            do_process_instruction(
+                instruction(&owner_acc_pubkey, block),
+                accounts(&mut owner_acc, &mut land_plane_acc, &mut land_asset_accs, &mut nft_assoc_token_accs, block),
+            )
+        );
+    }
+
+    #[test]
+    fn test_verify_parcel_ownership() {
+        let program_id = crate::id();
+
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let land_plane = initialized_plane(2, 1, 2);
+        let mut land_plane_acc = fake_plane_account(&land_plane);
+
+        // piece of land minted at (1, 1), bound to an nft held by the holder
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let coordinate = Coordinate { x: 1, z: 1 };
+        let (land_asset_acc_pubkey, _) = land_plane.land_asset_address(
+            &program_id,
+            &land_plane_acc_pubkey,
+            coordinate,
+        );
+        let mut land_asset_acc = fake_asset_account(&LandAsset{
+            land_plane: land_plane_acc_pubkey,
+            coordinate,
+            ..initialized_asset(&nft_mint_acc_pubkey)
+        });
+        let land_asset_acc_data = land_asset_acc.data.clone();
+
+        let holder_acc_pubkey = Pubkey::new_unique();
+        let mut holder_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = nft_token_account(
+            &holder_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+
+        let instruction = verify_parcel_ownership(
+            &program_id,
+            &holder_acc_pubkey,
+            &land_asset_acc_pubkey,
+            &land_plane_acc_pubkey,
+            &nft_assoc_token_acc_pubkey,
+        ).unwrap();
+
+        //
+        // holder has not signed
+        //
+        let mut unsigned = instruction.clone();
+        unsigned.accounts[0].is_signer = false;
+        assert_eq!(
+            Err(LandError::MissingSignature.into()),
+            do_process_instruction(
+                unsigned,
+                vec![&mut holder_acc, &mut land_asset_acc, &mut land_plane_acc, &mut nft_assoc_token_acc],
+            )
+        );
+
+        //
+        // land asset account is not that of the land on the land plane
+        //
+        assert_eq!(
+            Err(LandError::InvalidLandAssetAccKey.into()),
+            do_process_instruction(
+                verify_parcel_ownership(
+                    &program_id,
+                    &holder_acc_pubkey,
+                    &Pubkey::new_unique(),
+                    &land_plane_acc_pubkey,
+                    &nft_assoc_token_acc_pubkey,
+                ).unwrap(),
+                vec![&mut holder_acc, &mut land_asset_acc, &mut land_plane_acc, &mut nft_assoc_token_acc],
+            )
+        );
+
+        // instruction completes successfully for a holder still holding
+        // the nft, and changes nothing
+        do_process_instruction(
+            instruction.clone(),
+            vec![&mut holder_acc, &mut land_asset_acc, &mut land_plane_acc, &mut nft_assoc_token_acc],
+        )
+        .unwrap();
+        assert_eq!(land_asset_acc_data, land_asset_acc.data);
+
+        //
+        // holder has since transferred the nft away, emptying their
+        // token account
+        //
+        let mut emptied_nft_assoc_token_acc = SolanaAccount::new(1, Account::LEN, &spl_token::id());
+        Account{
+            mint: nft_mint_acc_pubkey,
+            owner: holder_acc_pubkey,
+            amount: 0,
+            state: AccountState::Initialized,
+            ..Account::default()
+        }.pack_into_slice(&mut emptied_nft_assoc_token_acc.data);
+        assert_eq!(
+            Err(LandError::NftNotHeld.into()),
+            do_process_instruction(
+                instruction.clone(),
+                vec![&mut holder_acc, &mut land_asset_acc, &mut land_plane_acc, &mut emptied_nft_assoc_token_acc],
+            )
+        );
+
+        //
+        // or gives the token account of the new holder
+        //
+        let mut new_holder_nft_assoc_token_acc = nft_token_account(
+            &Pubkey::new_unique(),
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+        assert_eq!(
+            Err(LandError::NftTokenAccOwnerMismatch.into()),
+            do_process_instruction(
+                instruction,
+                vec![&mut holder_acc, &mut land_asset_acc, &mut land_plane_acc, &mut new_holder_nft_assoc_token_acc],
+            )
+        );
+        assert_eq!(land_asset_acc_data, land_asset_acc.data);
+    }
+
+    #[test]
+    fn test_lock_parcel() {
+        let program_id = crate::id();
+
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(1_000_000, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+        let mut rent_sysvar = rent_sysvar();
+
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let land_plane = initialized_plane(2, 1, 2);
+        let mut land_plane_acc = fake_plane_account(&land_plane);
+
+        // piece of land minted at (1, 1), bound to an nft held by the
+        // holder and indexed by its mint
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let coordinate = Coordinate { x: 1, z: 1 };
+        let (land_asset_acc_pubkey, _) = land_plane.land_asset_address(
+            &program_id,
+            &land_plane_acc_pubkey,
+            coordinate,
+        );
+        let mut land_asset_acc = fake_asset_account(&LandAsset{
+            land_plane: land_plane_acc_pubkey,
+            coordinate,
+            ..initialized_asset(&nft_mint_acc_pubkey)
+        });
+        let mut land_asset_by_mint_acc = fake_land_asset_by_mint_account(&initialized_land_asset_by_mint(
+            &land_plane_acc_pubkey,
+            &land_asset_acc_pubkey,
+            coordinate,
+        ));
+
+        let holder_acc_pubkey = Pubkey::new_unique();
+        let mut holder_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = nft_token_account(
+            &holder_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+
+        let locker_acc_pubkey = Pubkey::new_unique();
+        let mut locker_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let new_nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut new_nft_mint_acc = new_nft_mint_account();
+        let mut new_land_asset_by_mint_acc = land_asset_by_mint_account();
+        let transfer = transfer_land(
+            &program_id,
+            &payer_acc_pubkey,
+            &holder_acc_pubkey,
+            &land_asset_acc_pubkey,
+            &land_plane_acc_pubkey,
+            &nft_assoc_token_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            &new_nft_mint_acc_pubkey,
+            None,
+            1,
+            1,
+            0,
+        ).unwrap();
+
+        //
+        // holder has not signed
+        //
+        let mut unsigned = lock_parcel(
+            &program_id,
+            &holder_acc_pubkey,
+            &land_asset_acc_pubkey,
+            &land_plane_acc_pubkey,
+            &nft_assoc_token_acc_pubkey,
+            &locker_acc_pubkey,
+        ).unwrap();
+        unsigned.accounts[0].is_signer = false;
+        assert_eq!(
+            Err(LandError::MissingSignature.into()),
+            do_process_instruction(
+                unsigned,
+                vec![&mut holder_acc, &mut land_asset_acc, &mut land_plane_acc, &mut nft_assoc_token_acc],
+            )
+        );
+
+        //
+        // signer does not hold the nft
+        //
+        let other_acc_pubkey = Pubkey::new_unique();
+        assert_eq!(
+            Err(LandError::NftTokenAccOwnerMismatch.into()),
+            do_process_instruction(
+                lock_parcel(
+                    &program_id,
+                    &other_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    &nft_assoc_token_acc_pubkey,
+                    &other_acc_pubkey,
+                ).unwrap(),
+                vec![&mut holder_acc, &mut land_asset_acc, &mut land_plane_acc, &mut nft_assoc_token_acc],
+            )
+        );
+
+        //
+        // land that is not locked cannot be unlocked
+        //
+        assert_eq!(
+            Err(LandError::LockerMismatch.into()),
+            do_process_instruction(
+                unlock_parcel(&program_id, &locker_acc_pubkey, &land_asset_acc_pubkey, &land_plane_acc_pubkey).unwrap(),
+                vec![&mut locker_acc, &mut land_asset_acc, &mut land_plane_acc],
+            )
+        );
+
+        // holder locks the land
+        do_process_instruction(
+            lock_parcel(
+                &program_id,
+                &holder_acc_pubkey,
+                &land_asset_acc_pubkey,
+                &land_plane_acc_pubkey,
+                &nft_assoc_token_acc_pubkey,
+                &locker_acc_pubkey,
+            ).unwrap(),
+            vec![&mut holder_acc, &mut land_asset_acc, &mut land_plane_acc, &mut nft_assoc_token_acc],
+        )
+        .unwrap();
+        let land_asset = LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap();
+        assert_eq!(locker_acc_pubkey, land_asset.locked_by);
+        assert!(land_asset.is_locked());
+
+        //
+        // locked land cannot be locked again, even by the same locker
+        //
+        assert_eq!(
+            Err(LandError::ParcelLocked.into()),
+            do_process_instruction(
+                lock_parcel(
+                    &program_id,
+                    &holder_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    &nft_assoc_token_acc_pubkey,
+                    &locker_acc_pubkey,
+                ).unwrap(),
+                vec![&mut holder_acc, &mut land_asset_acc, &mut land_plane_acc, &mut nft_assoc_token_acc],
+            )
+        );
+
+        //
+        // nor transferred
+        //
+        assert_eq!(
+            Err(LandError::ParcelLocked.into()),
+            do_process_instruction(
+                transfer.clone(),
+                vec![
+                    &mut payer_acc,
+                    &mut holder_acc,
+                    &mut land_asset_acc,
+                    &mut land_plane_acc,
+                    &mut nft_assoc_token_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut new_nft_mint_acc,
+                    &mut new_land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    &mut system_program_acc,
+                    ]
+            )
+        );
+
+        //
+        // nor unlocked by anyone but the locker, including the holder
+        //
+        assert_eq!(
+            Err(LandError::LockerMismatch.into()),
+            do_process_instruction(
+                unlock_parcel(&program_id, &holder_acc_pubkey, &land_asset_acc_pubkey, &land_plane_acc_pubkey).unwrap(),
+                vec![&mut holder_acc, &mut land_asset_acc, &mut land_plane_acc],
+            )
+        );
+
+        //
+        // locker has not signed
+        //
+        let mut unsigned = unlock_parcel(
+            &program_id,
+            &locker_acc_pubkey,
+            &land_asset_acc_pubkey,
+            &land_plane_acc_pubkey,
+        ).unwrap();
+        unsigned.accounts[0].is_signer = false;
+        assert_eq!(
+            Err(LandError::MissingSignature.into()),
+            do_process_instruction(
+                unsigned,
+                vec![&mut locker_acc, &mut land_asset_acc, &mut land_plane_acc],
+            )
+        );
+        assert_eq!(locker_acc_pubkey, LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap().locked_by);
+
+        // locker unlocks the land
+        do_process_instruction(
+            unlock_parcel(&program_id, &locker_acc_pubkey, &land_asset_acc_pubkey, &land_plane_acc_pubkey).unwrap(),
+            vec![&mut locker_acc, &mut land_asset_acc, &mut land_plane_acc],
+        )
+        .unwrap();
+        assert!(!LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap().is_locked());
+
+        // after which the land can be transferred
+        do_process_instruction(
+            transfer,
+            vec![
+                &mut payer_acc,
+                &mut holder_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut nft_assoc_token_acc,
+                &mut land_asset_by_mint_acc,
+                &mut new_nft_mint_acc,
+                &mut new_land_asset_by_mint_acc,
+                &mut rent_sysvar,
+                &mut system_program_acc,
+                ]
+        )
+        .unwrap();
+        assert_eq!(new_nft_mint_acc_pubkey, LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap().mint_pubkey);
+
+        //
+        // land asset account allocated before V8 is too short to be locked
+        //
+        let mut v7_land_asset_acc = fake_asset_account(&LandAsset{
+            version: LandAssetVersion::V7,
+            land_plane: land_plane_acc_pubkey,
+            coordinate,
+            ..initialized_asset(&nft_mint_acc_pubkey)
+        });
+        v7_land_asset_acc.data.truncate(LAND_ASSET_V7_ACC_DATA_LEN);
+        let mut nft_assoc_token_acc = nft_token_account(
+            &holder_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+        assert_eq!(
+            Err(LandError::IncorrectDataSize.into()),
+            do_process_instruction(
+                lock_parcel(
+                    &program_id,
+                    &holder_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    &nft_assoc_token_acc_pubkey,
+                    &locker_acc_pubkey,
+                ).unwrap(),
+                vec![&mut holder_acc, &mut v7_land_asset_acc, &mut land_plane_acc, &mut nft_assoc_token_acc],
+            )
+        );
+    }
+
+    #[test]
+    fn test_migrate_assets_batch() {
+        let program_id = crate::id();
+
+        let land_plane_owner_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        // land plane owned by the owner, minted up to but not including
+        // (2, 1), i.e. the land at indices 0 to 4 has been minted
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_plane_account(&LandPlane{
+            owner: Some(land_plane_owner_acc_pubkey),
+            ..initialized_plane(2, 1, 2)
+        });
+
+        // land asset accounts at each index, written at old versions
+        // except that at index 2, and that at index 5 not yet created.
+        // Those at indices 3 and 4 were allocated at V1 and V2, so are
+        // too short to migrate.
+        let land_asset_acc_pubkeys: Vec<Pubkey> = (0..6)
+            .map(|index| asset_address_at_index(&program_id, &land_plane_acc_pubkey, index, FULL_ADDR_WIDTH).0)
+            .collect();
+        let nft_mint_acc_pubkeys: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+        let current = LandAsset{
+            land_plane: land_plane_acc_pubkey,
+            coordinate: coordinate_at_index(2),
+            bump: asset_address_at_index(&program_id, &land_plane_acc_pubkey, 2, FULL_ADDR_WIDTH).1,
+            ..initialized_asset(&nft_mint_acc_pubkeys[2])
+        };
+        let mut land_asset_accs: Vec<SolanaAccount> = nft_mint_acc_pubkeys
+            .iter()
+            .enumerate()
+            .map(|(index, nft_mint_acc_pubkey)| match index {
+                1 => fake_asset_account(&LandAsset{
+                    version: LandAssetVersion::V1,
+                    ..initialized_asset(nft_mint_acc_pubkey)
+                }),
+                2 => fake_asset_account(&current),
+                3 => {
+                    let mut acc = fake_asset_account(&LandAsset{
+                        version: LandAssetVersion::V1,
+                        ..initialized_asset(nft_mint_acc_pubkey)
+                    });
+                    acc.data.truncate(LAND_ASSET_V1_ACC_DATA_LEN);
+                    acc
+                }
+                4 => {
+                    let mut acc = fake_asset_account(&LandAsset{
+                        version: LandAssetVersion::V2,
+                        ..initialized_asset(nft_mint_acc_pubkey)
+                    });
+                    acc.data.truncate(LAND_ASSET_V2_ACC_DATA_LEN);
+                    acc
+                }
+                _ => fake_asset_account(&LandAsset{
+                    version: LandAssetVersion::V2,
+                    ..initialized_asset(nft_mint_acc_pubkey)
+                }),
+            })
+            .collect();
+        land_asset_accs.push(SolanaAccount::default());
+        let short_accs_data: Vec<Vec<u8>> = land_asset_accs[3..5].iter().map(|acc| acc.data.clone()).collect();
+
+        // migrate the first three land asset accounts
+        {
+            let mut accounts = vec![&mut land_plane_owner_acc, &mut land_plane_acc];
+            accounts.extend(land_asset_accs[..3].iter_mut());
+            do_process_instruction(
+                migrate_assets_batch(
+                    &program_id,
+                    &land_plane_owner_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    0,
+                    &land_asset_acc_pubkeys[..3],
+                ).unwrap(),
+                accounts,
+            )
+            .unwrap();
+        }
+        let land_plane = LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap();
+        assert_eq!(3, land_plane.migrated_count);
+
+        //
+        // batches that do not start where the last one ended
+        //
+        for start_index in vec![0, 4] {
+            let first = start_index as usize;
+            let mut accounts = vec![&mut land_plane_owner_acc, &mut land_plane_acc];
+            accounts.extend(land_asset_accs[first..first + 2].iter_mut());
+            assert_eq!(
+                Err(LandError::MigrationOutOfOrder.into()),
+                do_process_instruction(
+                    migrate_assets_batch(
+                        &program_id,
+                        &land_plane_owner_acc_pubkey,
+                        &land_plane_acc_pubkey,
+                        start_index,
+                        &land_asset_acc_pubkeys[first..first + 2],
+                    ).unwrap(),
+                    accounts,
+                )
+            );
+        }
+
+        //
+        // land asset account not at the given index
+        //
+        {
+            let mut accounts = vec![&mut land_plane_owner_acc, &mut land_plane_acc];
+            accounts.extend(land_asset_accs[4..].iter_mut());
+            assert_eq!(
+                Err(LandError::InvalidLandAssetAccKey.into()),
+                do_process_instruction(
+                    migrate_assets_batch(
+                        &program_id,
+                        &land_plane_owner_acc_pubkey,
+                        &land_plane_acc_pubkey,
+                        3,
+                        &land_asset_acc_pubkeys[4..],
+                    ).unwrap(),
+                    accounts,
+                )
+            );
+        }
+
+        // migrate the rest, passing over the land not yet minted and
+        // the land asset accounts too short to migrate
+        {
+            let mut accounts = vec![&mut land_plane_owner_acc, &mut land_plane_acc];
+            accounts.extend(land_asset_accs[3..].iter_mut());
+            do_process_instruction(
+                migrate_assets_batch(
+                    &program_id,
+                    &land_plane_owner_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    3,
+                    &land_asset_acc_pubkeys[3..],
+                ).unwrap(),
+                accounts,
+            )
+            .unwrap();
+        }
+        let land_plane = LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap();
+        assert_eq!(6, land_plane.migrated_count);
+
+        // which are left as they were
+        assert_eq!(short_accs_data[0], land_asset_accs[3].data);
+        assert_eq!(short_accs_data[1], land_asset_accs[4].data);
+
+        // while every other minted piece of land is at the current
+        // version and records its land plane and co-ordinate
+        for (index, nft_mint_acc_pubkey) in nft_mint_acc_pubkeys[..3].iter().enumerate() {
+            let land_asset = LandAsset::unpack_from_slice(&land_asset_accs[index].data).unwrap();
+            assert_eq!(
+                LandAsset{
+                    version: LandAssetVersion::V8,
+                    land_plane: land_plane_acc_pubkey,
+                    coordinate: coordinate_at_index(index as u64),
+                    bump: asset_address_at_index(&program_id, &land_plane_acc_pubkey, index as u64, FULL_ADDR_WIDTH).1,
+                    ..initialized_asset(nft_mint_acc_pubkey)
+                },
+                land_asset,
+            );
+        }
+        assert!(land_asset_accs[5].data.is_empty());
+    }
+
+    #[test]
+    fn test_emit_parcel_range() {
         let program_id = crate::id();
-        let land_plane_acc_key = Pubkey::new_unique();
-        let mut land_plane_acc = SolanaAccount::new(42, LAND_PLANE_ACC_DATA_LEN, &program_id);
-        let mut rent_sysvar = rent_sysvar();
+
+        // land plane minted up to but not including (2, 1), i.e. the land
+        // at indices 0 to 4 has been minted, with land asset accounts at
+        // each index from 1 and that at index 5 not yet created
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_initialized_plane(2, 1, 2);
+        let land_plane_acc_data = land_plane_acc.data.clone();
+        let land_asset_acc_pubkeys: Vec<Pubkey> = (1..6)
+            .map(|index| asset_address_at_index(&program_id, &land_plane_acc_pubkey, index, FULL_ADDR_WIDTH).0)
+            .collect();
+        let mut land_asset_accs: Vec<SolanaAccount> = (1..5)
+            .map(|index| fake_asset_account(&LandAsset{
+                land_plane: land_plane_acc_pubkey,
+                coordinate: coordinate_at_index(index),
+                ..initialized_asset(&Pubkey::new_unique())
+            }))
+            .collect();
+        land_asset_accs.push(SolanaAccount::default());
+        let land_asset_accs_data: Vec<Vec<u8>> = land_asset_accs.iter().map(|a| a.data.clone()).collect();
 
         //
-        // given account to be initialised is not rent exempt 
+        // land asset accounts are not in mint order
         //
-        assert_eq!(
-            Err(LandError::NotRentExempt.into()),
-            do_process_instruction(
-                initialize_land_plane(&program_id, &land_plane_acc_key).unwrap(),
-                vec![&mut land_plane_acc, &mut rent_sysvar]
-            )
-        );
-        // correct rent
-        land_plane_acc.lamports = land_plane_minimum_balance();
-
-        // instruction completes successfully
-        do_process_instruction(
-            initialize_land_plane(&program_id, &land_plane_acc_key).unwrap(),
-            vec![&mut land_plane_acc, &mut rent_sysvar]
-        )
-        .unwrap();
+        {
+            let mut accounts = vec![&mut land_plane_acc];
+            accounts.extend(land_asset_accs.iter_mut());
+            assert_eq!(
+                Err(LandError::InvalidLandAssetAccKey.into()),
+                do_process_instruction(
+                    emit_parcel_range(&program_id, &land_plane_acc_pubkey, 0, &land_asset_acc_pubkeys).unwrap(),
+                    accounts,
+                )
+            );
+        }
 
         //
-        // trying to call initialise again fails
+        // range holds more than the maximum number of parcels
         //
-        assert_eq!(
-            Err(LandError::LandPlaneAccAlreadyInitialised.into()),
+        {
+            let mut unbounded_accs: Vec<SolanaAccount> =
+                (0..=MAX_PARCEL_RANGE_COUNT).map(|_| SolanaAccount::default()).collect();
+            let mut accounts = vec![&mut land_plane_acc];
+            accounts.extend(unbounded_accs.iter_mut());
+            assert_eq!(
+                Err(LandError::ParcelRangeTooLarge.into()),
+                do_process_instruction(
+                    emit_parcel_range(
+                        &program_id,
+                        &land_plane_acc_pubkey,
+                        0,
+                        &vec![Pubkey::new_unique(); MAX_PARCEL_RANGE_COUNT + 1],
+                    ).unwrap(),
+                    accounts,
+                )
+            );
+        }
+
+        // instruction completes successfully for the run of
+        // five parcels from index 1, and changes nothing
+        {
+            let mut accounts = vec![&mut land_plane_acc];
+            accounts.extend(land_asset_accs.iter_mut());
             do_process_instruction(
-                initialize_land_plane(&program_id, &land_plane_acc_key).unwrap(),
-                vec![&mut land_plane_acc, &mut rent_sysvar]
+                emit_parcel_range(&program_id, &land_plane_acc_pubkey, 1, &land_asset_acc_pubkeys).unwrap(),
+                accounts,
             )
-        );        
+            .unwrap();
+        }
+        assert_eq!(land_plane_acc_data, land_plane_acc.data);
+        for (data, land_asset_acc) in land_asset_accs_data.iter().zip(land_asset_accs.iter()) {
+            assert_eq!(data, &land_asset_acc.data);
+        }
     }
 
     #[test]
-    fn test_mint_next() {
+    fn test_process_batch_initialise_and_mint() {
         let program_id = crate::id();
 
-        let nft_assoc_token_acc_owner_acc_pubkey = Pubkey::new_unique();
-        let mut nft_assoc_token_acc_owner_acc = SolanaAccount::new(1, 0, &system_program::id());        
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(1_000_000, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
 
-        let land_asset_acc_wrong_pubkey = Pubkey::new_unique();
-        let mut land_asset_acc = SolanaAccount::new(1, LAND_ASSET_ACC_DATA_LEN, &system_program::id());
+        let protocol_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut protocol_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let (program_config_acc_pubkey, mut program_config_acc) =
+            program_config_account(&program_id, 0, &protocol_treasury_acc_pubkey);
+        let mut land_asset_by_mint_acc = land_asset_by_mint_account();
+        let mut rent_sysvar = rent_sysvar();
+        let mut slot_hashes_sysvar = slot_hashes_sysvar([0; 32]);
+        let mut nft_metadata_acc = SolanaAccount::default();
+        let mut clock_sysvar = clock_sysvar(0);
+        let mut wallet_mint_record_acc = SolanaAccount::default();
+
+        let nft_assoc_token_acc_owner_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
 
         let land_plane_acc_pubkey = Pubkey::new_unique();
-        let mut land_plane_acc = SolanaAccount::new(42, LAND_PLANE_ACC_DATA_LEN, &program_id);
+        let mut land_plane_acc = fake_initialized_plane(2, 1, 2);
 
-        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
-        let mut nft_assoc_token_acc = SolanaAccount::new(1, 0, &system_program::id());                
+        // land asset account for the next piece of land, not yet initialised
+        let (land_asset_acc_pubkey, _) =
+            next_land_asset_account(&program_id, &land_plane_acc_pubkey, &land_plane_acc);
+        let mut land_asset_acc = SolanaAccount::new(
+            Rent::default().minimum_balance(LAND_ASSET_ACC_DATA_LEN),
+            LAND_ASSET_ACC_DATA_LEN,
+            &program_id,
+        );
 
         let nft_mint_acc_pubkey = Pubkey::new_unique();
         let mut nft_mint_acc = SolanaAccount::new(1, 0, &system_program::id());
 
-        //
-        // land plane account not initialised
-        //
-        assert_eq!(
-            Err(LandError::LandPlaneAccUninitialised.into()),
-            do_process_instruction(
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = nft_token_account(
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+
+        let (land_plane_stats_acc_pubkey, mut land_plane_stats_acc) =
+            land_plane_stats_account(&program_id, &land_plane_acc_pubkey);
+
+        // initialise then mint the land asset in one instruction
+        let instruction = process_batch(
+            &program_id,
+            &[
+                initialize_next_land_asset(
+                    &program_id,
+                    &payer_acc_pubkey,
+                    &land_asset_acc_pubkey,
+                    &land_plane_acc_pubkey,
+                    None,
+                ).unwrap(),
                 mint_next_land_asset(
                     &program_id,
+                    &payer_acc_pubkey,
                     &nft_assoc_token_acc_owner_acc_pubkey,
-                    &land_asset_acc_wrong_pubkey,
+                    &land_asset_acc_pubkey,
                     &land_plane_acc_pubkey,
                     &nft_assoc_token_acc_pubkey,
                     &nft_mint_acc_pubkey,
+                    &land_plane_stats_acc_pubkey,
+                    &program_config_acc_pubkey,
+                    &protocol_treasury_acc_pubkey,
+                    None,
+                    None,
                 ).unwrap(),
-                vec![
+            ],
+        ).unwrap();
+
+        // accounts shared by the sub-instructions are given once
+        assert_eq!(16, instruction.accounts.len());
+
+        do_process_instruction(
+            instruction,
+            vec![
+                &mut payer_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut rent_sysvar,
+                &mut system_program_acc,
+                &mut nft_assoc_token_acc_owner_acc,
+                &mut nft_assoc_token_acc,
+                &mut nft_mint_acc,
+                &mut land_plane_stats_acc,
+                &mut program_config_acc,
+                &mut protocol_treasury_acc,
+                &mut land_asset_by_mint_acc,
+                &mut slot_hashes_sysvar,
+                &mut nft_metadata_acc,
+                &mut clock_sysvar,
+                &mut wallet_mint_record_acc,
+                ]
+        )
+        .unwrap();
+
+        // land is bound to the nft
+        assert_eq!(
+            LandAsset{
+                version: LandAssetVersion::V8,
+                mint_pubkey: nft_mint_acc_pubkey,
+                minter: nft_assoc_token_acc_owner_acc_pubkey,
+                land_plane: land_plane_acc_pubkey,
+                coordinate: Coordinate { x: 2, z: 1 },
+                attributes: 0,
+                merged_into: Pubkey::default(),
+                estate_footprint: 0,
+                bump: find_land_asset_address(&program_id, &land_plane_acc_pubkey, Coordinate { x: 2, z: 1 }, FULL_ADDR_WIDTH).1,
+                transfer_count: 0,
+                locked_by: Pubkey::default(),
+            },
+            LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap(),
+        );
+
+        // and the land plane has moved on to the next piece of land
+        let land_plane = LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap();
+        assert_eq!((2, 2, 2), (land_plane.next_x, land_plane.next_z, land_plane.depth));
+
+        //
+        // batches may not be nested
+        //
+        assert_eq!(
+            Err(LandError::NestedProcessBatch.into()),
+            do_process_instruction(
+                process_batch(&program_id, &[process_batch(&program_id, &[]).unwrap()]).unwrap(),
+                vec![],
+            )
+        );
+    }
+
+    #[test]
+    fn test_process_batch_too_large() {
+        let program_id = crate::id();
+        let (_, mut program_config_acc) = program_config_account(&program_id, 0, &Pubkey::new_unique());
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_initialized_plane(0, 0, 0);
+        let mut rent_sysvar = rent_sysvar();
+
+        // a batch of sub-instructions which each do nothing, since
+        // the land plane is already initialised with the given config
+        let batch = |count: usize| process_batch(
+            &program_id,
+            &vec![
+                initialize_land_plane_idempotent(&program_id, &land_plane_acc_pubkey, None, false, 0, None, MintPattern::Shell, false, 0, false, None, None, 0, None, None, None).unwrap();
+                count
+            ],
+        ).unwrap();
+
+        // a batch at the limit is processed
+        do_process_instruction(
+            batch(MAX_BATCH_COUNT),
+            vec![&mut land_plane_acc, &mut rent_sysvar, &mut program_config_acc],
+        )
+        .unwrap();
+
+        // but not one larger
+        assert_eq!(
+            Err(LandError::BatchTooLarge.into()),
+            do_process_instruction(
+                batch(MAX_BATCH_COUNT + 1),
+                vec![&mut land_plane_acc, &mut rent_sysvar, &mut program_config_acc],
+            )
+        );
+    }
+
+    fn slot_hashes_sysvar(recent_slot_hash: [u8; 32]) -> SolanaAccount {
+        create_account_for_test(&SlotHashes::new(&[(1, Hash::new_from_array(recent_slot_hash))]))
+    }
+
+    fn clock_sysvar(slot: u64) -> SolanaAccount {
+        create_account_for_test(&Clock { slot, ..Clock::default() })
+    }
+
+    #[test]
+    fn test_mint_next_random_fill() {
+        let program_id = crate::id();
+
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(1_000_000, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+
+        let protocol_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut protocol_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let (program_config_acc_pubkey, mut program_config_acc) =
+            program_config_account(&program_id, 0, &protocol_treasury_acc_pubkey);
+        let mut rent_sysvar = rent_sysvar();
+        let mut nft_metadata_acc = SolanaAccount::default();
+        let mut clock_sysvar = clock_sysvar(0);
+        let mut wallet_mint_record_acc = SolanaAccount::default();
+
+        let nft_assoc_token_acc_owner_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        // land plane of 16 pieces of land filled at random
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_plane_account(&LandPlane{
+            coordinate_ceiling: Some(3),
+            mint_pattern: MintPattern::RandomFill,
+            ..initialized_plane(0, 0, 0)
+        });
+
+        let (land_plane_stats_acc_pubkey, mut land_plane_stats_acc) =
+            land_plane_stats_account(&program_id, &land_plane_acc_pubkey);
+
+        // mint land with a new nft for each slot hash in turn, until ten
+        // pieces of land are minted, keeping the land asset account of each
+        let mut minted: Vec<(Coordinate, Pubkey, SolanaAccount)> = Vec::new();
+        let mut probed_past = false;
+        for seed in 1..=10u8 {
+            let mut slot_hashes_sysvar = slot_hashes_sysvar([seed; 32]);
+
+            // co-ordinate the mint will be assigned, as found by a client
+            // probing from the candidate past land already minted
+            let land_plane = LandPlane::unpack_versioned(&land_plane_acc.data).unwrap();
+            let mut probed: Vec<(Pubkey, SolanaAccount)> = Vec::new();
+            let coordinate = (0..=MAX_RANDOM_FILL_PROBES)
+                .map(|probe| land_plane.random_fill_coordinate(&[seed; 32], probe).unwrap())
+                .find(|coordinate| match minted.iter().find(|(c, _, _)| c == coordinate) {
+                    Some((_, pubkey, acc)) => {
+                        probed.push((*pubkey, acc.clone()));
+                        false
+                    }
+                    None => true,
+                })
+                .unwrap();
+            let (land_asset_acc_pubkey, _) =
+                find_land_asset_address(&program_id, &land_plane_acc_pubkey, coordinate, FULL_ADDR_WIDTH);
+            let probed_pubkeys: Vec<Pubkey> = probed.iter().map(|(pubkey, _)| *pubkey).collect();
+
+            let nft_mint_acc_pubkey = Pubkey::new_unique();
+            let mut nft_mint_acc = SolanaAccount::new(1, 0, &system_program::id());
+            let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+            let mut nft_assoc_token_acc = nft_token_account(
+                &nft_assoc_token_acc_owner_acc_pubkey,
+                &nft_mint_acc_pubkey,
+                AccountState::Initialized,
+            );
+            let mut land_asset_by_mint_acc = land_asset_by_mint_account();
+            let instruction = |land_asset_acc_pubkey: &Pubkey, probed_pubkeys: &[Pubkey]| mint_next_random_fill(
+                &program_id,
+                &payer_acc_pubkey,
+                &nft_assoc_token_acc_owner_acc_pubkey,
+                land_asset_acc_pubkey,
+                &land_plane_acc_pubkey,
+                &nft_assoc_token_acc_pubkey,
+                &nft_mint_acc_pubkey,
+                &land_plane_stats_acc_pubkey,
+                &program_config_acc_pubkey,
+                &protocol_treasury_acc_pubkey,
+                None,
+                None,
+                probed_pubkeys,
+            ).unwrap();
+
+            // land asset account for the co-ordinate, not yet initialised
+            let mut land_asset_acc = SolanaAccount::new(
+                Rent::default().minimum_balance(LAND_ASSET_ACC_DATA_LEN),
+                LAND_ASSET_ACC_DATA_LEN,
+                &program_id,
+            );
+
+            if let Some((candidate_acc_pubkey, candidate_acc)) = probed.first() {
+                probed_past = true;
+
+                //
+                // land at the candidate has already been minted, so its
+                // land asset account is rejected, as by a racing mint
+                //
+                let mut candidate_acc = candidate_acc.clone();
+                assert_eq!(
+                    Err(LandError::LandAssetAlreadyMinted.into()),
+                    do_process_instruction(
+                        instruction(candidate_acc_pubkey, &[]),
+                        vec![
+                            &mut payer_acc,
+                            &mut nft_assoc_token_acc_owner_acc,
+                            &mut candidate_acc,
+                            &mut land_plane_acc,
+                            &mut nft_assoc_token_acc,
+                            &mut nft_mint_acc,
+                            &mut land_plane_stats_acc,
+                            &mut system_program_acc,
+                            &mut program_config_acc,
+                            &mut protocol_treasury_acc,
+                            &mut land_asset_by_mint_acc,
+                            &mut rent_sysvar,
+                            &mut slot_hashes_sysvar,
+                            ]
+                    )
+                );
+
+                //
+                // land probed past must be shown to have been minted
+                //
+                assert_eq!(
+                    Err(ProgramError::NotEnoughAccountKeys),
+                    do_process_instruction(
+                        instruction(&land_asset_acc_pubkey, &[]),
+                        vec![
+                            &mut payer_acc,
+                            &mut nft_assoc_token_acc_owner_acc,
+                            &mut land_asset_acc,
+                            &mut land_plane_acc,
+                            &mut nft_assoc_token_acc,
+                            &mut nft_mint_acc,
+                            &mut land_plane_stats_acc,
+                            &mut system_program_acc,
+                            &mut program_config_acc,
+                            &mut protocol_treasury_acc,
+                            &mut land_asset_by_mint_acc,
+                            &mut rent_sysvar,
+                            &mut slot_hashes_sysvar,
+                            ]
+                    )
+                );
+                let mut unminted_accs: Vec<SolanaAccount> = probed
+                    .iter()
+                    .map(|_| fake_initialized_asset(&Pubkey::default()))
+                    .collect();
+                let mut accounts = vec![
+                    &mut payer_acc,
                     &mut nft_assoc_token_acc_owner_acc,
                     &mut land_asset_acc,
                     &mut land_plane_acc,
                     &mut nft_assoc_token_acc,
                     &mut nft_mint_acc,
-                    ]
-            )
-        );
-        
-        // initialise land plane account
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
+                    &mut slot_hashes_sysvar,
+                    &mut nft_metadata_acc,
+                    &mut clock_sysvar,
+                    &mut wallet_mint_record_acc,
+                ];
+                accounts.extend(unminted_accs.iter_mut());
+                assert_eq!(
+                    Err(LandError::ProbedLandNotMinted.into()),
+                    do_process_instruction(instruction(&land_asset_acc_pubkey, &probed_pubkeys), accounts)
+                );
+            }
+
+            // instruction completes successfully, given the land asset
+            // account of each co-ordinate probed past
+            let mut probed_accs: Vec<SolanaAccount> = probed.into_iter().map(|(_, acc)| acc).collect();
+            let mut accounts = vec![
+                &mut payer_acc,
+                &mut nft_assoc_token_acc_owner_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut nft_assoc_token_acc,
+                &mut nft_mint_acc,
+                &mut land_plane_stats_acc,
+                &mut system_program_acc,
+                &mut program_config_acc,
+                &mut protocol_treasury_acc,
+                &mut land_asset_by_mint_acc,
+                &mut rent_sysvar,
+                &mut slot_hashes_sysvar,
+                &mut nft_metadata_acc,
+                &mut clock_sysvar,
+                &mut wallet_mint_record_acc,
+            ];
+            accounts.extend(probed_accs.iter_mut());
+            do_process_instruction(instruction(&land_asset_acc_pubkey, &probed_pubkeys), accounts).unwrap();
+
+            // land is bound to the nft at the found co-ordinate
+            let land_asset = LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap();
+            assert_eq!(nft_mint_acc_pubkey, land_asset.mint_pubkey);
+            assert_eq!(coordinate, land_asset.coordinate);
+            minted.push((coordinate, land_asset_acc_pubkey, land_asset_acc));
+
+            // and the random fill seed is rolled on by the slot hash
+            let mut rolled = land_plane.clone();
+            rolled.increment_mint().unwrap();
+            rolled.roll_random_fill_seed(&[seed; 32]);
+            assert_eq!(Ok(rolled), LandPlane::unpack_versioned(&land_plane_acc.data));
+        }
+
+        // every piece of land was assigned a different co-ordinate,
+        // and land already minted was probed past along the way
+        for (i, (coordinate, _, _)) in minted.iter().enumerate() {
+            assert!(minted[i + 1..].iter().all(|(c, _, _)| c != coordinate), "{:?}", coordinate);
+        }
+        assert!(probed_past);
+
+        // and the land plane has counted the mints
+        let land_plane = LandPlane::unpack_versioned(&land_plane_acc.data).unwrap();
+        assert_eq!(10, land_plane.minted_count());
+        // as has the program config, across the program
+        assert_eq!(10, ProgramConfig::try_from_slice(&program_config_acc.data).unwrap().total_mints);
+    }
+
+    #[test]
+    fn test_mint_next_random_fill_past_run_of_minted_land() {
+        let program_id = crate::id();
+
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(1_000_000, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+
+        let protocol_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut protocol_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let (program_config_acc_pubkey, mut program_config_acc) =
+            program_config_account(&program_id, 0, &protocol_treasury_acc_pubkey);
+        let mut land_asset_by_mint_acc = land_asset_by_mint_account();
+        let mut rent_sysvar = rent_sysvar();
+        let mut nft_metadata_acc = SolanaAccount::default();
+        let mut clock_sysvar = clock_sysvar(0);
+        let mut wallet_mint_record_acc = SolanaAccount::default();
+
+        let nft_assoc_token_acc_owner_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        // land plane of 25 pieces of land filled at random
+        let land_plane_acc_pubkey = Pubkey::new_unique();
         let land_plane = LandPlane{
-            version: LandPlaneVersion::V1,
-            next_x: 100,
-            next_z: 21,
-            depth: 100,
+            coordinate_ceiling: Some(4),
+            mint_pattern: MintPattern::RandomFill,
+            ..initialized_plane(0, 0, 0)
+        };
+        let mut land_plane_acc = fake_plane_account(&land_plane);
+
+        let (land_plane_stats_acc_pubkey, mut land_plane_stats_acc) =
+            land_plane_stats_account(&program_id, &land_plane_acc_pubkey);
+
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut nft_mint_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = nft_token_account(
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+
+        let instruction = |land_asset_acc_pubkey: &Pubkey, probed_pubkeys: &[Pubkey]| mint_next_random_fill(
+            &program_id,
+            &payer_acc_pubkey,
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            land_asset_acc_pubkey,
+            &land_plane_acc_pubkey,
+            &nft_assoc_token_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            &land_plane_stats_acc_pubkey,
+            &program_config_acc_pubkey,
+            &protocol_treasury_acc_pubkey,
+            None,
+            None,
+            probed_pubkeys,
+        ).unwrap();
+        let land_asset_pubkey = |coordinate| {
+            find_land_asset_address(&program_id, &land_plane_acc_pubkey, coordinate, FULL_ADDR_WIDTH).0
         };
-        land_plane_acc.data = land_plane.try_to_vec().unwrap();
+
+        // a run of minted land over every probe from the candidate
+        // for the first slot hash, and the land just past it
+        let run: Vec<Coordinate> = (0..=MAX_RANDOM_FILL_PROBES)
+            .map(|probe| land_plane.random_fill_coordinate(&[1; 32], probe).unwrap())
+            .collect();
+        let run_pubkeys: Vec<Pubkey> = run.iter().map(|c| land_asset_pubkey(*c)).collect();
+        let mut run_accs: Vec<SolanaAccount> = run
+            .iter()
+            .map(|_| fake_initialized_asset(&Pubkey::new_unique()))
+            .collect();
+        let past_run = land_plane.random_fill_coordinate(&[1; 32], MAX_RANDOM_FILL_PROBES + 1).unwrap();
+
+        let mut land_asset_acc = SolanaAccount::new(
+            Rent::default().minimum_balance(LAND_ASSET_ACC_DATA_LEN),
+            LAND_ASSET_ACC_DATA_LEN,
+            &program_id,
+        );
 
         //
-        // invalid land asset acc key
+        // no land can be minted in the slot following the first slot
+        // hash, as the run is longer than the probes
         //
+        let mut first_slot_hashes_sysvar = slot_hashes_sysvar([1; 32]);
+        let mut accounts = vec![
+            &mut payer_acc,
+            &mut nft_assoc_token_acc_owner_acc,
+            &mut land_asset_acc,
+            &mut land_plane_acc,
+            &mut nft_assoc_token_acc,
+            &mut nft_mint_acc,
+            &mut land_plane_stats_acc,
+            &mut system_program_acc,
+            &mut program_config_acc,
+            &mut protocol_treasury_acc,
+            &mut land_asset_by_mint_acc,
+            &mut rent_sysvar,
+            &mut first_slot_hashes_sysvar,
+            &mut nft_metadata_acc,
+            &mut clock_sysvar,
+            &mut wallet_mint_record_acc,
+        ];
+        accounts.extend(run_accs.iter_mut());
         assert_eq!(
             Err(LandError::InvalidLandAssetAccKey.into()),
-            do_process_instruction(
-                mint_next_land_asset(
-                    &program_id,
-                    &nft_assoc_token_acc_owner_acc_pubkey,
-                    &land_asset_acc_wrong_pubkey,
-                    &land_plane_acc_pubkey,
-                    &nft_assoc_token_acc_pubkey,
-                    &nft_mint_acc_pubkey,
-                ).unwrap(),
-                vec![
-                    &mut nft_assoc_token_acc_owner_acc,
-                    &mut land_asset_acc,
-                    &mut land_plane_acc,
-                    &mut nft_assoc_token_acc,
-                    &mut nft_mint_acc,
-                    ]
-            )
+            do_process_instruction(instruction(&land_asset_pubkey(past_run), &run_pubkeys), accounts)
         );
 
-        // generate correct land asset account for next piece of land
-        let (land_asset_acc_pubkey, _) = Pubkey::find_program_address(
+        // but the candidate moves with the slot hash, so that the land
+        // is minted in a later slot, probing past any of the run
+        let slot_hash = (2..=u8::MAX)
+            .find(|slot_hash| !run.contains(&land_plane.random_fill_coordinate(&[*slot_hash; 32], 0).unwrap()))
+            .unwrap();
+        let mut probed_pubkeys = Vec::new();
+        let mut probed_accs = Vec::new();
+        let coordinate = (0..=MAX_RANDOM_FILL_PROBES)
+            .map(|probe| land_plane.random_fill_coordinate(&[slot_hash; 32], probe).unwrap())
+            .find(|coordinate| match run.iter().position(|c| c == coordinate) {
+                Some(i) => {
+                    probed_pubkeys.push(run_pubkeys[i]);
+                    probed_accs.push(run_accs[i].clone());
+                    false
+                }
+                None => true,
+            })
+            .unwrap();
+        let mut slot_hashes_sysvar = slot_hashes_sysvar([slot_hash; 32]);
+        let mut accounts = vec![
+            &mut payer_acc,
+            &mut nft_assoc_token_acc_owner_acc,
+            &mut land_asset_acc,
+            &mut land_plane_acc,
+            &mut nft_assoc_token_acc,
+            &mut nft_mint_acc,
+            &mut land_plane_stats_acc,
+            &mut system_program_acc,
+            &mut program_config_acc,
+            &mut protocol_treasury_acc,
+            &mut land_asset_by_mint_acc,
+            &mut rent_sysvar,
+            &mut slot_hashes_sysvar,
+            &mut nft_metadata_acc,
+            &mut clock_sysvar,
+            &mut wallet_mint_record_acc,
+        ];
+        accounts.extend(probed_accs.iter_mut());
+        do_process_instruction(instruction(&land_asset_pubkey(coordinate), &probed_pubkeys), accounts).unwrap();
+
+        // land is bound to the nft at a co-ordinate outside the run
+        let land_asset = LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap();
+        assert_eq!(nft_mint_acc_pubkey, land_asset.mint_pubkey);
+        assert_eq!(coordinate, land_asset.coordinate);
+        assert!(!run.contains(&coordinate));
+        assert_eq!(1, LandPlane::unpack_versioned(&land_plane_acc.data).unwrap().minted_count());
+    }
+
+    #[test]
+    fn test_mint_next_random_fill_from_v16_land_plane() {
+        let program_id = crate::id();
+
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(1_000_000, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+
+        let protocol_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut protocol_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let (program_config_acc_pubkey, mut program_config_acc) =
+            program_config_account(&program_id, 0, &protocol_treasury_acc_pubkey);
+        let mut land_asset_by_mint_acc = land_asset_by_mint_account();
+        let mut rent_sysvar = rent_sysvar();
+        let mut slot_hashes_sysvar = slot_hashes_sysvar([1; 32]);
+
+        let nft_assoc_token_acc_owner_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        // land plane filled at random, allocated at the V16 length,
+        // before the random fill seed was added
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let land_plane = LandPlane{
+            version: LandPlaneVersion::V16,
+            coordinate_ceiling: Some(3),
+            mint_pattern: MintPattern::RandomFill,
+            ..initialized_plane(0, 0, 0)
+        };
+        let mut land_plane_acc = fake_plane_account(&land_plane);
+        land_plane_acc.data.truncate(LAND_PLANE_V16_ACC_DATA_LEN);
+
+        // the candidate is picked by the slot hash and minted_count alone
+        let coordinate = land_plane.random_fill_coordinate(&[1; 32], 0).unwrap();
+        let (land_asset_acc_pubkey, _) =
+            find_land_asset_address(&program_id, &land_plane_acc_pubkey, coordinate, FULL_ADDR_WIDTH);
+        let mut land_asset_acc = SolanaAccount::new(
+            Rent::default().minimum_balance(LAND_ASSET_ACC_DATA_LEN),
+            LAND_ASSET_ACC_DATA_LEN,
+            &program_id,
+        );
+
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut nft_mint_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = nft_token_account(
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+
+        let (land_plane_stats_acc_pubkey, mut land_plane_stats_acc) =
+            land_plane_stats_account(&program_id, &land_plane_acc_pubkey);
+
+        // instruction completes successfully
+        do_process_instruction(
+            mint_next_land_asset(
+                &program_id,
+                &payer_acc_pubkey,
+                &nft_assoc_token_acc_owner_acc_pubkey,
+                &land_asset_acc_pubkey,
+                &land_plane_acc_pubkey,
+                &nft_assoc_token_acc_pubkey,
+                &nft_mint_acc_pubkey,
+                &land_plane_stats_acc_pubkey,
+                &program_config_acc_pubkey,
+                &protocol_treasury_acc_pubkey,
+                None,
+                None,
+            ).unwrap(),
+            vec![
+                &mut payer_acc,
+                &mut nft_assoc_token_acc_owner_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut nft_assoc_token_acc,
+                &mut nft_mint_acc,
+                &mut land_plane_stats_acc,
+                &mut system_program_acc,
+                &mut program_config_acc,
+                &mut protocol_treasury_acc,
+                &mut land_asset_by_mint_acc,
+                &mut rent_sysvar,
+                &mut slot_hashes_sysvar,
+                ]
+        )
+        .unwrap();
+
+        // the land plane account keeps its length, with the cursor moved
+        // on but the random fill seed left unset, as it does not fit
+        assert_eq!(LAND_PLANE_V16_ACC_DATA_LEN, land_plane_acc.data.len());
+        assert_eq!(
+            Ok(LandPlane{ next_x: 1, depth: 1, ..land_plane }),
+            LandPlane::unpack_versioned(&land_plane_acc.data),
+        );
+        assert_eq!(
+            coordinate,
+            LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap().coordinate,
+        );
+    }
+
+    #[test]
+    fn test_mint_next_first_mint_is_genesis_square() {
+        let program_id = crate::id();
+
+        let payer_acc_pubkey = Pubkey::new_unique();
+        let mut payer_acc = SolanaAccount::new(1_000_000, 0, &system_program::id());
+        let mut system_program_acc = SolanaAccount::default();
+
+        let protocol_treasury_acc_pubkey = Pubkey::new_unique();
+        let mut protocol_treasury_acc = SolanaAccount::new(1, 0, &system_program::id());
+        let (program_config_acc_pubkey, mut program_config_acc) =
+            program_config_account(&program_id, 0, &protocol_treasury_acc_pubkey);
+        let mut land_asset_by_mint_acc = land_asset_by_mint_account();
+        let mut rent_sysvar = rent_sysvar();
+
+        let nft_assoc_token_acc_owner_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc_owner_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        // freshly initialised land plane
+        let land_plane_acc_pubkey = Pubkey::new_unique();
+        let mut land_plane_acc = fake_initialized_plane(0, 0, 0);
+
+        // the first piece of land is at (0, 0), whose land asset account
+        // is the PDA of the prefix, the land plane and two zero u64s
+        let (land_asset_acc_pubkey, bump) = Pubkey::find_program_address(
             &[
-                LAND_ASSET_ACC_PREFIX.as_bytes(),
+                &b"solsspace-land"[..],
                 land_plane_acc_pubkey.as_ref(),
-                &land_plane.next_x.to_le_bytes(),
-                &land_plane.next_z.to_le_bytes(),
+                &[0; 8],
+                &[0; 8],
             ],
             &program_id,
         );
+        let mut land_asset_acc = fake_asset_account(&LandAsset{ bump, ..initialized_asset(&Pubkey::default()) });
+
+        let nft_mint_acc_pubkey = Pubkey::new_unique();
+        let mut nft_mint_acc = SolanaAccount::new(1, 0, &system_program::id());
+
+        let nft_assoc_token_acc_pubkey = Pubkey::new_unique();
+        let mut nft_assoc_token_acc = nft_token_account(
+            &nft_assoc_token_acc_owner_acc_pubkey,
+            &nft_mint_acc_pubkey,
+            AccountState::Initialized,
+        );
+
+        let (land_plane_stats_acc_pubkey, mut land_plane_stats_acc) =
+            land_plane_stats_account(&program_id, &land_plane_acc_pubkey);
 
         //
-        // land asset account not initialised
+        // land asset account of the piece of land after the genesis square
         //
+        let (next_land_asset_acc_pubkey, _) = Pubkey::find_program_address(
+            &[
+                &b"solsspace-land"[..],
+                land_plane_acc_pubkey.as_ref(),
+                &1u64.to_le_bytes(),
+                &[0; 8],
+            ],
+            &program_id,
+        );
         assert_eq!(
-            Err(LandError::LandAssetAccUninitialised.into()),
+            Err(LandError::InvalidLandAssetAccKey.into()),
             do_process_instruction(
                 mint_next_land_asset(
                     &program_id,
+                    &payer_acc_pubkey,
                     &nft_assoc_token_acc_owner_acc_pubkey,
-                    &land_asset_acc_pubkey,
+                    &next_land_asset_acc_pubkey,
                     &land_plane_acc_pubkey,
                     &nft_assoc_token_acc_pubkey,
                     &nft_mint_acc_pubkey,
+                    &land_plane_stats_acc_pubkey,
+                    &program_config_acc_pubkey,
+                    &protocol_treasury_acc_pubkey,
+                    None,
+                    None,
                 ).unwrap(),
                 vec![
+                    &mut payer_acc,
                     &mut nft_assoc_token_acc_owner_acc,
                     &mut land_asset_acc,
                     &mut land_plane_acc,
                     &mut nft_assoc_token_acc,
                     &mut nft_mint_acc,
+                    &mut land_plane_stats_acc,
+                    &mut system_program_acc,
+                    &mut program_config_acc,
+                    &mut protocol_treasury_acc,
+                    &mut land_asset_by_mint_acc,
+                    &mut rent_sysvar,
                     ]
             )
         );
+
+        // instruction completes successfully
+        do_process_instruction(
+            mint_next_land_asset(
+                &program_id,
+                &payer_acc_pubkey,
+                &nft_assoc_token_acc_owner_acc_pubkey,
+                &land_asset_acc_pubkey,
+                &land_plane_acc_pubkey,
+                &nft_assoc_token_acc_pubkey,
+                &nft_mint_acc_pubkey,
+                &land_plane_stats_acc_pubkey,
+                &program_config_acc_pubkey,
+                &protocol_treasury_acc_pubkey,
+                None,
+                None,
+            ).unwrap(),
+            vec![
+                &mut payer_acc,
+                &mut nft_assoc_token_acc_owner_acc,
+                &mut land_asset_acc,
+                &mut land_plane_acc,
+                &mut nft_assoc_token_acc,
+                &mut nft_mint_acc,
+                &mut land_plane_stats_acc,
+                &mut system_program_acc,
+                &mut program_config_acc,
+                &mut protocol_treasury_acc,
+                &mut land_asset_by_mint_acc,
+                &mut rent_sysvar,
+                ]
+        )
+        .unwrap();
+
+        // the genesis square is bound to the nft
+        let land_asset = LandAsset::unpack_from_slice(&land_asset_acc.data).unwrap();
+        assert_eq!(nft_mint_acc_pubkey, land_asset.mint_pubkey);
+        assert_eq!(Coordinate { x: 0, z: 0 }, land_asset.coordinate);
+
+        // and the land plane has moved on to the shell at depth 1
+        let land_plane = LandPlane::deserialize(&mut &land_plane_acc.data[..]).unwrap();
+        assert_eq!((1, 0, 1), (land_plane.next_x, land_plane.next_z, land_plane.depth));
+        assert_eq!(1, land_plane.minted_count());
     }
-}
\ No newline at end of file
+}
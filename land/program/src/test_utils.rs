@@ -0,0 +1,173 @@
+//! Fixtures for tests of the land program, and of programs built on
+//! it. Building state and accounts through these rather than by hand
+//! keeps tests working as fields are added to the account layouts.
+
+use {
+    crate::state::{
+        LAND_ASSET_ACC_DATA_LEN,
+        LAND_PLANE_ACC_DATA_LEN,
+        LAND_PLANE_STATS_ACC_DATA_LEN,
+        LAND_PLANE_STATS_WALLET_BLOOM_LEN,
+        PROGRAM_CONFIG_ACC_DATA_LEN,
+        LAND_ASSET_BY_MINT_ACC_DATA_LEN,
+        Coordinate,
+        LandAsset,
+        LandAssetByMint,
+        LandAssetByMintVersion,
+        LandAssetVersion,
+        LandPlane,
+        LandPlaneStats,
+        LandPlaneStatsVersion,
+        LandPlaneVersion,
+        MintPattern,
+        FULL_ADDR_WIDTH,
+        ProgramConfig,
+        ProgramConfigVersion,
+    },
+    borsh::BorshSerialize,
+    solana_program::{program_pack::Pack, pubkey::Pubkey, sysvar::rent::Rent},
+    solana_sdk::account::Account as SolanaAccount,
+};
+
+/// Initialised land plane at the given co-ordinate, with no
+/// coordinate_ceiling, no frozen mint requirement, no mint fee, no
+/// owner, land minted in shells, any number of parcels per NFT, no
+/// royalty, land asset addresses at FULL_ADDR_WIDTH, no required
+/// collection and no land asset accounts migrated.
+/// Other config can be given with struct update syntax, e.g.
+///
+/// ```ignore
+/// LandPlane{ mint_fee: 5_000, ..initialized_plane(0, 0, 0) }
+/// ```
+pub fn initialized_plane(next_x: u64, next_z: u64, depth: u64) -> LandPlane {
+    LandPlane{
+        version: LandPlaneVersion::V18,
+        next_x,
+        next_z,
+        depth,
+        coordinate_ceiling: None,
+        require_frozen_mint: false,
+        mint_fee: 0,
+        owner: None,
+        mint_pattern: MintPattern::Shell,
+        one_parcel_per_nft: false,
+        royalty_bps: 0,
+        addr_width: FULL_ADDR_WIDTH,
+        required_collection: None,
+        migrated_count: 0,
+        max_supply: None,
+        mint_cooldown_slots: 0,
+        price_oracle: None,
+        reserve_claimant: None,
+        closes_at_slot: None,
+        mint_start_slot: None,
+        mint_end_slot: None,
+        random_fill_seed: [0; 32],
+        plane_treasury: None,
+    }
+}
+
+/// Initialised land asset bound to the given NFT mint, with no minter,
+/// land plane, co-ordinate or bump. The default public key gives a land
+/// asset that is yet to be minted.
+pub fn initialized_asset(mint_pubkey: &Pubkey) -> LandAsset {
+    LandAsset{
+        version: LandAssetVersion::V8,
+        mint_pubkey: *mint_pubkey,
+        minter: Pubkey::default(),
+        land_plane: Pubkey::default(),
+        coordinate: Coordinate { x: 0, z: 0 },
+        attributes: 0,
+        merged_into: Pubkey::default(),
+        estate_footprint: 0,
+        bump: 0,
+        transfer_count: 0,
+        locked_by: Pubkey::default(),
+    }
+}
+
+/// Initialised land plane stats with nothing yet minted.
+pub fn initialized_plane_stats() -> LandPlaneStats {
+    LandPlaneStats{
+        version: LandPlaneStatsVersion::V1,
+        mints: 0,
+        fees_collected: 0,
+        unique_wallets: 0,
+        wallet_bloom: [0; LAND_PLANE_STATS_WALLET_BLOOM_LEN],
+    }
+}
+
+/// Initialised program config with the given protocol fee and treasury.
+pub fn initialized_program_config(protocol_fee: u64, protocol_treasury: &Pubkey) -> ProgramConfig {
+    ProgramConfig{
+        version: ProgramConfigVersion::V2,
+        protocol_fee,
+        protocol_treasury: *protocol_treasury,
+        total_planes: 0,
+        total_mints: 0,
+    }
+}
+
+/// Initialised land asset by mint index pointing at the given land.
+pub fn initialized_land_asset_by_mint(
+    land_plane: &Pubkey,
+    land_asset: &Pubkey,
+    coordinate: Coordinate,
+) -> LandAssetByMint {
+    LandAssetByMint{
+        version: LandAssetByMintVersion::V1,
+        land_plane: *land_plane,
+        land_asset: *land_asset,
+        coordinate,
+    }
+}
+
+/// Rent exempt account owned by the land program holding the given data.
+fn fake_account(data: Vec<u8>, data_len: usize) -> SolanaAccount {
+    let mut account = SolanaAccount::new(
+        Rent::default().minimum_balance(data_len),
+        data_len,
+        &crate::id(),
+    );
+    account.data[..data.len()].copy_from_slice(&data);
+    account
+}
+
+/// Land plane account holding the given land plane.
+pub fn fake_plane_account(land_plane: &LandPlane) -> SolanaAccount {
+    fake_account(land_plane.try_to_vec().unwrap(), LAND_PLANE_ACC_DATA_LEN)
+}
+
+/// Land asset account holding the given land asset.
+pub fn fake_asset_account(land_asset: &LandAsset) -> SolanaAccount {
+    let mut data = vec![0; LAND_ASSET_ACC_DATA_LEN];
+    land_asset.pack_into_slice(&mut data);
+    fake_account(data, LAND_ASSET_ACC_DATA_LEN)
+}
+
+/// Land plane stats account holding the given land plane stats.
+pub fn fake_plane_stats_account(land_plane_stats: &LandPlaneStats) -> SolanaAccount {
+    fake_account(land_plane_stats.try_to_vec().unwrap(), LAND_PLANE_STATS_ACC_DATA_LEN)
+}
+
+/// Program config account holding the given program config.
+pub fn fake_program_config_account(program_config: &ProgramConfig) -> SolanaAccount {
+    fake_account(program_config.try_to_vec().unwrap(), PROGRAM_CONFIG_ACC_DATA_LEN)
+}
+
+/// Land asset by mint account holding the given index.
+pub fn fake_land_asset_by_mint_account(land_asset_by_mint: &LandAssetByMint) -> SolanaAccount {
+    fake_account(land_asset_by_mint.try_to_vec().unwrap(), LAND_ASSET_BY_MINT_ACC_DATA_LEN)
+}
+
+/// Land plane account holding an initialised land plane at the given
+/// co-ordinate. See initialized_plane.
+pub fn fake_initialized_plane(next_x: u64, next_z: u64, depth: u64) -> SolanaAccount {
+    fake_plane_account(&initialized_plane(next_x, next_z, depth))
+}
+
+/// Land asset account holding an initialised land asset bound to the
+/// given NFT mint. See initialized_asset.
+pub fn fake_initialized_asset(mint_pubkey: &Pubkey) -> SolanaAccount {
+    fake_asset_account(&initialized_asset(mint_pubkey))
+}
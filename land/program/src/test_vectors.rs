@@ -0,0 +1,92 @@
+//! Test vectors for the co-ordinate math of the land program, so that
+//! implementations in other languages can check their walk of the
+//! shells against the canonical one.
+
+use crate::state::{Coordinate, LandPlane, LandPlaneVersion, MintPattern, FULL_ADDR_WIDTH};
+
+/// Number of indices covered by coordinate_vectors, i.e. every piece of
+/// land on the shells up to and including depth 19.
+pub const COORDINATE_VECTORS_LEN: u64 = 400;
+
+/// Coordinate_vectors returns the co-ordinate of each of the first
+/// COORDINATE_VECTORS_LEN pieces of land minted from a land plane, as
+/// (index, co-ordinate) pairs in order of index, where the first piece
+/// of land minted has index 0.
+///
+/// The co-ordinates are generated by stepping a fresh land plane with
+/// LandPlane::increment_mint, so that the index of each is also the
+/// LandPlane::minted_count of the plane when at that co-ordinate.
+pub fn coordinate_vectors() -> Vec<(u64, Coordinate)> {
+    let mut land_plane = LandPlane{
+        version: LandPlaneVersion::V18,
+        next_x: 0,
+        next_z: 0,
+        depth: 0,
+        coordinate_ceiling: None,
+        require_frozen_mint: false,
+        mint_fee: 0,
+        owner: None,
+        mint_pattern: MintPattern::Shell,
+        one_parcel_per_nft: false,
+        royalty_bps: 0,
+        addr_width: FULL_ADDR_WIDTH,
+        required_collection: None,
+        migrated_count: 0,
+        max_supply: None,
+        mint_cooldown_slots: 0,
+        price_oracle: None,
+        reserve_claimant: None,
+        closes_at_slot: None,
+        mint_start_slot: None,
+        mint_end_slot: None,
+        random_fill_seed: [0; 32],
+        plane_treasury: None,
+    };
+    let mut vectors = Vec::with_capacity(COORDINATE_VECTORS_LEN as usize);
+    for index in 0..COORDINATE_VECTORS_LEN {
+        vectors.push((index, Coordinate { x: land_plane.next_x, z: land_plane.next_z }));
+        land_plane.increment_mint().unwrap();
+    }
+    vectors
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{state::coordinate_at_index, test_utils::initialized_plane},
+    };
+
+    #[test]
+    fn test_coordinate_vectors() {
+        let vectors = coordinate_vectors();
+        assert_eq!(COORDINATE_VECTORS_LEN as usize, vectors.len());
+
+        // spot check the start of the first few shells
+        assert_eq!((0, Coordinate { x: 0, z: 0 }), vectors[0]);
+        assert_eq!((1, Coordinate { x: 1, z: 0 }), vectors[1]);
+        assert_eq!((2, Coordinate { x: 1, z: 1 }), vectors[2]);
+        assert_eq!((3, Coordinate { x: 0, z: 1 }), vectors[3]);
+        assert_eq!((4, Coordinate { x: 2, z: 0 }), vectors[4]);
+        assert_eq!((399, Coordinate { x: 0, z: 19 }), vectors[399]);
+
+        // every piece of land on the shells is covered exactly once
+        let mut coordinates: Vec<(u64, u64)> = vectors.iter().map(|(_, c)| (c.x, c.z)).collect();
+        coordinates.sort_unstable();
+        coordinates.dedup();
+        assert_eq!(COORDINATE_VECTORS_LEN as usize, coordinates.len());
+        assert!(coordinates.iter().all(|(x, z)| *x < 20 && *z < 20));
+
+        // and the vectors match the iterative walk of the shells, the
+        // closed form count of the land minted before each and the
+        // closed form co-ordinate at each index
+        let mut land_plane = initialized_plane(0, 0, 0);
+        for (index, coordinate) in vectors {
+            assert_eq!((coordinate.x, coordinate.z), (land_plane.next_x, land_plane.next_z));
+            let depth = coordinate.x.max(coordinate.z);
+            assert_eq!(index, initialized_plane(coordinate.x, coordinate.z, depth).minted_count());
+            assert_eq!(coordinate, coordinate_at_index(index));
+            land_plane.increment_mint().unwrap();
+        }
+    }
+}
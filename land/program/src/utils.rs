@@ -1,4 +1,5 @@
 use {
+    crate::error::LandError,
     // crate::{
     //     // error::LandError,
     //     // // processor::process_create_metadata_accounts,
@@ -28,6 +29,15 @@ use {
     std::convert::TryInto,
 };
 
+/// Minimum_balance returns the lamports an account holding size bytes
+/// of data must hold to be rent exempt. This is never less than 1, so
+/// that under zero rent, e.g. on a local test validator, an account is
+/// still required to be funded, as the runtime removes accounts holding
+/// no lamports.
+pub fn minimum_balance(rent: &Rent, size: usize) -> u64 {
+    rent.minimum_balance(size).max(1)
+}
+
 /// Create account almost from scratch, lifted from
 /// https://github.com/solana-labs/solana-program-library/tree/master/associated-token-account/program/src/processor.rs#L51-L98
 #[inline(always)]
@@ -41,12 +51,16 @@ pub fn create_or_allocate_account_raw<'a>(
     signer_seeds: &[&[u8]],
 ) -> ProgramResult {
     let rent = &Rent::from_account_info(rent_sysvar_info)?;
-    let required_lamports = rent
-        .minimum_balance(size)
-        .max(1)
+    let required_lamports = minimum_balance(rent, size)
         .saturating_sub(new_account_info.lamports());
 
     if required_lamports > 0 {
+        // confirm that the payer can fund the new account, rather
+        // than failing within the system program's transfer
+        if payer_info.lamports() < required_lamports {
+            return Err(LandError::InsufficientRentFunds.into());
+        }
+
         msg!("Transfer {} lamports to the new account", required_lamports);
         invoke(
             &system_instruction::transfer(&payer_info.key, new_account_info.key, required_lamports),
@@ -75,3 +89,23 @@ pub fn create_or_allocate_account_raw<'a>(
     Ok(())
 }
 
+/// Close an account owned by the land program, moving all of its
+/// lamports to the given destination account.
+///
+/// The runtime only removes an account holding no lamports at the end
+/// of the transaction, so a later instruction in the same transaction
+/// may refund it and find it still owned by the land program, as the
+/// account cannot be reassigned to the system program from here. Its
+/// data is therefore zeroed, so that its version reads as Uninitialised
+/// and it may never be treated as holding its old state. Callers must:
+///
+/// 1. confirm that the account may be closed, e.g. that it is the
+///    expected PDA, as no checks are made here
+/// 2. have finished reading its state, which is lost
+/// 3. not write to it again within the same instruction
+pub fn close_account(account_info: &AccountInfo, destination_info: &AccountInfo) {
+    let lamports = account_info.lamports();
+    **account_info.lamports.borrow_mut() = 0;
+    **destination_info.lamports.borrow_mut() += lamports;
+    account_info.data.borrow_mut().fill(0);
+}